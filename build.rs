@@ -0,0 +1,34 @@
+use std::env;
+
+/// The libproj release assumed when proj-sys doesn't report the version it found, as in proj-sys
+const MINIMUM_PROJ_VERSION: &str = "9.2.0";
+
+/// The minor releases of PROJ 9 enabling a `proj_9_<minor>` cfg, which gates the wrappers of the
+/// functions they added: the other functions wrapped are all available in PROJ 9.2
+const VERSION_CFG_MINORS: [u32; 1] = [4];
+
+// enables the `proj_9_<minor>` cfgs of the releases up to the libproj found by proj-sys, whose
+// version it reports as DEP_PROJ_VERSION, so that wrappers of functions missing from older
+// releases are left out
+fn main() {
+    // set to run the loom model of context registration, see src/context.rs
+    println!("cargo:rustc-check-cfg=cfg(loom)");
+    println!("cargo:rerun-if-env-changed=DEP_PROJ_VERSION");
+    let version = env::var("DEP_PROJ_VERSION").unwrap_or_else(|_| {
+        println!(
+            "cargo:warning=proj-sys didn't report the version of libproj, assuming {MINIMUM_PROJ_VERSION}"
+        );
+        MINIMUM_PROJ_VERSION.to_string()
+    });
+    let mut parts = version
+        .split('.')
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    for cfg_minor in VERSION_CFG_MINORS {
+        println!("cargo:rustc-check-cfg=cfg(proj_9_{})", cfg_minor);
+        if major > 9 || (major == 9 && minor >= cfg_minor) {
+            println!("cargo:rustc-cfg=proj_9_{}", cfg_minor);
+        }
+    }
+}
@@ -1,19 +1,61 @@
 use flate2::read::GzDecoder;
 use std::env;
-use std::fs::File;
-use std::path::PathBuf;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
 use tar::Archive;
 
-const MINIMUM_PROJ_VERSION: &str = "9.4.0";
+const MINIMUM_PROJ_VERSION: &str = "9.2.0";
+
+/// The release whose headers the bundled bindings were generated from
+const BINDINGS_VERSION: &str = "9.4.0";
+
+/// The PROJ source archive bundled with the crate
+const BUNDLED_ARCHIVE: &str = "PROJSRC/proj-9.4.0.tar.gz";
+
+/// Build from this directory of PROJ sources instead of the bundled archive
+const SOURCE_DIR_VAR: &str = "PROJ_SOURCE_DIR";
+
+/// Build from this `.tar.gz` archive of PROJ sources instead of the bundled archive
+const SOURCE_ARCHIVE_VAR: &str = "PROJ_SOURCE_ARCHIVE";
+
+/// The libtiff source archive built by static builds
+const TIFF_BUNDLED_ARCHIVE: &str = "PROJSRC/tiff-4.7.2.tar.gz";
+
+/// Build libtiff from this directory of sources in static builds
+const TIFF_SOURCE_DIR_VAR: &str = "TIFF_SOURCE_DIR";
+
+/// Build libtiff from this `.tar.gz` archive of sources in static builds
+const TIFF_SOURCE_ARCHIVE_VAR: &str = "TIFF_SOURCE_ARCHIVE";
+
+/// Use the `proj.h` header in this directory, bypassing pkg-config
+const INCLUDE_DIR_VAR: &str = "PROJ_SYS_INCLUDE_DIR";
+
+/// Link the libproj in this directory, bypassing pkg-config
+const LIB_DIR_VAR: &str = "PROJ_SYS_LIB_DIR";
+
+/// Link libproj statically when it is found using `PROJ_SYS_LIB_DIR`
+const STATIC_VAR: &str = "PROJ_SYS_STATIC";
 
 #[cfg(feature = "nobuild")]
-fn main() {} // Skip the build script on docs.rs
+fn main() {
+    // Skip the build script on docs.rs, documenting the API of the bundled bindings
+    emit_version(parse_version(BINDINGS_VERSION));
+}
 
 #[cfg(not(feature = "nobuild"))]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rustc-check-cfg=cfg(bundled_build)");
+    println!("cargo:rustc-check-cfg=cfg(static_proj)");
+    if cfg!(feature = "runtime-loading") {
+        eprintln!("feature flags specified loading libproj at runtime");
+        emit_version(parse_version(BINDINGS_VERSION));
+        return generate_runtime_bindings();
+    }
     let include_path = if cfg!(feature = "bundled_proj") {
         eprintln!("feature flags specified source build");
         build_from_source()?
+    } else if let Some(include_path) = find_from_env()? {
+        include_path
     } else {
         pkg_config::Config::new()
         .atleast_version(MINIMUM_PROJ_VERSION)
@@ -42,18 +84,185 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         })
         .or_else(|err| {
             eprintln!("pkg-config unable to find existing libproj installation: {err}");
+            find_vcpkg()
+        })
+        .or_else(|err| {
+            eprintln!("vcpkg unable to find existing libproj installation: {err}");
             build_from_source()
         })?
     };
 
+    let version = fs::read_to_string(include_path.join("proj.h"))
+        .map_err(|err| err.to_string())
+        .and_then(|header| header_version(&header))
+        .unwrap_or_else(|err| {
+            println!("cargo:warning=couldn't read the version of libproj, assuming {MINIMUM_PROJ_VERSION}: {err}");
+            parse_version(MINIMUM_PROJ_VERSION)
+        });
+    emit_version(version);
+
     #[cfg(feature = "buildtime_bindgen")]
     generate_bindings(include_path)?;
     #[cfg(not(feature = "buildtime_bindgen"))]
-    let _ = include_path;
+    {
+        let _ = include_path;
+        let bindings_path = pregenerated_bindings()?;
+        println!(
+            "cargo:rustc-env=PROJ_SYS_BINDINGS={}",
+            bindings_path.display()
+        );
+    }
 
     Ok(())
 }
 
+// returns the path of the bindings pregenerated for the target in src/bindings, or of the
+// bundled bindings if there are none
+//
+// The bindings are generated for each target by generate-bindings.sh, as the types of C enums
+// and of some integers differ between targets.
+fn pregenerated_bindings() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let src_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?).join("src");
+    let target = env::var("TARGET")?;
+    let bindings_dir = src_dir.join("bindings");
+    let target_path = bindings_dir.join(format!("{target}.rs"));
+    // rerun if bindings are added for the target
+    if bindings_dir.is_dir() {
+        println!("cargo:rerun-if-changed={}", bindings_dir.display());
+    }
+    let path = if target_path.exists() {
+        eprintln!("using the bindings pregenerated for {target}");
+        target_path
+    } else {
+        src_dir.join("bundled_bindings.rs")
+    };
+    println!("cargo:rerun-if-changed={}", path.display());
+    Ok(path)
+}
+
+// links the libproj found in the directories set using PROJ_SYS_INCLUDE_DIR and
+// PROJ_SYS_LIB_DIR, if any, and returns its "include" path
+//
+// This bypasses pkg-config, whose metadata may be wrong when cross-compiling. The directories
+// are checked, and the version of PROJ is read from proj.h.
+fn find_from_env() -> Result<Option<PathBuf>, Box<dyn std::error::Error>> {
+    for var in [INCLUDE_DIR_VAR, LIB_DIR_VAR, STATIC_VAR] {
+        println!("cargo:rerun-if-env-changed={var}");
+    }
+    let (include_dir, lib_dir) = match (env::var_os(INCLUDE_DIR_VAR), env::var_os(LIB_DIR_VAR)) {
+        (Some(include_dir), Some(lib_dir)) => (PathBuf::from(include_dir), PathBuf::from(lib_dir)),
+        (None, None) => return Ok(None),
+        _ => {
+            return Err(format!("{INCLUDE_DIR_VAR} and {LIB_DIR_VAR} must be set together").into())
+        }
+    };
+    let header = include_dir.join("proj.h");
+    let version = fs::read_to_string(&header)
+        .map_err(|err| format!("{INCLUDE_DIR_VAR} doesn't contain a readable proj.h: {err}"))
+        .and_then(|header| header_version(&header))?;
+    let (major, minor, patch) = version;
+    if version < parse_version(MINIMUM_PROJ_VERSION) {
+        return Err(format!(
+            "{header:?} is PROJ {major}.{minor}.{patch}, but {MINIMUM_PROJ_VERSION} or later is required"
+        )
+        .into());
+    }
+    let link_static = env::var(STATIC_VAR).is_ok_and(|val| val != "0");
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    let file_names: &[&str] = if target_env == "msvc" {
+        &["proj.lib"]
+    } else if link_static {
+        &["libproj.a"]
+    } else if target_os == "macos" || target_os == "ios" {
+        &["libproj.dylib", "libproj.a"]
+    } else if target_os == "windows" {
+        &["libproj.dll.a", "libproj.a"]
+    } else {
+        &["libproj.so", "libproj.a"]
+    };
+    if !file_names.iter().any(|name| lib_dir.join(name).is_file()) {
+        return Err(format!(
+            "{LIB_DIR_VAR} doesn't contain {}: {lib_dir:?}",
+            file_names[0]
+        )
+        .into());
+    }
+    eprintln!("using libproj {major}.{minor}.{patch} from {lib_dir:?} and {include_dir:?}");
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    if link_static {
+        // libsqlite3-sys and link-cplusplus provide the dependencies of a static libproj
+        println!("cargo:rustc-cfg=static_proj");
+        println!("cargo:rustc-link-lib=static=proj");
+        if cfg!(feature = "tiff") {
+            println!("cargo:rustc-link-lib=dylib=tiff");
+        }
+    } else {
+        println!("cargo:rustc-link-lib=proj");
+    }
+    Ok(Some(include_dir))
+}
+
+// reads the version defined by a proj.h header
+fn header_version(header: &str) -> Result<(u32, u32, u32), String> {
+    let define = |name: &str| {
+        header
+            .lines()
+            .find_map(|line| {
+                let mut words = line.split_whitespace();
+                match (words.next(), words.next(), words.next()) {
+                    (Some("#define"), Some(key), Some(value)) if key == name => value.parse().ok(),
+                    _ => None,
+                }
+            })
+            .ok_or_else(|| format!("proj.h doesn't define {name}"))
+    };
+    Ok((
+        define("PROJ_VERSION_MAJOR")?,
+        define("PROJ_VERSION_MINOR")?,
+        define("PROJ_VERSION_PATCH")?,
+    ))
+}
+
+// passes the version of libproj to the crates depending on proj-sys as DEP_PROJ_VERSION
+fn emit_version((major, minor, patch): (u32, u32, u32)) {
+    println!("cargo:version={major}.{minor}.{patch}");
+}
+
+fn parse_version(version: &str) -> (u32, u32, u32) {
+    let mut parts = version.split('.').map(|part| part.parse().unwrap_or(0));
+    let mut next = || parts.next().unwrap_or(0);
+    (next(), next(), next())
+}
+
+// finds libproj installed using vcpkg, such as proj:x64-windows, when targeting MSVC, and
+// returns its "include" path
+//
+// vcpkg doesn't record the version of the package, so it isn't checked. Set VCPKGRS_DISABLE
+// to skip this step.
+fn find_vcpkg() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if env::var("CARGO_CFG_TARGET_ENV").as_deref() != Ok("msvc") {
+        return Err("vcpkg is only used when targeting MSVC".into());
+    }
+    let library = vcpkg::Config::new()
+        .emit_includes(true)
+        .find_package("proj")?;
+    eprintln!(
+        "found libproj installed using vcpkg at: {:?}",
+        library.link_paths
+    );
+    if let Ok(val) = &env::var("_PROJ_SYS_TEST_EXPECT_BUILD_FROM_SRC") {
+        if val != "0" {
+            panic!("for testing purposes: existing package was found, but should not have been");
+        }
+    }
+    library
+        .include_paths
+        .first()
+        .cloned()
+        .ok_or_else(|| "the vcpkg package of libproj has no include path".into())
+}
+
 #[cfg(feature = "buildtime_bindgen")]
 fn generate_bindings(include_path: std::path::PathBuf) -> Result<(), Box<dyn std::error::Error>> {
     // The bindgen::Builder is the main entry point
@@ -82,6 +291,255 @@ fn generate_bindings(include_path: std::path::PathBuf) -> Result<(), Box<dyn std
     Ok(())
 }
 
+// writes $OUT_DIR/runtime_bindings.rs: the pregenerated bindings, with each function replaced by
+// a wrapper calling the function of the libproj loaded at runtime
+fn generate_runtime_bindings() -> Result<(), Box<dyn std::error::Error>> {
+    let bindings_path = pregenerated_bindings()?;
+    let bindings = fs::read_to_string(&bindings_path)?;
+    let mut output = String::new();
+    let mut lines = bindings.lines();
+    while let Some(line) = lines.next() {
+        if line != "extern \"C\" {" {
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        }
+        // bindgen declares each function in its own block
+        let block: Vec<&str> = lines.by_ref().take_while(|line| *line != "}").collect();
+        output.push_str(&runtime_wrapper(&block)?);
+    }
+    let out_path = PathBuf::from(env::var("OUT_DIR")?);
+    fs::write(out_path.join("runtime_bindings.rs"), output)?;
+    Ok(())
+}
+
+// converts the lines of an `extern "C"` block into a function looking the symbol up on its
+// first call, which does nothing and returns zeroes (such as a null pointer) if libproj isn't
+// loaded or doesn't export the function
+fn runtime_wrapper(block: &[&str]) -> Result<String, String> {
+    let (attributes, declaration): (Vec<&str>, Vec<&str>) = block
+        .iter()
+        .map(|line| line.trim())
+        .partition(|line| line.starts_with("#["));
+    let declaration = declaration.join(" ");
+    if declaration.starts_with("pub static ") {
+        // statics can't be loaded lazily, and are superseded by functions such as proj_info
+        return Ok(String::new());
+    }
+    let unexpected = || format!("unexpected declaration in the bindings: {declaration}");
+    let signature = declaration
+        .strip_prefix("pub fn ")
+        .and_then(|rest| rest.strip_suffix(';'))
+        .ok_or_else(unexpected)?;
+    let open = signature.find('(').ok_or_else(unexpected)?;
+    let name = &signature[..open];
+    // the parameters are split on the commas outside of function pointer types and generics
+    let mut depth = 0;
+    let mut params = vec![];
+    let mut start = open + 1;
+    let mut close = None;
+    let mut previous = ' ';
+    for (i, c) in signature.char_indices().skip(open + 1) {
+        let arrow = previous == '-';
+        previous = c;
+        match c {
+            '(' | '<' => depth += 1,
+            '>' if arrow => {}
+            '>' => depth -= 1,
+            ')' if depth == 0 => {
+                params.push(&signature[start..i]);
+                close = Some(i);
+                break;
+            }
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                params.push(&signature[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let close = close.ok_or_else(unexpected)?;
+    let output = signature[close + 1..].trim();
+    let params = params
+        .iter()
+        .map(|param| param.trim())
+        .filter(|param| !param.is_empty())
+        .map(|param| param.split_once(": ").ok_or_else(unexpected))
+        .collect::<Result<Vec<_>, _>>()?;
+    let join = |f: fn(&(&str, &str)) -> String| params.iter().map(f).collect::<Vec<_>>().join(", ");
+    let typed_params = join(|(param, ty)| format!("{param}: {ty}"));
+    let types = join(|(_, ty)| ty.to_string());
+    let names = join(|(param, _)| param.to_string());
+    let mut wrapper = String::new();
+    for attribute in attributes {
+        wrapper.push_str(attribute);
+        wrapper.push('\n');
+    }
+    wrapper.push_str(&format!(
+        "pub unsafe fn {name}({typed_params}) {output} {{\n    \
+         type Symbol = unsafe extern \"C\" fn({types}) {output};\n    \
+         static SYMBOL: ::std::sync::OnceLock<Symbol> = ::std::sync::OnceLock::new();\n    \
+         match crate::runtime::symbol(&SYMBOL, b\"{name}\\0\") {{\n        \
+         Some(symbol) => symbol({names}),\n        \
+         None => ::std::mem::zeroed(),\n    \
+         }}\n\
+         }}\n"
+    ));
+    Ok(wrapper)
+}
+
+// returns the path of the PROJ sources to build, unpacking them into $OUT_DIR/PROJSRC/proj
+// unless a source tree was provided
+fn source_dir(out_path: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    sources(
+        "PROJ",
+        SOURCE_DIR_VAR,
+        SOURCE_ARCHIVE_VAR,
+        Some(BUNDLED_ARCHIVE),
+        &out_path.join("PROJSRC/proj"),
+    )
+}
+
+// returns the path of the `library` sources found in the directory or archive named by the
+// environment variables, or else in the bundled archive if there is one, unpacking archives into
+// `unpack_path`
+fn sources(
+    library: &str,
+    dir_var: &str,
+    archive_var: &str,
+    bundled_archive: Option<&str>,
+    unpack_path: &Path,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-env-changed={dir_var}");
+    println!("cargo:rerun-if-env-changed={archive_var}");
+    if let Some(dir) = env::var_os(dir_var) {
+        let dir = PathBuf::from(dir);
+        if !dir.join("CMakeLists.txt").is_file() {
+            return Err(format!("{dir_var} is not a {library} source tree: {dir:?}").into());
+        }
+        eprintln!("using {library} sources from {dir:?}");
+        println!("cargo:rerun-if-changed={}", dir.display());
+        return Ok(dir);
+    }
+    let archive_path = match (env::var_os(archive_var), bundled_archive) {
+        (Some(path), _) => PathBuf::from(path),
+        (None, Some(bundled_archive)) => PathBuf::from(bundled_archive),
+        (None, None) => {
+            return Err(format!(
+                "{library} sources aren't bundled with the crate: set {dir_var} or {archive_var}"
+            )
+            .into())
+        }
+    };
+    if !archive_path.is_file() {
+        return Err(format!(
+            "{library} sources not found at {archive_path:?}: set {dir_var} or {archive_var}"
+        )
+        .into());
+    }
+    eprintln!("using {library} sources from {archive_path:?}");
+    println!("cargo:rerun-if-changed={}", archive_path.display());
+    // don't mix the sources of a previously unpacked archive with these
+    if unpack_path.exists() {
+        fs::remove_dir_all(unpack_path)?;
+    }
+    let tar_gz = File::open(&archive_path)?;
+    let tar = GzDecoder::new(tar_gz);
+    let mut archive = Archive::new(tar);
+    archive.unpack(unpack_path)?;
+    // release archives contain a single directory, such as proj-9.4.0
+    let mut dirs = fs::read_dir(unpack_path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    match dirs.pop() {
+        Some(dir) if dirs.is_empty() && dir.join("CMakeLists.txt").is_file() => Ok(dir),
+        _ => Err(format!("{archive_path:?} is not a {library} source archive").into()),
+    }
+}
+
+// builds a static libtiff, whose only dependency is the static zlib built by libz-sys, and
+// returns its installation directory
+fn build_static_tiff(out_path: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    eprintln!("building static libtiff from source");
+    let source = sources(
+        "libtiff",
+        TIFF_SOURCE_DIR_VAR,
+        TIFF_SOURCE_ARCHIVE_VAR,
+        Some(TIFF_BUNDLED_ARCHIVE),
+        &out_path.join("PROJSRC/tiff"),
+    )?;
+    let mut config = cmake::Config::new(source);
+    config.out_dir(out_path.join("tiff"));
+    config.define("BUILD_SHARED_LIBS", "OFF");
+    config.define("CMAKE_POSITION_INDEPENDENT_CODE", "ON");
+    // rather than lib64 on some distributions, as the library is looked for in lib
+    config.define("CMAKE_INSTALL_LIBDIR", "lib");
+    for option in [
+        "tiff-tools",
+        "tiff-tests",
+        "tiff-contrib",
+        "tiff-docs",
+        "tiff-opengl",
+    ] {
+        config.define(option, "OFF");
+    }
+    config.define("cxx", "OFF");
+    // codecs requiring other libraries; PROJ's grids only use deflate and LZW compression
+    for codec in [
+        "jpeg",
+        "old-jpeg",
+        "jbig",
+        "lerc",
+        "libdeflate",
+        "lzma",
+        "webp",
+        "zstd",
+    ] {
+        config.define(codec, "OFF");
+    }
+    config.define("zlib", "ON");
+    let zlib_include = env::var("DEP_Z_INCLUDE")?;
+    let zlib_root = env::var("DEP_Z_ROOT")?;
+    config.define("ZLIB_INCLUDE_DIR", &zlib_include);
+    config.define("ZLIB_LIBRARY", zlib_library(Path::new(&zlib_root))?);
+    if cfg!(target_env = "msvc") {
+        config.profile("Release");
+    }
+    Ok(config.build())
+}
+
+// returns the path of the static zlib built by libz-sys in `zlib_root`, whose name depends on
+// the target: libz.a, except on MSVC, where it is z.lib (or zlibstatic.lib when built by cmake)
+fn zlib_library(zlib_root: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let names: &[&str] = if env::var("CARGO_CFG_TARGET_ENV")? == "msvc" {
+        &["z.lib", "zlibstatic.lib", "zlib.lib"]
+    } else {
+        &["libz.a"]
+    };
+    let lib = zlib_root.join("lib");
+    names
+        .iter()
+        .map(|name| lib.join(name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| format!("no static zlib ({}) found in {lib:?}", names.join(", ")).into())
+}
+
+// returns the path of the static libtiff installed in `tiff_root` by build_static_tiff: libtiff.a,
+// except on MSVC, where it is tiff.lib
+fn tiff_library(tiff_root: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let name = if env::var("CARGO_CFG_TARGET_ENV")? == "msvc" {
+        "tiff.lib"
+    } else {
+        "libtiff.a"
+    };
+    let path = tiff_root.join("lib").join(name);
+    if !path.is_file() {
+        return Err(format!("the static libtiff wasn't found at {path:?}").into());
+    }
+    Ok(path)
+}
+
 // returns the path of "include" for the built proj
 fn build_from_source() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
     eprintln!("building libproj from source");
@@ -94,13 +552,8 @@ fn build_from_source() -> Result<std::path::PathBuf, Box<dyn std::error::Error>>
         }
     }
 
-    let path = "PROJSRC/proj-9.4.0.tar.gz";
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    let tar_gz = File::open(path)?;
-    let tar = GzDecoder::new(tar_gz);
-    let mut archive = Archive::new(tar);
-    archive.unpack(out_path.join("PROJSRC/proj"))?;
-    let mut config = cmake::Config::new(out_path.join("PROJSRC/proj/proj-9.4.0"));
+    let mut config = cmake::Config::new(source_dir(&out_path)?);
     config.define("BUILD_SHARED_LIBS", "OFF");
     config.define("BUILD_TESTING", "OFF");
     config.define("BUILD_CCT", "OFF");
@@ -126,6 +579,16 @@ fn build_from_source() -> Result<std::path::PathBuf, Box<dyn std::error::Error>>
         config.define("SQLITE3_LIBRARY", format!("{sqlite_lib_dir}/libsqlite3.a",));
     }
 
+    // a static libtiff, built and linked here rather than found on the system
+    let static_tiff = if cfg!(feature = "bundled_static") {
+        let tiff = build_static_tiff(&out_path)?;
+        config.define("TIFF_INCLUDE_DIR", tiff.join("include"));
+        config.define("TIFF_LIBRARY", tiff_library(&tiff)?);
+        Some(tiff)
+    } else {
+        None
+    };
+
     if cfg!(feature = "tiff") {
         eprintln!("enabling tiff support");
         config.define("ENABLE_TIFF", "ON");
@@ -168,7 +631,14 @@ fn build_from_source() -> Result<std::path::PathBuf, Box<dyn std::error::Error>>
         &out_path.join("build/lib").display()
     );
 
-    if cfg!(feature = "tiff") {
+    if let Some(tiff) = static_tiff {
+        println!(
+            "cargo:rustc-link-search=native={}",
+            tiff.join("lib").display()
+        );
+        // zlib is linked by libz-sys
+        println!("cargo:rustc-link-lib=static=tiff");
+    } else if cfg!(feature = "tiff") {
         // On platforms like apples aarch64, users are likely to have installed libtiff with homebrew,
         // which isn't in the default search path, so try to determine path from pkg-config
         match pkg_config::Config::new()
@@ -0,0 +1,41 @@
+#!/bin/sh
+# Generates the bindings pregenerated for each target in src/bindings, from the headers of the
+# bundled PROJ sources, so that building the crate doesn't require libclang.
+#
+# Requires the bindgen CLI (`cargo install bindgen-cli`) and libclang. Only clang's own headers
+# are used, so the bindings of every target can be generated on any host. Pass target triples
+# to only generate those.
+#
+# If you update the bindgen arguments here, you also need to update them in `build.rs`.
+set -eu
+
+cd "$(dirname "$0")"
+
+PROJ_VERSION=9.4.0
+TARGETS="
+aarch64-apple-darwin
+aarch64-unknown-linux-gnu
+aarch64-unknown-linux-musl
+x86_64-apple-darwin
+x86_64-pc-windows-msvc
+x86_64-unknown-linux-gnu
+x86_64-unknown-linux-musl
+"
+if [ $# -gt 0 ]; then
+    TARGETS="$*"
+fi
+
+sources=$(mktemp -d)
+trap 'rm -rf "$sources"' EXIT
+tar -xzf "PROJSRC/proj-$PROJ_VERSION.tar.gz" -C "$sources"
+
+mkdir -p src/bindings
+for target in $TARGETS; do
+    echo "generating the bindings for $target"
+    bindgen \
+        --distrust-clang-mangling \
+        --blocklist-type max_align_t \
+        --output "src/bindings/$target.rs" \
+        wrapper.h \
+        -- -I "$sources/proj-$PROJ_VERSION/src" --target="$target"
+done
@@ -16,6 +16,12 @@
 //! installation on your system using
 //! [pkg-config](https://www.freedesktop.org/wiki/Software/pkg-config/).
 //!
+//! Setting `PROJ_SYS_INCLUDE_DIR` and `PROJ_SYS_LIB_DIR` bypasses pkg-config, for
+//! instance when cross-compiling: see the README.
+//!
+//! When targeting MSVC, an installation made using [vcpkg](https://vcpkg.io), such
+//! as `proj:x64-windows`, is used if pkg-config can't find one: see the README.
+//!
 //! If an acceptable installation is not found, proj-sys will attempt to build
 //! libproj from source bundled in the crate.
 //!
@@ -27,17 +33,58 @@
 //! `libproj` **without** its native network functionality; you will have to
 //! implement your own set of callbacks if you wish to make use of them (see the
 //! [`proj`](https://crates.io/crates/proj) crate for an example).
+//!
+//! `bundled_static` - builds libproj from source, together with SQLite3, zlib and
+//! `libtiff`, and links them all statically, so that fully static binaries (for
+//! instance targeting musl) can read TIFF grids. The bundled `libtiff` sources can be
+//! replaced using `TIFF_SOURCE_DIR` or `TIFF_SOURCE_ARCHIVE`, as the PROJ sources can;
+//! only `cmake` and a C/C++ compiler are required.
+//!
+//! `runtime-loading` - doesn't link libproj, which is loaded when the program runs
+//! instead, from the path in `PROJ_SYS_LIBRARY` or by searching for its usual names,
+//! such as `libproj.so.25`. The same binary can then use the libproj installed on
+//! each system, or run without it: call `load_libproj` to find out whether it is
+//! available, as the functions do nothing and return zeroes, such as null pointers, if
+//! it isn't. The bundled bindings are used, and functions missing from an older libproj
+//! behave the same way.
 
-#[cfg(bundled_build)]
+#[cfg(any(bundled_build, static_proj))]
 extern crate libsqlite3_sys;
-#[cfg(bundled_build)]
+#[cfg(all(bundled_build, feature = "bundled_static"))]
+extern crate libz_sys;
+#[cfg(any(bundled_build, static_proj))]
 extern crate link_cplusplus;
 
-#[cfg(all(not(feature = "nobuild"), feature = "buildtime_bindgen"))]
+/// Whether libproj was built from the bundled source, rather than found on the system
+pub const BUNDLED: bool = cfg!(bundled_build);
+
+#[cfg(all(not(feature = "nobuild"), feature = "runtime-loading"))]
+mod runtime;
+#[cfg(all(not(feature = "nobuild"), feature = "runtime-loading"))]
+pub use runtime::{is_libproj_loaded, load_libproj, load_libproj_from, LoadError};
+
+#[cfg(all(not(feature = "nobuild"), feature = "runtime-loading"))]
+#[allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+mod runtime_bindings {
+    include!(concat!(env!("OUT_DIR"), "/runtime_bindings.rs"));
+}
+#[cfg(all(not(feature = "nobuild"), feature = "runtime-loading"))]
+pub use runtime_bindings::*;
+
+#[cfg(all(
+    not(feature = "nobuild"),
+    not(feature = "runtime-loading"),
+    feature = "buildtime_bindgen"
+))]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
 #[cfg(all(feature = "nobuild", not(feature = "buildtime_bindgen")))]
 include!("bindings_docs-rs.rs");
 
-#[cfg(all(not(feature = "nobuild"), not(feature = "buildtime_bindgen")))]
-include!("bundled_bindings.rs");
+// the bindings pregenerated for the target, or the bundled bindings, as chosen by the build script
+#[cfg(all(
+    not(feature = "nobuild"),
+    not(feature = "runtime-loading"),
+    not(feature = "buildtime_bindgen")
+))]
+include!(env!("PROJ_SYS_BINDINGS"));
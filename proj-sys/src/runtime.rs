@@ -0,0 +1,136 @@
+//! Loading of libproj when the program runs, with the `runtime-loading` feature
+use libloading::Library;
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::sync::OnceLock;
+
+/// The path of the libproj to load, instead of searching for it
+const LIBRARY_VAR: &str = "PROJ_SYS_LIBRARY";
+
+/// The names of libproj searched for in the paths of the dynamic loader
+#[cfg(target_os = "windows")]
+const LIBRARY_NAMES: &[&str] = &[
+    "proj.dll",
+    "proj_9.dll",
+    "proj_9_6.dll",
+    "proj_9_5.dll",
+    "proj_9_4.dll",
+    "proj_9_3.dll",
+    "proj_9_2.dll",
+];
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+const LIBRARY_NAMES: &[&str] = &["libproj.25.dylib", "libproj.dylib"];
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "ios")))]
+const LIBRARY_NAMES: &[&str] = &["libproj.so.25", "libproj.so"];
+
+static LIBRARY: OnceLock<Library> = OnceLock::new();
+/// The outcome of the search for libproj, which is only made once
+static SEARCH: OnceLock<Result<(), LoadError>> = OnceLock::new();
+
+/// The reason libproj couldn't be loaded
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoadError(String);
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Load libproj, unless it has already been loaded
+///
+/// The library at the path in `PROJ_SYS_LIBRARY` is loaded if it is set. Otherwise the usual
+/// names of libproj, such as `libproj.so.25`, are searched for by the dynamic loader. The
+/// search is only made once: later calls return its outcome. The functions of this crate load
+/// libproj when they are first called, and do nothing if it can't be loaded: call this function
+/// beforehand to handle its absence.
+pub fn load_libproj() -> Result<(), LoadError> {
+    if LIBRARY.get().is_some() {
+        return Ok(());
+    }
+    SEARCH.get_or_init(search).clone()
+}
+
+/// Search for libproj and load it
+fn search() -> Result<(), LoadError> {
+    let candidates: Vec<OsString> = match env::var_os(LIBRARY_VAR) {
+        Some(path) => vec![path],
+        None => LIBRARY_NAMES.iter().map(OsString::from).collect(),
+    };
+    let mut errors = vec![];
+    for candidate in candidates {
+        match open(&candidate) {
+            Ok(library) => {
+                // another thread may have loaded libproj in the meantime
+                let _ = LIBRARY.set(library);
+                return Ok(());
+            }
+            Err(err) => errors.push(err.0),
+        }
+    }
+    Err(LoadError(format!(
+        "couldn't load libproj: {}",
+        errors.join("; ")
+    )))
+}
+
+/// Load the libproj at `path`
+///
+/// Returns an error if libproj has already been loaded, from this path or any other.
+pub fn load_libproj_from<P: AsRef<OsStr>>(path: P) -> Result<(), LoadError> {
+    if LIBRARY.get().is_some() {
+        return Err(LoadError("libproj has already been loaded".to_string()));
+    }
+    let library = open(path.as_ref())?;
+    LIBRARY
+        .set(library)
+        .map_err(|_| LoadError("libproj has already been loaded".to_string()))
+}
+
+/// Whether libproj has been loaded
+pub fn is_libproj_loaded() -> bool {
+    LIBRARY.get().is_some()
+}
+
+fn open(path: &OsStr) -> Result<Library, LoadError> {
+    let describe = |err: libloading::Error| LoadError(format!("{path:?}: {err}"));
+    // libproj has no initialisation routines with side effects
+    let library = unsafe { Library::new(path) }.map_err(describe)?;
+    unsafe { library.get::<unsafe extern "C" fn()>(b"proj_info\0") }.map_err(describe)?;
+    Ok(library)
+}
+
+/// Return the function exported as `name`, which must be nul-terminated, loading libproj if
+/// needed, and keep it in `cache`
+///
+/// Returns `None` if libproj can't be loaded, or doesn't export the function, which can happen
+/// if it is older than the bindings.
+pub(crate) fn symbol<T: Copy>(cache: &OnceLock<T>, name: &[u8]) -> Option<T> {
+    if let Some(symbol) = cache.get() {
+        return Some(*symbol);
+    }
+    load_libproj().ok()?;
+    let symbol = *unsafe { LIBRARY.get()?.get::<T>(name) }.ok()?;
+    Some(*cache.get_or_init(|| symbol))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        assert!(load_libproj_from("/nonexistent/libproj.so").is_err());
+        load_libproj().unwrap();
+        assert!(is_libproj_loaded());
+        assert!(load_libproj_from(LIBRARY_NAMES[0]).is_err());
+        let info = unsafe { crate::proj_info() };
+        assert_eq!(info.major, 9);
+        let cache = OnceLock::new();
+        assert!(symbol::<unsafe extern "C" fn()>(&cache, b"proj_no_such_function\0").is_none());
+        assert!(cache.get().is_none());
+    }
+}
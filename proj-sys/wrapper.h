@@ -1 +1,3 @@
 #include <proj.h>
+/* declares the functions constructing CRSs before PROJ 9.4, which moved them to proj.h */
+#include <proj_experimental.h>
@@ -0,0 +1,271 @@
+//! Bulk transformation of the points of Apache Arrow arrays
+//!
+//! Dataframe libraries such as Polars and DataFusion store points as Arrow arrays: either a
+//! `FixedSizeList` of two or three `Float64` values, or a `Struct` with `Float64` fields named
+//! `x`, `y` and optionally `z`, as in the
+//! [GeoArrow](https://geoarrow.org/format.html) point layouts. The functions of this module
+//! transform every point of such an array at once, returning a new array of the same type:
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use std::sync::Arc;
+//!
+//! use arrow_array::{Array, Float64Array, StructArray};
+//! use arrow_schema::{DataType, Field};
+//! use proj::Proj;
+//!
+//! let points = StructArray::from(vec![
+//!     (
+//!         Arc::new(Field::new("x", DataType::Float64, false)),
+//!         Arc::new(Float64Array::from(vec![4760096.421921, 4760197.421921])) as _,
+//!     ),
+//!     (
+//!         Arc::new(Field::new("y", DataType::Float64, false)),
+//!         Arc::new(Float64Array::from(vec![3744293.729449, 3744394.729449])) as _,
+//!     ),
+//! ]);
+//! let nad_ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+//! let transformed = proj::arrow::transform_struct(&points, &nad_ft_to_m).unwrap();
+//! let x = transformed.column_by_name("x").unwrap();
+//! let x = x.as_any().downcast_ref::<Float64Array>().unwrap();
+//! assert_relative_eq!(x.value(0), 1450880.29, epsilon = 1.0e-2);
+//! ```
+//!
+//! The coordinates are copied once into a buffer which becomes the values of the new array, and
+//! converted in place using [`CoordTransform::convert_array`]. Null points, and points with a
+//! null coordinate, are left untouched.
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, FixedSizeListArray, Float64Array, StructArray};
+use arrow_buffer::{BooleanBuffer, NullBuffer, ScalarBuffer};
+use arrow_schema::DataType;
+
+use crate::{CoordTransform, ProjError};
+
+/// Transform the points of a `FixedSizeList` or `Struct` array
+///
+/// See [`transform_fixed_size_list`] and [`transform_struct`] for the layouts of the arrays.
+pub fn transform_array<P: CoordTransform>(
+    array: &dyn Array,
+    proj: &P,
+) -> Result<ArrayRef, ProjError> {
+    if let Some(array) = array.as_any().downcast_ref::<FixedSizeListArray>() {
+        Ok(Arc::new(transform_fixed_size_list(array, proj)?))
+    } else if let Some(array) = array.as_any().downcast_ref::<StructArray>() {
+        Ok(Arc::new(transform_struct(array, proj)?))
+    } else {
+        Err(ProjError::ArrowArray(format!(
+            "{} isn't an array of points",
+            array.data_type()
+        )))
+    }
+}
+
+/// Transform the points of a `FixedSizeList` array of two or three `Float64` values, the
+/// third one being the height
+pub fn transform_fixed_size_list<P: CoordTransform>(
+    array: &FixedSizeListArray,
+    proj: &P,
+) -> Result<FixedSizeListArray, ProjError> {
+    let DataType::FixedSizeList(field, size) = array.data_type() else {
+        unreachable!("a FixedSizeListArray has a FixedSizeList type");
+    };
+    let dims = *size as usize;
+    if dims != 2 && dims != 3 {
+        return Err(ProjError::ArrowArray(format!(
+            "points must have 2 or 3 coordinates, not {}",
+            size
+        )));
+    }
+    let values = float64(array.values())?;
+    let offset = |i: usize| array.value_offset(i) as usize;
+    let mut points: Vec<_> = (0..array.len())
+        .map(|i| {
+            let coord = |d: usize| {
+                if d < dims {
+                    values.value(offset(i) + d)
+                } else {
+                    0.0
+                }
+            };
+            (coord(0), coord(1), coord(2))
+        })
+        .collect();
+    let valid = values.nulls().map(|nulls| {
+        let valid = BooleanBuffer::collect_bool(array.len(), |i| {
+            (0..dims).all(|d| nulls.is_valid(offset(i) + d))
+        });
+        NullBuffer::new(valid)
+    });
+    convert_valid(
+        proj,
+        &mut points,
+        NullBuffer::union(array.nulls(), valid.as_ref()),
+    )?;
+    let mut coords = Vec::with_capacity(points.len() * dims);
+    for (x, y, z) in points {
+        coords.extend_from_slice(&[x, y, z][..dims]);
+    }
+    let values = Float64Array::new(ScalarBuffer::from(coords), values.nulls().cloned());
+    Ok(FixedSizeListArray::new(
+        Arc::clone(field),
+        *size,
+        Arc::new(values),
+        array.nulls().cloned(),
+    ))
+}
+
+/// Transform the points of a `Struct` array with `Float64` fields named `x`, `y` and optionally
+/// `z`, the height
+///
+/// The other fields are kept as they are.
+pub fn transform_struct<P: CoordTransform>(
+    array: &StructArray,
+    proj: &P,
+) -> Result<StructArray, ProjError> {
+    let column = |name: &str| {
+        let (index, _) = array.fields().find(name)?;
+        Some(float64(array.column(index)).map(|column| (index, column)))
+    };
+    let missing = || ProjError::ArrowArray("the points need x and y fields".to_string());
+    let (ix, x) = column("x").ok_or_else(missing)??;
+    let (iy, y) = column("y").ok_or_else(missing)??;
+    let z = column("z").transpose()?;
+    let mut points: Vec<_> = (0..array.len())
+        .map(|i| (x.value(i), y.value(i), z.map_or(0.0, |(_, z)| z.value(i))))
+        .collect();
+    let valid = [Some(x), Some(y), z.map(|(_, z)| z)]
+        .iter()
+        .flatten()
+        .fold(array.nulls().cloned(), |valid, column| {
+            NullBuffer::union(valid.as_ref(), column.nulls())
+        });
+    convert_valid(proj, &mut points, valid)?;
+    let rebuilt = |column: &Float64Array, coord: fn(&(f64, f64, f64)) -> f64| -> ArrayRef {
+        let coords: Vec<_> = points.iter().map(coord).collect();
+        Arc::new(Float64Array::new(
+            ScalarBuffer::from(coords),
+            column.nulls().cloned(),
+        ))
+    };
+    let mut columns = array.columns().to_vec();
+    columns[ix] = rebuilt(x, |point| point.0);
+    columns[iy] = rebuilt(y, |point| point.1);
+    if let Some((iz, z)) = z {
+        columns[iz] = rebuilt(z, |point| point.2);
+    }
+    Ok(StructArray::new(
+        array.fields().clone(),
+        columns,
+        array.nulls().cloned(),
+    ))
+}
+
+/// The values of an array of coordinates
+fn float64(array: &ArrayRef) -> Result<&Float64Array, ProjError> {
+    array
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .ok_or_else(|| {
+            ProjError::ArrowArray(format!(
+                "coordinates must be Float64 values, not {}",
+                array.data_type()
+            ))
+        })
+}
+
+/// Convert the points which aren't null, in runs of consecutive valid points
+fn convert_valid<P: CoordTransform>(
+    proj: &P,
+    points: &mut [(f64, f64, f64)],
+    nulls: Option<NullBuffer>,
+) -> Result<(), ProjError> {
+    match nulls {
+        None => {
+            proj.convert_array(points)?;
+        }
+        Some(nulls) => {
+            for (start, end) in nulls.valid_slices() {
+                proj.convert_array(&mut points[start..end])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Proj;
+    use arrow_array::StringArray;
+    use arrow_schema::Field;
+
+    #[test]
+    fn test_transform_arrow() {
+        let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let expected = to_merc.convert((2.0, 48.0)).unwrap();
+
+        // a sliced list of points, with a null point
+        let field = Arc::new(Field::new("xy", DataType::Float64, false));
+        let values = Float64Array::from(vec![0.0, 0.0, 2.0, 48.0, 0.0, 91.0, 2.0, 48.0]);
+        let nulls = NullBuffer::from(vec![true, true, false, true]);
+        let list = FixedSizeListArray::new(field, 2, Arc::new(values), Some(nulls)).slice(1, 3);
+        let transformed = transform_array(&list, &to_merc).unwrap();
+        let transformed = transformed
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(transformed.len(), 3);
+        assert!(transformed.is_null(1));
+        for i in [0, 2] {
+            let point = transformed.value(i);
+            let point = point.as_any().downcast_ref::<Float64Array>().unwrap();
+            assert_eq!((point.value(0), point.value(1)), expected);
+        }
+        // the invalid latitude of the null point wasn't transformed
+        let point = transformed.value(1);
+        let point = point.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(point.value(1), 91.0);
+
+        // points with a height, and another field
+        let to_feet = Proj::new("+proj=unitconvert +z_in=m +z_out=ft").unwrap();
+        let points = StructArray::from(vec![
+            (
+                Arc::new(Field::new("name", DataType::Utf8, false)),
+                Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("x", DataType::Float64, false)),
+                Arc::new(Float64Array::from(vec![2.0, 3.0])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("y", DataType::Float64, false)),
+                Arc::new(Float64Array::from(vec![48.0, 49.0])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("z", DataType::Float64, true)),
+                Arc::new(Float64Array::from(vec![Some(100.0), None])) as ArrayRef,
+            ),
+        ]);
+        let transformed = transform_struct(&points, &to_feet).unwrap();
+        assert_eq!(transformed.column(0).as_ref(), points.column(0).as_ref());
+        let z = transformed.column_by_name("z").unwrap();
+        let z = z.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_relative_eq!(z.value(0), 328.0839895, epsilon = 1e-6);
+        assert!(z.is_null(1));
+
+        // arrays which aren't arrays of points
+        assert!(matches!(
+            transform_array(&Float64Array::from(vec![1.0]), &to_merc),
+            Err(ProjError::ArrowArray(_))
+        ));
+        let missing_y = StructArray::from(vec![(
+            Arc::new(Field::new("x", DataType::Float64, false)),
+            Arc::new(Float64Array::from(vec![1.0])) as ArrayRef,
+        )]);
+        assert!(matches!(
+            transform_struct(&missing_y, &to_merc),
+            Err(ProjError::ArrowArray(_))
+        ));
+    }
+}
@@ -0,0 +1,397 @@
+//! A record of the transformations created by the process
+//!
+//! Applications which must be able to prove how their output coordinates were computed, such as
+//! in regulated surveying, can install an [`AuditSink`] using [`set_sink`]. Every
+//! transformation created afterwards, by any thread, is then described to the sink: its source
+//! and target CRSs, and the operations PROJ may use, with their definition, accuracy and grids.
+//!
+//! ```rust
+//! use std::sync::{Arc, Mutex};
+//! use proj::audit::{self, AuditRecord};
+//! use proj::Proj;
+//!
+//! let records = Arc::new(Mutex::new(vec![]));
+//! let log = records.clone();
+//! audit::set_sink(move |record: &AuditRecord| log.lock().unwrap().push(record.clone()));
+//!
+//! let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+//! audit::clear_sink();
+//!
+//! let records = records.lock().unwrap();
+//! let record = records.iter().find(|r| r.target.as_deref() == Some("EPSG:3857")).unwrap();
+//! assert_eq!(record.source.as_deref(), Some("EPSG:4326"));
+//! assert!(record.operations[0].definition.as_ref().unwrap().contains("proj=webmerc"));
+//! ```
+//!
+//! With the `audit-sqlite` feature, records can be stored in an SQLite database using a
+//! [`SqliteSink`].
+use std::ptr;
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+
+use proj_sys::{
+    proj_as_proj_string, proj_context_is_network_enabled, proj_coordoperation_get_accuracy,
+    proj_create_operation_factory_context, proj_create_operations, proj_destroy, proj_errno_reset,
+    proj_get_id_auth_name, proj_get_id_code, proj_get_name, proj_get_source_crs,
+    proj_get_target_crs, proj_get_type, proj_is_crs, proj_list_destroy, proj_list_get,
+    proj_list_get_count, proj_operation_factory_context_destroy,
+    proj_operation_factory_context_set_area_of_interest,
+    proj_operation_factory_context_set_grid_availability_use,
+    proj_operation_factory_context_set_spatial_criterion, PJ, PJ_PROJ_STRING_TYPE_PJ_PROJ_5,
+    PJ_TYPE_PJ_TYPE_CONCATENATED_OPERATION, PJ_TYPE_PJ_TYPE_CONVERSION,
+    PJ_TYPE_PJ_TYPE_OTHER_COORDINATE_OPERATION, PJ_TYPE_PJ_TYPE_TRANSFORMATION,
+    PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_DISCARD_OPERATION_IF_MISSING_GRID,
+    PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_KNOWN_AVAILABLE,
+    PROJ_SPATIAL_CRITERION_PROJ_SPATIAL_CRITERION_PARTIAL_INTERSECTION,
+};
+
+use crate::grid::add_operation_grids;
+use crate::proj::_string;
+use crate::{Accuracy, Area, Context, PjPtrExt};
+
+static SINK: RwLock<Option<Arc<dyn AuditSink>>> = RwLock::new(None);
+
+/// A destination for audit records
+///
+/// Sinks are called synchronously by the thread creating the transformation, and must handle
+/// their own errors. Closures taking an [`AuditRecord`] are sinks.
+pub trait AuditSink: Send + Sync {
+    fn record(&self, record: &AuditRecord);
+}
+
+impl<F> AuditSink for F
+where
+    F: Fn(&AuditRecord) + Send + Sync,
+{
+    fn record(&self, record: &AuditRecord) {
+        self(record)
+    }
+}
+
+/// The description of a created transformation
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditRecord {
+    /// When the transformation was created
+    pub time: SystemTime,
+    /// The source CRS, as an authority code such as `EPSG:4326` if it has one, or its name
+    pub source: Option<String>,
+    /// The target CRS, as an authority code if it has one, or its name
+    pub target: Option<String>,
+    /// The area of interest used to select the operations
+    pub area: Option<Area>,
+    /// The operations PROJ chooses from when transforming a coordinate, in order of preference
+    ///
+    /// A transformation defined by a PROJ string or a pipeline has a single operation.
+    pub operations: Vec<AuditedOperation>,
+}
+
+/// A coordinate operation which may be used by an audited transformation
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditedOperation {
+    /// The name of the operation
+    pub name: Option<String>,
+    /// The PROJ string definition of the operation, if it can be represented as one
+    pub definition: Option<String>,
+    /// The accuracy declared by the metadata of the operation
+    pub accuracy: Accuracy,
+    /// The names of the grids used by the operation
+    pub grids: Vec<String>,
+}
+
+/// Describe every transformation created from now on to `sink`, replacing the current sink
+pub fn set_sink<S>(sink: S)
+where
+    S: AuditSink + 'static,
+{
+    *SINK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(sink));
+}
+
+/// Stop describing the created transformations
+pub fn clear_sink() {
+    SINK.write().unwrap_or_else(|e| e.into_inner()).take();
+}
+
+/// Describe a new transformation to the sink, if one is set
+///
+/// A transformation between CRSs must be described before its axis order is normalised, which
+/// replaces its CRSs with anonymous ones.
+pub(crate) fn record(ctx: &Context, op: *mut PJ, area: Option<Area>) {
+    let sink = SINK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(sink) = sink {
+        let record = describe(ctx, op, area);
+        // describing the operation mustn't leave errors behind
+        unsafe { proj_errno_reset(op) };
+        if let Some(record) = record {
+            sink.record(&record);
+        }
+    }
+}
+
+/// Describe a transformation, or return `None` if `op` isn't a coordinate operation
+fn describe(ctx: &Context, op: *const PJ, area: Option<Area>) -> Option<AuditRecord> {
+    unsafe {
+        if proj_is_crs(op) == 1 {
+            return None;
+        }
+        let source = proj_get_source_crs(ctx.as_ptr(), op);
+        let target = proj_get_target_crs(ctx.as_ptr(), op);
+        let record = if source.is_null() || target.is_null() {
+            let operation_type = proj_get_type(op);
+            let is_operation = [
+                PJ_TYPE_PJ_TYPE_CONVERSION,
+                PJ_TYPE_PJ_TYPE_TRANSFORMATION,
+                PJ_TYPE_PJ_TYPE_CONCATENATED_OPERATION,
+                PJ_TYPE_PJ_TYPE_OTHER_COORDINATE_OPERATION,
+            ]
+            .contains(&operation_type);
+            is_operation.then(|| AuditRecord {
+                time: SystemTime::now(),
+                source: None,
+                target: None,
+                area,
+                operations: vec![describe_operation(ctx, op)],
+            })
+        } else {
+            Some(AuditRecord {
+                time: SystemTime::now(),
+                source: identify(source),
+                target: identify(target),
+                area,
+                operations: candidate_operations(ctx, source, target, area),
+            })
+        };
+        proj_destroy(source);
+        proj_destroy(target);
+        record
+    }
+}
+
+/// The authority code of a CRS, or its name
+unsafe fn identify(crs: *const PJ) -> Option<String> {
+    let auth = proj_get_id_auth_name(crs, 0);
+    let code = proj_get_id_code(crs, 0);
+    if !auth.is_null() && !code.is_null() {
+        if let (Ok(auth), Ok(code)) = (_string(auth), _string(code)) {
+            return Some(format!("{}:{}", auth, code));
+        }
+    }
+    let name = proj_get_name(crs);
+    if name.is_null() {
+        None
+    } else {
+        _string(name).ok()
+    }
+}
+
+/// The operations between two CRSs, selected as `proj_create_crs_to_crs` does
+unsafe fn candidate_operations(
+    ctx: &Context,
+    source: *const PJ,
+    target: *const PJ,
+    area: Option<Area>,
+) -> Vec<AuditedOperation> {
+    let factory = proj_create_operation_factory_context(ctx.as_ptr(), ptr::null());
+    if let Some(area) = area {
+        proj_operation_factory_context_set_area_of_interest(
+            ctx.as_ptr(),
+            factory,
+            area.west,
+            area.south,
+            area.east,
+            area.north,
+        );
+    }
+    proj_operation_factory_context_set_spatial_criterion(
+        ctx.as_ptr(),
+        factory,
+        PROJ_SPATIAL_CRITERION_PROJ_SPATIAL_CRITERION_PARTIAL_INTERSECTION,
+    );
+    let grid_availability = if proj_context_is_network_enabled(ctx.as_ptr()) == 1 {
+        PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_KNOWN_AVAILABLE
+    } else {
+        PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_DISCARD_OPERATION_IF_MISSING_GRID
+    };
+    proj_operation_factory_context_set_grid_availability_use(
+        ctx.as_ptr(),
+        factory,
+        grid_availability,
+    );
+    let list = proj_create_operations(ctx.as_ptr(), source, target, factory);
+    proj_operation_factory_context_destroy(factory);
+    if list.is_null() {
+        return vec![];
+    }
+    let operations = (0..proj_list_get_count(list))
+        .filter_map(|i| proj_list_get(ctx.as_ptr(), list, i).into_pj_ref(ctx).ok())
+        .map(|op| describe_operation(ctx, op.as_ptr()))
+        .collect();
+    proj_list_destroy(list);
+    operations
+}
+
+unsafe fn describe_operation(ctx: &Context, op: *const PJ) -> AuditedOperation {
+    let optional = |s: *const libc::c_char| {
+        if s.is_null() {
+            None
+        } else {
+            _string(s).ok()
+        }
+    };
+    let definition =
+        proj_as_proj_string(ctx.as_ptr(), op, PJ_PROJ_STRING_TYPE_PJ_PROJ_5, ptr::null());
+    let mut grids = vec![];
+    add_operation_grids(ctx, op, &mut grids);
+    AuditedOperation {
+        name: optional(proj_get_name(op)),
+        definition: optional(definition),
+        accuracy: Accuracy::from(proj_coordoperation_get_accuracy(ctx.as_ptr(), op)),
+        grids: grids.into_iter().map(|grid| grid.name).collect(),
+    }
+}
+
+/// A sink storing records in an SQLite database
+///
+/// Each transformation is a row of the `transformations` table, and each of its candidate
+/// operations a row of the `operations` table referencing it. Times are in seconds since the
+/// Unix epoch, and grid names are separated by commas. Records which can't be written are
+/// logged as errors.
+///
+/// ```rust
+/// use proj::audit::{self, SqliteSink};
+/// use proj::Proj;
+///
+/// # let dir = std::env::temp_dir().join("proj-audit-doctest");
+/// # std::fs::create_dir_all(&dir).unwrap();
+/// # let path = dir.join("audit.sqlite");
+/// audit::set_sink(SqliteSink::open(&path).unwrap());
+/// let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+/// audit::clear_sink();
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "audit-sqlite")))]
+#[cfg(feature = "audit-sqlite")]
+pub struct SqliteSink {
+    connection: std::sync::Mutex<rusqlite::Connection>,
+}
+
+#[cfg(feature = "audit-sqlite")]
+impl SqliteSink {
+    /// Open or create an audit database, creating its tables if needed
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Self, crate::ProjError> {
+        let connection = rusqlite::Connection::open(path)?;
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS transformations (
+                id INTEGER PRIMARY KEY,
+                time REAL NOT NULL,
+                source TEXT,
+                target TEXT,
+                west REAL,
+                south REAL,
+                east REAL,
+                north REAL
+            );
+            CREATE TABLE IF NOT EXISTS operations (
+                transformation_id INTEGER NOT NULL REFERENCES transformations(id),
+                position INTEGER NOT NULL,
+                name TEXT,
+                definition TEXT,
+                accuracy REAL,
+                grids TEXT NOT NULL
+            );",
+        )?;
+        Ok(SqliteSink {
+            connection: std::sync::Mutex::new(connection),
+        })
+    }
+
+    fn insert(&self, record: &AuditRecord) -> Result<(), rusqlite::Error> {
+        let mut connection = self.connection.lock().unwrap_or_else(|e| e.into_inner());
+        let transaction = connection.transaction()?;
+        let time = record
+            .time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0.0, |time| time.as_secs_f64());
+        let area = record.area;
+        transaction.execute(
+            "INSERT INTO transformations (time, source, target, west, south, east, north)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![
+                time,
+                record.source,
+                record.target,
+                area.map(|area| area.west),
+                area.map(|area| area.south),
+                area.map(|area| area.east),
+                area.map(|area| area.north),
+            ],
+        )?;
+        let id = transaction.last_insert_rowid();
+        for (position, operation) in record.operations.iter().enumerate() {
+            transaction.execute(
+                "INSERT INTO operations
+                 (transformation_id, position, name, definition, accuracy, grids)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                rusqlite::params![
+                    id,
+                    position as i64,
+                    operation.name,
+                    operation.definition,
+                    operation.accuracy.metres(),
+                    operation.grids.join(","),
+                ],
+            )?;
+        }
+        transaction.commit()
+    }
+}
+
+#[cfg(feature = "audit-sqlite")]
+impl AuditSink for SqliteSink {
+    fn record(&self, record: &AuditRecord) {
+        if let Err(e) = self.insert(record) {
+            log::error!("Couldn't write to the transformation audit log: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Proj;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_record() {
+        let records = Arc::new(Mutex::new(vec![]));
+        let log = records.clone();
+        set_sink(move |record: &AuditRecord| log.lock().unwrap().push(record.clone()));
+        let area = Area::new(-124.0, 25.0, -67.0, 49.0);
+        Proj::new_known_crs("EPSG:4267", "EPSG:4269", Some(area)).unwrap();
+        Proj::new("+proj=pipeline +step +proj=unitconvert +xy_in=deg +xy_out=rad").unwrap();
+        // CRSs aren't transformations
+        Proj::new("EPSG:4326").unwrap();
+        clear_sink();
+        Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+
+        // sinks are process-wide, so other tests may have been recorded too
+        let records = records.lock().unwrap();
+        let nad = records
+            .iter()
+            .find(|r| r.source.as_deref() == Some("EPSG:4267"))
+            .unwrap();
+        assert_eq!(nad.target.as_deref(), Some("EPSG:4269"));
+        assert_eq!(nad.area, Some(area));
+        assert!(!nad.operations.is_empty());
+        assert!(nad
+            .operations
+            .iter()
+            .all(|op| op.name.is_some() && op.definition.is_some()));
+        assert!(records.iter().any(|r| r.source.is_none()
+            && r.operations.len() == 1
+            && r.operations[0].definition.as_deref()
+                == Some("+proj=unitconvert +xy_in=deg +xy_out=rad")));
+        assert!(!records
+            .iter()
+            .any(|r| r.source.as_deref() == Some("EPSG:4326")
+                && r.target.as_deref() == Some("EPSG:3857")));
+    }
+}
@@ -0,0 +1,167 @@
+//! Conversions between CRSs given by their definitions, reusing cached transformations
+//!
+//! Creating a transformation between two CRSs queries the PROJ database, which is much slower
+//! than converting a few points. Services converting coordinates between arbitrary CRS pairs
+//! can use [`convert_between`] instead of creating a [`Proj`] for each request: the
+//! transformations are kept in a cache of the most recently used pairs.
+//!
+//! Since a [`Proj`] can't be shared between threads, each thread has its own cache. Their
+//! capacity is set for all threads using [`set_cache_size`].
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use proj::batch;
+//!
+//! let mut points = [(2.3522, 48.8566)];
+//! batch::convert_between("EPSG:4326", "EPSG:3857", &mut points).unwrap();
+//! assert_relative_eq!(points[0].0, 261845.70624393807, epsilon = 1e-6);
+//! // the transformation is reused
+//! let mut points = [(4.8357, 45.7640)];
+//! batch::convert_between("EPSG:4326", "EPSG:3857", &mut points).unwrap();
+//! ```
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::proj::CoordinateType;
+use crate::{Coord, Proj, ProjCreateError, TransformError};
+
+/// The number of transformations cached by each thread, unless set otherwise
+pub const DEFAULT_CACHE_SIZE: usize = 16;
+
+static CACHE_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_CACHE_SIZE);
+
+thread_local! {
+    /// The cached transformations, the most recently used last
+    static CACHE: RefCell<Vec<(String, String, Rc<Proj>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Set the number of transformations cached by each thread
+///
+/// Caches holding more transformations are trimmed when they're next used. A size of `0`
+/// disables caching.
+pub fn set_cache_size(size: usize) {
+    CACHE_SIZE.store(size, Ordering::Relaxed);
+}
+
+/// The number of transformations cached by each thread
+pub fn cache_size() -> usize {
+    CACHE_SIZE.load(Ordering::Relaxed)
+}
+
+/// Remove the transformations cached by the current thread
+pub fn clear_cache() {
+    CACHE.with(|cache| cache.borrow_mut().clear());
+}
+
+/// Return the transformation from `from` to `to`, creating it if it isn't cached
+fn cached(from: &str, to: &str) -> Result<Rc<Proj>, ProjCreateError> {
+    let capacity = cache_size();
+    let found = CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let position = cache
+            .iter()
+            .position(|(source, target, _)| source == from && target == to)?;
+        let entry = cache.remove(position);
+        let proj = Rc::clone(&entry.2);
+        cache.push(entry);
+        Some(proj)
+    });
+    let proj = match found {
+        Some(proj) => proj,
+        None => Rc::new(Proj::new_known_crs(from, to, None)?),
+    };
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if !cache.iter().any(|(_, _, cached)| Rc::ptr_eq(cached, &proj)) {
+            cache.push((from.to_string(), to.to_string(), Rc::clone(&proj)));
+        }
+        let excess = cache.len().saturating_sub(capacity);
+        cache.drain(..excess);
+    });
+    Ok(proj)
+}
+
+/// Call `f` with the transformation from `from` to `to`, as created by
+/// [`Proj::new_known_crs`]
+///
+/// The transformation is taken from the cache of the current thread, or created and cached.
+pub fn with_proj<R, F>(from: &str, to: &str, f: F) -> Result<R, ProjCreateError>
+where
+    F: FnOnce(&Proj) -> R,
+{
+    let proj = cached(from, to)?;
+    Ok(f(&proj))
+}
+
+/// Convert `points` in place from `from` to `to`, as
+/// [`Proj::convert_array`] would with a transformation created by [`Proj::new_known_crs`]
+///
+/// The transformation is taken from the cache of the current thread, or created and cached.
+pub fn convert_between<'a, C, F>(
+    from: &str,
+    to: &str,
+    points: &'a mut [C],
+) -> Result<&'a mut [C], TransformError>
+where
+    C: Coord<F>,
+    F: CoordinateType,
+{
+    let proj = cached(from, to)?;
+    Ok(proj.convert_array(points)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cached_pairs() -> Vec<(String, String)> {
+        CACHE.with(|cache| {
+            cache
+                .borrow()
+                .iter()
+                .map(|(from, to, _)| (from.clone(), to.clone()))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_cache() {
+        let pair = |from: &str, to: &str| (from.to_string(), to.to_string());
+        clear_cache();
+        assert_eq!(cache_size(), DEFAULT_CACHE_SIZE);
+        let mut points = [(4760096.421921, 3744293.729449)];
+        convert_between("EPSG:2230", "EPSG:26946", &mut points).unwrap();
+        assert_relative_eq!(points[0].0, 1450880.29, epsilon = 1e-2);
+        let first = with_proj("EPSG:2230", "EPSG:26946", |proj| proj.as_ptr()).unwrap();
+        assert_eq!(cached_pairs(), vec![pair("EPSG:2230", "EPSG:26946")]);
+
+        let mut points = [(2.3522, 48.8566)];
+        convert_between("EPSG:4326", "EPSG:3857", &mut points).unwrap();
+        // the least recently used transformation is evicted first
+        with_proj("EPSG:2230", "EPSG:26946", |_| ()).unwrap();
+        set_cache_size(2);
+        convert_between("EPSG:4326", "EPSG:32631", &mut [(2.3522, 48.8566)]).unwrap();
+        set_cache_size(DEFAULT_CACHE_SIZE);
+        assert_eq!(
+            cached_pairs(),
+            vec![
+                pair("EPSG:2230", "EPSG:26946"),
+                pair("EPSG:4326", "EPSG:32631")
+            ]
+        );
+        let again = with_proj("EPSG:2230", "EPSG:26946", |proj| proj.as_ptr()).unwrap();
+        assert_eq!(first, again);
+
+        assert!(matches!(
+            convert_between("EPSG:4326", "EPSG:0", &mut [(0.0, 0.0)]),
+            Err(TransformError::ProjCreateError(_))
+        ));
+        assert!(matches!(
+            convert_between("EPSG:4326", "EPSG:3857", &mut [(100.0, 100.0)]),
+            Err(TransformError::ProjError(_))
+        ));
+        clear_cache();
+        assert!(cached_pairs().is_empty());
+    }
+}
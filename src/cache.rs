@@ -0,0 +1,118 @@
+//! A grid cache directory shared by several processes, such as the replicas of a deployment
+//!
+//! PROJ's cache of downloaded grid chunks (`cache.db`) is an SQLite database, whose own file
+//! locking serialises writes from concurrent processes. Complete grids are downloaded into the
+//! same directory by [`ProjBuilder::warm_cache`](crate::ProjBuilder::warm_cache) while holding an
+//! advisory lock on a `.lock` file, so concurrent jobs don't download the same grids, and are
+//! moved into place once complete, so readers never find a partial file.
+//!
+//! The lock is only taken on Unix: elsewhere, concurrent jobs may download the same grid, each
+//! into its own partial file, and the last one to complete replaces the others.
+use std::cell::RefCell;
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use crate::grid::{grids_used, GridUsage};
+use crate::net::{download_file, NetworkSettings};
+use crate::{Area, Context, ProjError};
+
+const LOCK_FILE: &str = ".lock";
+
+/// An exclusive advisory lock on a cache directory, released when dropped
+///
+/// On platforms other than Unix, the lock file is created but not locked.
+struct DirLock {
+    _file: File,
+}
+
+impl DirLock {
+    /// Block until the lock is acquired
+    fn acquire(dir: &Path) -> Result<Self, ProjError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(dir.join(LOCK_FILE))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::io::AsRawFd;
+            if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+        }
+        Ok(Self { _file: file })
+    }
+}
+
+/// Download the grids used by the candidate operations between each pair of CRSs into `dir`,
+/// returning their paths
+pub(crate) fn warm(
+    ctx: &Context,
+    settings: &RefCell<NetworkSettings>,
+    dir: &Path,
+    area: Option<Area>,
+    crs_pairs: &[(&str, &str)],
+) -> Result<Vec<PathBuf>, ProjError> {
+    let mut grids = vec![];
+    for (source, target) in crs_pairs {
+        for grid in grids_used(ctx, source, target, area)? {
+            let GridUsage {
+                name,
+                url: Some(url),
+                direct_download: true,
+                ..
+            } = grid
+            else {
+                continue;
+            };
+            if !grids.iter().any(|(n, _)| n == &name) {
+                grids.push((name, url));
+            }
+        }
+    }
+    let _lock = DirLock::acquire(dir)?;
+    grids
+        .into_iter()
+        .map(|(name, url)| {
+            let path = dir.join(&name);
+            // another job may have downloaded it while we were waiting for the lock
+            if !path.exists() {
+                // unique to this process, as the directory isn't locked on all platforms
+                let partial = dir.join(format!(".{name}.{}.partial", std::process::id()));
+                download_file(settings, &url, &partial)?;
+                if let Err(e) = fs::rename(&partial, &path) {
+                    let _ = fs::remove_file(&partial);
+                    return Err(e.into());
+                }
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ProjBuilder;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("proj-rs-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_warm_cache() {
+        let dir = cache_dir("warm-cache");
+        let mut builder = ProjBuilder::new();
+        assert!(matches!(
+            builder.warm_cache(None, &[]),
+            Err(ProjError::NoSharedCache)
+        ));
+        builder.set_shared_grid_cache(&dir).unwrap();
+        let grids = builder
+            .warm_cache(None, &[("EPSG:4326", "EPSG:3857")])
+            .unwrap();
+        assert!(grids.is_empty());
+        assert!(dir.join(LOCK_FILE).exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,203 @@
+//! Default settings: process-wide ones inherited by builders on request, and per-thread ones
+//! applied to every context
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::{Context, ProjBuilder, ProjError};
+
+static GLOBAL_CONFIG: Mutex<Option<GlobalConfig>> = Mutex::new(None);
+
+thread_local! {
+    static THREAD_CONFIG: RefCell<Option<GlobalConfig>> = const { RefCell::new(None) };
+}
+
+/// Settings configured once by an application, and applied to the builders created using
+/// [`ProjBuilder::inheriting_defaults`]
+///
+/// Libraries embedding this crate can create their transformations using
+/// `ProjBuilder::inheriting_defaults`, so that they respect the configuration of the host
+/// application without having to pass it through every call:
+///
+/// ```rust
+/// use proj::{GlobalConfig, ProjBuilder};
+///
+/// // in the application, at startup
+/// proj::set_global_config(GlobalConfig {
+///     search_paths: vec!["/opt/grids".into()],
+///     url_endpoint: Some("https://grids.example.com".to_string()),
+///     ..Default::default()
+/// });
+///
+/// // in a library
+/// let builder = ProjBuilder::inheriting_defaults().unwrap();
+/// assert_eq!(builder.get_url_endpoint().unwrap(), "https://grids.example.com");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GlobalConfig {
+    /// Resource file search paths, added to PROJ's defaults
+    pub search_paths: Vec<PathBuf>,
+    /// The URL endpoint to query for remote grids
+    pub url_endpoint: Option<String>,
+    /// The PROJ database to use instead of the `proj.db` found in the search paths
+    pub database_path: Option<PathBuf>,
+    /// Whether to cache downloaded grid chunks, if not PROJ's default
+    pub grid_cache_enabled: Option<bool>,
+    /// The grid cache database to use instead of the one in the user-writable directory
+    pub grid_cache_path: Option<PathBuf>,
+    /// The maximum size of the grid cache, in megabytes
+    pub grid_cache_max_size: Option<u32>,
+    /// Whether to enable network access for resource file download
+    #[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+    #[cfg(feature = "network")]
+    pub network: bool,
+}
+
+/// Set the settings inherited by [`ProjBuilder::inheriting_defaults`]
+///
+/// Builders and transformations which have already been created are unaffected.
+pub fn set_global_config(config: GlobalConfig) {
+    *GLOBAL_CONFIG.lock().unwrap_or_else(|e| e.into_inner()) = Some(config);
+}
+
+/// Return the settings inherited by [`ProjBuilder::inheriting_defaults`], if they have been set
+pub fn global_config() -> Option<GlobalConfig> {
+    GLOBAL_CONFIG
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Change the settings applied to every context created from now on by the current thread
+///
+/// Every [`Proj`](crate::Proj) and [`ProjBuilder`] owns a context, so an application can
+/// configure grid search paths and downloads once, instead of creating every transformation
+/// using a `ProjBuilder`. `f` is passed the current settings of the thread, or the defaults.
+///
+/// ```rust
+/// use proj::Proj;
+///
+/// proj::configure(|config| {
+///     config.search_paths.push("/opt/grids".into());
+///     config.url_endpoint = Some("https://grids.example.com".to_string());
+/// });
+/// let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+/// assert_eq!(ft_to_m.get_url_endpoint().unwrap(), "https://grids.example.com");
+/// assert!(ft_to_m.search_paths().unwrap().contains(&"/opt/grids".into()));
+///
+/// proj::reset_configuration();
+/// ```
+///
+/// Settings which can't be applied, such as a missing database, are logged and skipped.
+pub fn configure<F>(f: F)
+where
+    F: FnOnce(&mut GlobalConfig),
+{
+    THREAD_CONFIG.with(|config| {
+        let mut config = config.borrow_mut();
+        f(config.get_or_insert_with(Default::default));
+    });
+}
+
+/// Return the settings applied to the contexts created by the current thread, if they have
+/// been set using [`configure`]
+pub fn configuration() -> Option<GlobalConfig> {
+    THREAD_CONFIG.with(|config| config.borrow().clone())
+}
+
+/// Stop applying the settings set using [`configure`] to the contexts created by the current
+/// thread
+pub fn reset_configuration() {
+    THREAD_CONFIG.with(|config| config.borrow_mut().take());
+}
+
+/// Apply the settings of the current thread to a new context
+pub(crate) fn apply_thread_config(ctx: Context) -> Context {
+    let Some(config) = configuration() else {
+        return ctx;
+    };
+    let mut builder = ProjBuilder::from_context(ctx);
+    if let Err(e) = config.apply(&mut builder) {
+        log::warn!("Couldn't apply the thread's PROJ configuration: {}", e);
+    }
+    builder.into_context()
+}
+
+impl GlobalConfig {
+    /// Apply the settings to a builder
+    pub(crate) fn apply(&self, builder: &mut ProjBuilder) -> Result<(), ProjError> {
+        for path in &self.search_paths {
+            builder.set_search_paths(path)?;
+        }
+        if let Some(endpoint) = &self.url_endpoint {
+            builder.set_url_endpoint(endpoint)?;
+        }
+        if let Some(database) = &self.database_path {
+            builder.set_database_path(database, &[] as &[PathBuf])?;
+        }
+        if let Some(enabled) = self.grid_cache_enabled {
+            builder.grid_cache_enable(enabled);
+        }
+        if let Some(path) = &self.grid_cache_path {
+            builder.set_grid_cache_path(path)?;
+        }
+        if let Some(size) = self.grid_cache_max_size {
+            builder.set_grid_cache_max_size(size);
+        }
+        #[cfg(feature = "network")]
+        if self.network {
+            builder.enable_network(true)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Proj;
+
+    #[test]
+    fn test_inheriting_defaults() {
+        let data = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data"));
+        set_global_config(GlobalConfig {
+            search_paths: vec![data.clone()],
+            url_endpoint: Some("https://grids.example.com".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(global_config().unwrap().search_paths, vec![data.clone()]);
+        let builder = ProjBuilder::inheriting_defaults().unwrap();
+        assert!(builder.search_paths().unwrap().contains(&data));
+        assert_eq!(
+            builder.get_url_endpoint().unwrap(),
+            "https://grids.example.com"
+        );
+        // the grid is found in the inherited search paths
+        builder
+            .proj("+proj=hgridshift +grids=test_hgrid_little_endian.gsb")
+            .unwrap();
+        // plain builders are unaffected
+        let builder = ProjBuilder::new();
+        assert!(!builder.search_paths().unwrap().contains(&data));
+    }
+
+    #[test]
+    fn test_configure() {
+        let data = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data"));
+        let searched = || Proj::new("EPSG:4326").unwrap().search_paths().unwrap();
+        assert!(!searched().contains(&data));
+        configure(|config| config.search_paths.push(data.clone()));
+        configure(|config| config.grid_cache_max_size = Some(10));
+        assert_eq!(configuration().unwrap().search_paths, vec![data.clone()]);
+        let shift = Proj::new("+proj=hgridshift +grids=test_hgrid_little_endian.gsb").unwrap();
+        assert!(shift.search_paths().unwrap().contains(&data));
+        assert_eq!(ProjBuilder::new().grid_cache_max_size(), Some(10));
+        // other threads are unaffected
+        std::thread::spawn(|| assert!(Proj::new("EPSG:4326").is_ok() && configuration().is_none()))
+            .join()
+            .unwrap();
+        reset_configuration();
+        // PROJ caches the grids it has opened, so check the settings of new contexts instead
+        assert!(!searched().contains(&data));
+    }
+}
@@ -0,0 +1,286 @@
+use proj_sys::{
+    proj_cleanup, proj_context_clone, proj_context_create, proj_context_destroy, proj_log_func,
+    PJ_CONTEXT,
+};
+use std::rc::Rc;
+
+#[cfg(loom)]
+use loom::sync::Mutex;
+#[cfg(not(loom))]
+use std::sync::Mutex;
+
+use crate::config::apply_thread_config;
+use crate::logging::log_callback;
+use crate::proj::ContextData;
+
+/// The number of live contexts, in every thread
+#[cfg(not(loom))]
+static LIVE_CONTEXTS: Mutex<usize> = Mutex::new(0);
+#[cfg(loom)]
+loom::lazy_static! {
+    static ref LIVE_CONTEXTS: Mutex<usize> = Mutex::new(0);
+}
+
+/// Release the memory held by PROJ's global caches, such as its database connections and
+/// grids, if no context is alive
+///
+/// Contexts don't clean up when they are dropped, so that creating and destroying
+/// transformations keeps benefiting from the caches. Long-running applications which have
+/// stopped using PROJ can call this to reclaim the memory; PROJ fills the caches again if it
+/// is used afterwards. Returns whether the caches were released: nothing is done while
+/// contexts, and so [`Proj`](crate::Proj) objects, are alive in any thread.
+///
+/// Only the contexts created by this crate are counted: contexts created directly through
+/// `proj-sys`, such as by `proj_context_create`, aren't, and must be destroyed before calling
+/// this.
+///
+/// ```rust
+/// let to_merc = proj::Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+/// assert!(!proj::cleanup());
+/// drop(to_merc);
+/// // other threads may still own contexts
+/// proj::cleanup();
+/// ```
+///
+/// # Safety
+/// This method contains unsafe code.
+pub fn cleanup() -> bool {
+    cleanup_with(|| unsafe { proj_cleanup() })
+}
+
+/// Run `clean` if no context is alive
+fn cleanup_with(clean: impl FnOnce()) -> bool {
+    let live = LIVE_CONTEXTS.lock().unwrap_or_else(|e| e.into_inner());
+    if *live > 0 {
+        return false;
+    }
+    // contexts are created while holding the lock, so none can be until it is released
+    clean();
+    true
+}
+
+/// Create and count a new context, waiting for a cleanup in progress to finish
+///
+/// The lock is held while the context is created, so that PROJ's global state isn't cleaned
+/// up at the same time.
+fn register<T>(create: impl FnOnce() -> T) -> T {
+    let mut live = LIVE_CONTEXTS.lock().unwrap_or_else(|e| e.into_inner());
+    let created = create();
+    *live += 1;
+    created
+}
+
+/// Destroy a context, and stop counting it once it has been
+fn unregister(destroy: impl FnOnce()) {
+    destroy();
+    // NB proj_cleanup mustn't be called until proj_destroy and proj_context_destroy have
+    // both returned: https://proj.org/development/reference/functions.html#c.proj_cleanup
+    *LIVE_CONTEXTS.lock().unwrap_or_else(|e| e.into_inner()) -= 1;
+}
+
+/// A PROJ [threading context](https://proj.org/development/threads.html)
+///
+/// Every PROJ object is created in a context, and must be destroyed before it. A [`Proj`](crate::Proj)
+/// holds its context in an `Rc`, which can be shared with other transformations using
+/// [`Proj::with_context`](crate::Proj::with_context), so that a context configured by a
+/// [`ProjBuilder`](crate::ProjBuilder) is reused. Objects created directly through `proj-sys`
+/// can be tied to a context using [`PjPtrExt::into_pj_ref`](crate::PjPtrExt::into_pj_ref), so
+/// that the compiler ensures they don't outlive it.
+///
+/// The context is destroyed when it is dropped.
+///
+/// Contexts, and the objects created in them, must not be shared between threads: create a
+/// context, or a [`Proj`](crate::Proj), in each thread instead.
+///
+/// ```compile_fail
+/// let ft_to_m = proj::Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+/// std::thread::spawn(move || ft_to_m.convert((4760096.421921, 3744293.729449)));
+/// ```
+pub struct Context {
+    ptr: *mut PJ_CONTEXT,
+    // Rust-side state referred to by callbacks: dropped after the context has been destroyed
+    pub(crate) data: ContextData,
+}
+
+impl Context {
+    /// Create a new context
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn new() -> Self {
+        let ptr = register(|| unsafe { proj_context_create() });
+        let data = ContextData::default();
+        // PROJ's messages are routed to the log crate rather than stderr. Clones of the context
+        // keep the same user data, which is shared with their ContextData.
+        let last_error = Rc::as_ptr(&data.last_error) as *mut _;
+        unsafe { proj_log_func(ptr, last_error, Some(log_callback)) };
+        // the settings set using `configure`, if any
+        apply_thread_config(Context { ptr, data })
+    }
+
+    /// Return the raw context pointer, for use with `proj-sys`
+    ///
+    /// The pointer is only valid as long as `self` is alive.
+    pub fn as_ptr(&self) -> *mut PJ_CONTEXT {
+        self.ptr
+    }
+
+    /// Forget the last error message logged by PROJ
+    pub(crate) fn clear_last_error(&self) {
+        self.data.last_error.borrow_mut().take();
+    }
+
+    /// Return the last error message logged by PROJ since it was cleared
+    ///
+    /// Nothing is logged, and so recorded, if the log level is [`LogLevel::None`](crate::LogLevel::None).
+    pub(crate) fn take_last_error(&self) -> Option<String> {
+        self.data.last_error.borrow_mut().take()
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Context {
+    /// Create a new context with the same configuration (search paths, network settings…)
+    fn clone(&self) -> Self {
+        Context {
+            ptr: register(|| unsafe { proj_context_clone(self.ptr) }),
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        let ptr = self.ptr;
+        unregister(|| {
+            unsafe { proj_context_destroy(ptr) };
+        });
+    }
+}
+
+// Run with `RUSTFLAGS="--cfg loom" cargo test --lib loom`: PROJ's global state is modelled by
+// the number of contexts it knows about, which must be zero whenever it is cleaned up
+#[cfg(all(test, loom))]
+mod loom_model {
+    use super::*;
+    use loom::sync::atomic::{AtomicUsize, Ordering};
+    use loom::sync::Arc;
+    use loom::thread;
+
+    fn create(contexts: &AtomicUsize) {
+        register(|| contexts.fetch_add(1, Ordering::SeqCst));
+    }
+
+    fn destroy(contexts: &AtomicUsize) {
+        unregister(|| {
+            contexts.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+
+    fn cleanup(contexts: &AtomicUsize) -> bool {
+        cleanup_with(|| assert_eq!(contexts.load(Ordering::SeqCst), 0))
+    }
+
+    #[test]
+    fn test_loom_cleanup_while_creating() {
+        loom::model(|| {
+            let contexts = Arc::new(AtomicUsize::new(0));
+            let creator = {
+                let contexts = Arc::clone(&contexts);
+                thread::spawn(move || {
+                    // a context, and a clone of it
+                    create(&contexts);
+                    create(&contexts);
+                    destroy(&contexts);
+                    destroy(&contexts);
+                })
+            };
+            cleanup(&contexts);
+            creator.join().unwrap();
+            assert!(cleanup(&contexts));
+        });
+    }
+
+    #[test]
+    fn test_loom_cleanup_while_alive() {
+        loom::model(|| {
+            let contexts = Arc::new(AtomicUsize::new(0));
+            create(&contexts);
+            let cleaner = {
+                let contexts = Arc::clone(&contexts);
+                thread::spawn(move || cleanup(&contexts))
+            };
+            assert!(!cleaner.join().unwrap());
+            destroy(&contexts);
+        });
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod test {
+    use super::*;
+    use crate::Proj;
+    use std::thread;
+
+    const THREADS: usize = 8;
+    const ITERATIONS: usize = 10;
+
+    // Each thread repeatedly creates, uses and destroys its own contexts, and tries to clear
+    // PROJ's global caches while other threads are transforming
+    #[test]
+    fn test_contexts_in_threads() {
+        let points: Vec<(f64, f64)> = (0..100)
+            .map(|i| (4760096.421921 + f64::from(i), 3744293.729449 + f64::from(i)))
+            .collect();
+        let expected: Vec<(f64, f64)> = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None)
+            .unwrap()
+            .convert_collect(points.clone())
+            .unwrap();
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let points = points.clone();
+                thread::spawn(move || {
+                    (0..ITERATIONS)
+                        .map(|_| {
+                            let ft_to_m =
+                                Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+                            let result = ft_to_m.convert_collect::<_, _, _, Vec<_>>(points.clone());
+                            drop(ft_to_m);
+                            cleanup();
+                            result
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            for result in handle.join().unwrap().unwrap() {
+                assert_eq!(result, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clones_in_threads() {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                thread::spawn(|| {
+                    let crs = Proj::new("EPSG:4326").unwrap();
+                    // objects derived from a Proj share its context
+                    (0..ITERATIONS)
+                        .map(|_| crs.promote_to_3d().and_then(|crs| crs.demote_to_2d()))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map(|crss| crss.len())
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap().unwrap(), ITERATIONS);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Four-dimensional coordinates, as transformed by PROJ
+use proj_sys::{
+    proj_errno, proj_trans, PJ_COORD, PJ_DIRECTION, PJ_DIRECTION_PJ_FWD, PJ_DIRECTION_PJ_INV,
+};
+
+use crate::proj::{reset_errno, transform_error, CoordinateType};
+use crate::{Coord, Proj, ProjError};
+
+/// The direction in which an operation is run
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From the source to the target of the operation
+    Forward,
+    /// From the target to the source of the operation
+    Inverse,
+}
+
+impl From<Direction> for PJ_DIRECTION {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Forward => PJ_DIRECTION_PJ_FWD,
+            Direction::Inverse => PJ_DIRECTION_PJ_INV,
+        }
+    }
+}
+
+/// A coordinate with up to four dimensions, such as easting, northing, height and time
+///
+/// The values are in the units expected and produced by the operation: angles are in radians
+/// for operations defined by PROJ strings, but usually in degrees for transformations between
+/// CRSs. A time of `f64::INFINITY` means that no time is given, and the coordinates at the
+/// epoch of the operation are used.
+///
+/// `ProjCoord` implements [`Coord`] using its first three values, so that it can also be passed
+/// to [`Proj::convert`] and similar methods, which return coordinates with no time.
+///
+/// ```rust
+/// # use approx::assert_relative_eq;
+/// use proj::{Direction, Proj, ProjCoord};
+///
+/// // ITRF2014 to ETRF2014, which depends on the time
+/// let itrf_to_etrf = Proj::new(
+///     "+proj=helmert +x=0.0 +y=0.0 +z=0.0 +dx=0.0 +dy=0.0 +dz=0.0 +drx=0.000085 \
+///      +dry=0.000531 +drz=-0.00077 +t_epoch=1989.0 +convention=position_vector",
+/// )
+/// .unwrap();
+/// let point = ProjCoord::xyzt(4027894.006, 307045.600, 4919474.910, 2020.0);
+/// let moved = itrf_to_etrf.trans_coord(point, Direction::Forward).unwrap();
+/// assert_relative_eq!(moved.x(), 4027894.434, epsilon = 1e-3);
+/// assert_eq!(moved.t(), 2020.0);
+/// let back = itrf_to_etrf.trans_coord(moved, Direction::Inverse).unwrap();
+/// assert_relative_eq!(back.x(), point.x(), epsilon = 1e-6);
+///
+/// // the rotations are null at the reference epoch
+/// let at_epoch = ProjCoord::xyzt(4027894.006, 307045.600, 4919474.910, 1989.0);
+/// let unchanged = itrf_to_etrf.trans_coord(at_epoch, Direction::Forward).unwrap();
+/// assert_relative_eq!(unchanged.x(), at_epoch.x(), epsilon = 1e-6);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProjCoord(pub [f64; 4]);
+
+impl ProjCoord {
+    /// A coordinate with an easting and a northing, and no height or time
+    pub fn xy(x: f64, y: f64) -> Self {
+        Self::xyzt(x, y, 0.0, f64::INFINITY)
+    }
+
+    /// A coordinate with an easting, a northing and a height, and no time
+    pub fn xyz(x: f64, y: f64, z: f64) -> Self {
+        Self::xyzt(x, y, z, f64::INFINITY)
+    }
+
+    /// A coordinate with an easting, a northing, a height and a time
+    pub fn xyzt(x: f64, y: f64, z: f64, t: f64) -> Self {
+        ProjCoord([x, y, z, t])
+    }
+
+    /// A geographic coordinate with a longitude, a latitude, a height and a time
+    pub fn lpzt(lam: f64, phi: f64, z: f64, t: f64) -> Self {
+        Self::xyzt(lam, phi, z, t)
+    }
+
+    /// A coordinate with the first two values of `point`, and no height or time
+    pub fn from_coord<C, F>(point: &C) -> Result<Self, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        Ok(Self::xy(
+            point.x().to_f64().ok_or(ProjError::FloatConversion)?,
+            point.y().to_f64().ok_or(ProjError::FloatConversion)?,
+        ))
+    }
+
+    /// A [`Coord`] with the first two values of the coordinate
+    pub fn to_coord<C, F>(&self) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        Ok(C::from_xy(
+            F::from(self.x()).ok_or(ProjError::FloatConversion)?,
+            F::from(self.y()).ok_or(ProjError::FloatConversion)?,
+        ))
+    }
+
+    /// The first value, such as an easting or a longitude
+    pub fn x(&self) -> f64 {
+        self.0[0]
+    }
+
+    /// The second value, such as a northing or a latitude
+    pub fn y(&self) -> f64 {
+        self.0[1]
+    }
+
+    /// The third value, such as a height
+    pub fn z(&self) -> f64 {
+        self.0[2]
+    }
+
+    /// The fourth value, the time
+    pub fn t(&self) -> f64 {
+        self.0[3]
+    }
+}
+
+impl Default for ProjCoord {
+    /// The origin, with no time
+    fn default() -> Self {
+        Self::xy(0.0, 0.0)
+    }
+}
+
+impl Coord<f64> for ProjCoord {
+    fn x(&self) -> f64 {
+        self.0[0]
+    }
+    fn y(&self) -> f64 {
+        self.0[1]
+    }
+    fn from_xy(x: f64, y: f64) -> Self {
+        Self::xy(x, y)
+    }
+    fn z(&self) -> Option<f64> {
+        Some(self.0[2])
+    }
+    fn from_xyz(x: f64, y: f64, z: f64) -> Self {
+        Self::xyz(x, y, z)
+    }
+}
+
+impl From<ProjCoord> for PJ_COORD {
+    fn from(coord: ProjCoord) -> Self {
+        PJ_COORD { v: coord.0 }
+    }
+}
+
+impl From<PJ_COORD> for ProjCoord {
+    fn from(coord: PJ_COORD) -> Self {
+        ProjCoord(unsafe { coord.v })
+    }
+}
+
+impl Proj {
+    /// Transform a coordinate with up to four dimensions in the given direction
+    ///
+    /// Unlike [`convert`](#method.convert) and [`project`](#method.project), the height and
+    /// time are passed to PROJ and returned, and the coordinate is transformed by PROJ even when
+    /// [`enable_fast_path`](#method.enable_fast_path) has been called.
+    ///
+    /// ```rust
+    /// # use approx::assert_relative_eq;
+    /// use proj::{Direction, Proj, ProjCoord};
+    ///
+    /// // WGS 84 to WGS 84 with ellipsoidal heights in feet
+    /// let to_feet = Proj::new("+proj=unitconvert +z_in=m +z_out=ft").unwrap();
+    /// let result = to_feet
+    ///     .trans_coord(ProjCoord::xyz(2.0, 48.0, 100.0), Direction::Forward)
+    ///     .unwrap();
+    /// assert_relative_eq!(result.z(), 328.0839895, epsilon = 1e-6);
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn trans_coord(
+        &self,
+        coord: ProjCoord,
+        direction: Direction,
+    ) -> Result<ProjCoord, ProjError> {
+        unsafe {
+            reset_errno(self.as_ptr());
+            let result = proj_trans(self.as_ptr(), direction.into(), coord.into());
+            match proj_errno(self.as_ptr()) {
+                0 => Ok(result.into()),
+                err => Err(transform_error(self, err, ProjError::Conversion)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_trans_coord() {
+        let to_merc = crate::proj::normalized_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let point = ProjCoord::xyzt(2.0, 48.0, 50.0, 2020.0);
+        let result = to_merc.trans_coord(point, Direction::Forward).unwrap();
+        let expected = to_merc.convert((2.0, 48.0)).unwrap();
+        assert_relative_eq!(result.x(), expected.0, epsilon = 1e-9);
+        assert_relative_eq!(result.y(), expected.1, epsilon = 1e-9);
+        assert_eq!((result.z(), result.t()), (50.0, 2020.0));
+        // the same conversion, through the Coord trait
+        let converted = to_merc.convert(point).unwrap();
+        assert_eq!(converted, ProjCoord::xyz(result.x(), result.y(), 50.0));
+        let (x, y): (f32, f32) = result.to_coord().unwrap();
+        assert_relative_eq!(x as f64, expected.0, epsilon = 1.0);
+        assert_relative_eq!(y as f64, expected.1, epsilon = 1.0);
+        assert_eq!(
+            ProjCoord::from_coord(&(1.0, 2.0)).unwrap(),
+            ProjCoord::xy(1.0, 2.0)
+        );
+        // an invalid latitude
+        assert!(to_merc
+            .trans_coord(ProjCoord::xy(0.0, 91.0), Direction::Forward)
+            .is_err());
+    }
+}
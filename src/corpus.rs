@@ -0,0 +1,169 @@
+//! A corpus of transformations with known results
+//!
+//! The corpus covers cases which are easy to get wrong: unit conversions, datum shifts,
+//! polygons crossing the antimeridian, and grid-based transformations. It's shipped with the
+//! crate so that integrations, such as conversions from other geometry libraries, can be
+//! validated against the same expected outputs as this crate's own tests.
+//!
+//! Cases which need grids downloaded from the PROJ CDN are marked as
+//! [`requires_network`](Case::requires_network). The corpus only contains 2D cases, since
+//! [`Proj`] transforms horizontal coordinates.
+//!
+//! ```rust
+//! use proj::corpus;
+//! use proj::ProjBuilder;
+//!
+//! for case in corpus::cases().iter().filter(|case| !case.requires_network) {
+//!     let proj = case.proj(ProjBuilder::new()).unwrap();
+//!     let output = proj.convert(case.input).unwrap();
+//!     assert!(case.check(output), "{}: {:?}", case.name, output);
+//! }
+//! ```
+use thiserror::Error;
+
+use crate::{Proj, ProjBuilder, ProjCreateError};
+
+/// The corpus shipped with the crate, as comma-separated values
+///
+/// Each line contains the name of a case, its source and target CRSs, its input and expected
+/// output coordinates, its tolerance, and whether it requires network access. Lines starting
+/// with `#` are comments.
+pub const CORPUS: &str = include_str!("corpus.csv");
+
+/// A transformation of a point, and its expected result
+#[derive(Clone, Debug, PartialEq)]
+pub struct Case {
+    pub name: String,
+    /// The source CRS, in any format accepted by [`Proj::new_known_crs`]
+    pub source: String,
+    /// The target CRS, in any format accepted by [`Proj::new_known_crs`]
+    pub target: String,
+    /// The input coordinates, longitude or easting first
+    pub input: (f64, f64),
+    /// The expected output coordinates, longitude or easting first
+    pub expected: (f64, f64),
+    /// The maximum difference from the expected coordinates, in units of the target CRS
+    pub tolerance: f64,
+    /// Whether the transformation uses grids which must be downloaded
+    pub requires_network: bool,
+}
+
+/// An error in the definition of a corpus
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid corpus line {line}: {reason}")]
+pub struct ParseCorpusError {
+    /// The line number, starting at 1
+    pub line: usize,
+    pub reason: String,
+}
+
+impl Case {
+    /// Create the transformation from the source to the target CRS using `builder`
+    ///
+    /// Enable network access on the builder to run the cases which require it.
+    pub fn proj(&self, builder: ProjBuilder) -> Result<Proj, ProjCreateError> {
+        builder.proj_known_crs(&self.source, &self.target, None)
+    }
+
+    /// Return whether `output` matches the expected coordinates within the tolerance
+    pub fn check(&self, output: (f64, f64)) -> bool {
+        (output.0 - self.expected.0).abs() <= self.tolerance
+            && (output.1 - self.expected.1).abs() <= self.tolerance
+    }
+}
+
+/// Return the cases of the corpus shipped with the crate
+pub fn cases() -> Vec<Case> {
+    parse(CORPUS).expect("the shipped corpus is valid")
+}
+
+/// Parse a corpus in the format of [`CORPUS`]
+pub fn parse(corpus: &str) -> Result<Vec<Case>, ParseCorpusError> {
+    corpus
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            parse_case(line).map_err(|reason| ParseCorpusError {
+                line: i + 1,
+                reason,
+            })
+        })
+        .collect()
+}
+
+fn parse_case(line: &str) -> Result<Case, String> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    let [name, source, target, x, y, expected_x, expected_y, tolerance, requires_network] =
+        fields[..]
+    else {
+        return Err(format!("expected 9 fields, found {}", fields.len()));
+    };
+    let number = |field: &str| {
+        field
+            .parse::<f64>()
+            .map_err(|_| format!("invalid number {:?}", field))
+    };
+    Ok(Case {
+        name: name.to_string(),
+        source: source.to_string(),
+        target: target.to_string(),
+        input: (number(x)?, number(y)?),
+        expected: (number(expected_x)?, number(expected_y)?),
+        tolerance: number(tolerance)?,
+        requires_network: requires_network
+            .parse()
+            .map_err(|_| format!("invalid boolean {:?}", requires_network))?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let cases = parse(
+            "# comment\n\
+             \n\
+             merc,EPSG:4326,EPSG:3857,0.0,0.0,0.0,0.0,0.001,false\n",
+        )
+        .unwrap();
+        assert_eq!(cases.len(), 1);
+        assert_eq!(cases[0].name, "merc");
+        assert!(cases[0].check((0.0005, -0.0005)));
+        assert!(!cases[0].check((0.002, 0.0)));
+        assert_eq!(
+            parse("\nmerc,EPSG:4326,EPSG:3857,0.0,0.0,0.0,0.0,0.001").unwrap_err(),
+            ParseCorpusError {
+                line: 2,
+                reason: "expected 9 fields, found 8".to_string(),
+            }
+        );
+        assert!(parse("merc,EPSG:4326,EPSG:3857,0.0,0.0,0.0,zero,0.001,false").is_err());
+    }
+
+    #[test]
+    fn test_corpus() {
+        let cases = cases();
+        assert!(cases.iter().any(|case| case.requires_network));
+        for case in cases.iter().filter(|case| !case.requires_network) {
+            let mut builder = ProjBuilder::new();
+            builder.set_normalize_axis_order(true);
+            let output = case.proj(builder).unwrap().convert(case.input);
+            assert!(case.check(output.unwrap()), "{}", case.name);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "network")]
+    fn test_corpus_network() {
+        for case in cases().iter().filter(|case| case.requires_network) {
+            let mut builder = ProjBuilder::new();
+            builder.set_normalize_axis_order(true);
+            builder.enable_network(true).unwrap();
+            let output = case.proj(builder).unwrap().convert(case.input);
+            assert!(case.check(output.unwrap()), "{}", case.name);
+        }
+    }
+}
@@ -0,0 +1,949 @@
+//! Construction and export of coordinate reference systems
+use libc::c_char;
+use proj_sys::{
+    proj_as_projjson, proj_create, proj_create_cartesian_2D_cs, proj_create_compound_crs,
+    proj_create_conversion_albers_equal_area, proj_create_conversion_lambert_azimuthal_equal_area,
+    proj_create_conversion_lambert_conic_conformal_1sp,
+    proj_create_conversion_lambert_conic_conformal_2sp, proj_create_conversion_mercator_variant_a,
+    proj_create_conversion_oblique_stereographic,
+    proj_create_conversion_polar_stereographic_variant_b,
+    proj_create_conversion_transverse_mercator, proj_create_conversion_utm,
+    proj_create_ellipsoidal_2D_cs, proj_create_geographic_crs, proj_create_projected_crs,
+    proj_create_vertical_crs_ex, proj_is_crs, PJ,
+    PJ_CARTESIAN_CS_2D_TYPE_PJ_CART2D_EASTING_NORTHING, PJ_CONTEXT,
+    PJ_ELLIPSOIDAL_CS_2D_TYPE_PJ_ELLPS2D_LONGITUDE_LATITUDE,
+};
+use std::borrow::Cow;
+use std::ffi::CString;
+use std::ptr;
+use std::rc::Rc;
+
+use crate::proj::{_string, as_proj_string, as_wkt};
+use crate::{Context, PjPtrExt, PjRef, Proj, ProjCreateError, ProjError};
+
+pub use crate::proj::{WktOptions, WktVersion};
+pub use crate::typed::{CoordinateMetadata, Crs};
+
+const DEGREE: &str = "degree";
+const DEGREE_TO_RADIAN: f64 = 0.017_453_292_519_943_3;
+
+/// A geodetic datum, defined by its ellipsoid, used as the base of a custom CRS.
+///
+/// The prime meridian is always Greenwich.
+#[derive(Clone, Debug)]
+pub struct Datum {
+    /// The name of the datum, such as `World Geodetic System 1984`
+    pub name: String,
+    /// The name of its ellipsoid, such as `WGS 84`
+    pub ellipsoid_name: String,
+    /// The semi-major axis of the ellipsoid, in metres
+    pub semi_major_metre: f64,
+    /// The inverse flattening of the ellipsoid
+    pub inv_flattening: f64,
+}
+
+impl Datum {
+    /// Create a new Datum from its ellipsoid parameters
+    pub fn new(
+        name: &str,
+        ellipsoid_name: &str,
+        semi_major_metre: f64,
+        inv_flattening: f64,
+    ) -> Self {
+        Datum {
+            name: name.to_string(),
+            ellipsoid_name: ellipsoid_name.to_string(),
+            semi_major_metre,
+            inv_flattening,
+        }
+    }
+
+    /// The World Geodetic System 1984 datum
+    pub fn wgs84() -> Self {
+        Self::new(
+            "World Geodetic System 1984",
+            "WGS 84",
+            6378137.0,
+            298.257223563,
+        )
+    }
+
+    /// The North American Datum 1983, on the GRS 1980 ellipsoid
+    pub fn nad83() -> Self {
+        Self::new(
+            "North American Datum 1983",
+            "GRS 1980",
+            6378137.0,
+            298.257222101,
+        )
+    }
+}
+
+/// The unit of the projected axes of a custom CRS
+#[derive(Clone, Debug)]
+pub struct LinearUnit {
+    /// The name of the unit, such as `metre`
+    pub name: String,
+    /// The length of the unit in metres
+    pub to_metre: f64,
+}
+
+impl LinearUnit {
+    /// Create a new linear unit, given its conversion factor to metres
+    pub fn new(name: &str, to_metre: f64) -> Self {
+        LinearUnit {
+            name: name.to_string(),
+            to_metre,
+        }
+    }
+
+    /// The metre, whose conversion factor is 1
+    pub fn metre() -> Self {
+        Self::new("metre", 1.0)
+    }
+
+    /// The international foot, of 0.3048 metres
+    pub fn foot() -> Self {
+        Self::new("foot", 0.3048)
+    }
+
+    /// The US survey foot, of 1200/3937 metres (about 0.3048006 metres)
+    pub fn us_survey_foot() -> Self {
+        Self::new("US survey foot", 0.304_800_609_601_219_2)
+    }
+}
+
+/// A geoid model, relating the gravity-related heights of a vertical CRS to ellipsoidal heights
+#[derive(Clone, Debug)]
+pub struct GeoidModel {
+    /// The file name of the geoid grid, such as `us_noaa_g2018u0.tif`
+    pub grid: String,
+    /// The accuracy of the model in metres, if known
+    pub accuracy: Option<f64>,
+}
+
+impl GeoidModel {
+    /// Create a new geoid model from its grid file name
+    pub fn new(grid: &str) -> Self {
+        GeoidModel {
+            grid: grid.to_string(),
+            accuracy: None,
+        }
+    }
+}
+
+/// A map projection method and its parameters.
+///
+/// Angular parameters are in degrees; false eastings and northings are expressed in the
+/// [`LinearUnit`] of the CRS being built.
+#[derive(Copy, Clone, Debug)]
+pub enum Conversion {
+    /// Universal Transverse Mercator
+    Utm { zone: u8, north: bool },
+    TransverseMercator {
+        center_lat: f64,
+        center_lon: f64,
+        scale: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    LambertConicConformal1Sp {
+        center_lat: f64,
+        center_lon: f64,
+        scale: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    LambertConicConformal2Sp {
+        lat_false_origin: f64,
+        lon_false_origin: f64,
+        lat_first_parallel: f64,
+        lat_second_parallel: f64,
+        easting_false_origin: f64,
+        northing_false_origin: f64,
+    },
+    AlbersEqualArea {
+        lat_false_origin: f64,
+        lon_false_origin: f64,
+        lat_first_parallel: f64,
+        lat_second_parallel: f64,
+        easting_false_origin: f64,
+        northing_false_origin: f64,
+    },
+    LambertAzimuthalEqualArea {
+        lat_nat_origin: f64,
+        lon_nat_origin: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    /// Mercator (1SP)
+    MercatorVariantA {
+        center_lat: f64,
+        center_lon: f64,
+        scale: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    ObliqueStereographic {
+        center_lat: f64,
+        center_lon: f64,
+        scale: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+    PolarStereographicVariantB {
+        lat_standard_parallel: f64,
+        lon_origin: f64,
+        false_easting: f64,
+        false_northing: f64,
+    },
+}
+
+/// A format in which to [export definitions](export_definitions)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Wkt(WktVersion),
+    ProjJson,
+    ProjString,
+}
+
+/// Export the definitions of many objects (e.g. `"EPSG:4326"`) at once
+///
+/// A single PROJ context, and therefore a single database connection, is used for all the
+/// definitions, which is much cheaper than creating a [`Proj`] for each of them.
+/// Results are returned in the same order as `definitions`.
+///
+/// ```rust
+/// use proj::{export_definitions, ExportFormat, WktVersion};
+///
+/// let wkts = export_definitions(
+///     &["EPSG:4326", "EPSG:3857"],
+///     ExportFormat::Wkt(WktVersion::Wkt2_2019),
+/// );
+/// assert!(wkts[1].as_ref().unwrap().starts_with("PROJCRS"));
+/// ```
+///
+/// # Safety
+/// This method contains unsafe code.
+pub fn export_definitions(
+    definitions: &[&str],
+    format: ExportFormat,
+) -> Vec<Result<String, ProjError>> {
+    let ctx = Context::new();
+    definitions
+        .iter()
+        .map(|definition| {
+            let c_definition = c_string(definition)?;
+            let pj = create(&ctx, unsafe {
+                proj_create(ctx.as_ptr(), c_definition.as_ptr())
+            })?;
+            match format {
+                ExportFormat::Wkt(version) => {
+                    as_wkt(&ctx, pj.as_ptr(), version, &WktOptions::default())
+                }
+                ExportFormat::ProjJson => as_projjson(ctx.as_ptr(), pj.as_ptr()),
+                ExportFormat::ProjString => as_proj_string(ctx.as_ptr(), pj.as_ptr()),
+            }
+        })
+        .collect()
+}
+
+/// A CRS, as accepted by [`Proj::new_known_crs`] and
+/// [`Transform::transform_crs_to_crs`](crate::Transform::transform_crs_to_crs)
+///
+/// It is implemented for:
+///
+/// - definitions, in any format accepted by [`Proj::new_known_crs`], such as `"EPSG:4326"`;
+/// - EPSG codes, as `u32`;
+/// - [`Proj`] objects holding a CRS, such as those created by a [`CrsBuilder`];
+/// - the `Def` structs of the `crs-definitions` crate, when the `crs-definitions` feature is
+///   enabled.
+///
+/// ```rust
+/// # use approx::assert_relative_eq;
+/// use proj::Proj;
+///
+/// let to_merc = Proj::new_known_crs(4326, 3857, None).unwrap();
+/// let result = to_merc.convert((2.3522, 48.8566)).unwrap();
+/// assert_relative_eq!(result.0, 261845.70624393807, epsilon = 1e-6);
+///
+/// let nad83 = Proj::new("EPSG:4269").unwrap();
+/// let to_nad83 = Proj::new_known_crs("EPSG:4326", &nad83, None).unwrap();
+/// ```
+pub trait IntoCrs {
+    /// Return a definition of the CRS which can be passed to PROJ
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError>;
+}
+
+impl IntoCrs for str {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl IntoCrs for String {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl IntoCrs for u32 {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        Ok(Cow::Owned(format!("EPSG:{}", self)))
+    }
+}
+
+/// The CRS is passed as PROJJSON, so nothing is lost
+///
+/// # Safety
+/// This method contains unsafe code.
+impl IntoCrs for Proj {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        if unsafe { proj_is_crs(self.as_ptr()) } != 1 && self.epoch().is_none() {
+            return Err(ProjCreateError::ProjError(
+                "Object is not a CRS".to_string(),
+            ));
+        }
+        self.to_projjson(None, None, None)
+            .map(Cow::Owned)
+            .map_err(|e| ProjCreateError::ProjError(e.to_string()))
+    }
+}
+
+impl<T: IntoCrs + ?Sized> IntoCrs for &T {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        (**self).crs_definition()
+    }
+}
+
+/// The kind of definition found in user input, as determined by [`classify_input`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    /// WKT1 or WKT2, such as `GEOGCRS["WGS 84", ...]`
+    Wkt,
+    /// PROJJSON, such as `{"type": "GeographicCRS", ...}`
+    ProjJson,
+    /// An authority and a code, such as `EPSG:4326` or `IGNF:LAMB93`
+    AuthorityCode,
+    /// An OGC URN, such as `urn:ogc:def:crs:EPSG::4326`
+    Urn,
+    /// An OGC URL, such as `http://www.opengis.net/def/crs/EPSG/0/4326`
+    Url,
+    /// A PROJ string, such as `+proj=utm +zone=31`
+    ProjString,
+    /// Anything else, which PROJ looks up as the name of an object in its database, such as
+    /// `NAD83(2011)`
+    Name,
+    /// Nothing but whitespace
+    Empty,
+}
+
+/// Determine the kind of definition a user supplied, without parsing it
+///
+/// This allows applications to validate and explain a definition before passing it to
+/// [`Proj::from_user_input`]. The classification is syntactic: the definition may still be
+/// rejected by PROJ.
+///
+/// ```rust
+/// use proj::{classify_input, InputKind};
+///
+/// assert_eq!(classify_input("EPSG:4326"), InputKind::AuthorityCode);
+/// assert_eq!(classify_input("+proj=longlat +datum=WGS84"), InputKind::ProjString);
+/// assert_eq!(classify_input("GEOGCS[\"WGS 84\",DATUM[...]]"), InputKind::Wkt);
+/// assert_eq!(classify_input("WGS 84"), InputKind::Name);
+/// ```
+pub fn classify_input(input: &str) -> InputKind {
+    let input = input.trim();
+    let starts_with = |prefix: &str| {
+        input
+            .get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    };
+    // WKT objects start with a keyword, and a bracket followed by the name of the object
+    let is_wkt = || {
+        let keyword_len = input
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(input.len());
+        let rest = input[keyword_len..].trim_start();
+        keyword_len > 0 && rest.starts_with(['[', '(']) && rest[1..].trim_start().starts_with('"')
+    };
+    if input.is_empty() {
+        InputKind::Empty
+    } else if input.starts_with('{') {
+        InputKind::ProjJson
+    } else if starts_with("urn:") {
+        InputKind::Urn
+    } else if starts_with("http://") || starts_with("https://") {
+        InputKind::Url
+    } else if input.starts_with('+') || starts_with("proj=") || starts_with("init=") {
+        InputKind::ProjString
+    } else if is_wkt() {
+        InputKind::Wkt
+    } else if input.split_once(':').is_some_and(|(authority, code)| {
+        !authority.is_empty() && !code.is_empty() && !input.contains(char::is_whitespace)
+    }) {
+        InputKind::AuthorityCode
+    } else {
+        InputKind::Name
+    }
+}
+
+/// Programmatically build a geographic or projected coordinate reference system.
+///
+/// The resulting [`Proj`] holds a CRS rather than a transformation: it can be inspected, or
+/// exported (e.g. using [`Proj::to_projjson`]) and used to define a transformation.
+///
+/// ```rust
+/// # use approx::assert_relative_eq;
+/// use proj::{Conversion, CrsBuilder, Proj};
+///
+/// let mut builder = CrsBuilder::new("Study area LCC");
+/// let study_area = builder
+///     .projected(Conversion::LambertConicConformal2Sp {
+///         lat_false_origin: 45.0,
+///         lon_false_origin: 10.0,
+///         lat_first_parallel: 44.0,
+///         lat_second_parallel: 46.0,
+///         easting_false_origin: 500000.0,
+///         northing_false_origin: 0.0,
+///     })
+///     .unwrap();
+/// let definition = study_area.to_projjson(None, None, None).unwrap();
+///
+/// let to_study_area = Proj::new_known_crs("EPSG:4326", &definition, None).unwrap();
+/// let result = to_study_area.convert((10.0, 45.0)).unwrap();
+/// assert_relative_eq!(result.0, 500000.0, epsilon = 1e-6);
+/// assert_relative_eq!(result.1, 0.0, epsilon = 1e-6);
+/// ```
+pub struct CrsBuilder {
+    ctx: Context,
+    name: String,
+    datum: Datum,
+    linear_unit: LinearUnit,
+}
+
+impl CrsBuilder {
+    /// Create a new `CrsBuilder` for a CRS named `name`, based on the WGS84 datum, using metres.
+    pub fn new(name: &str) -> Self {
+        CrsBuilder {
+            ctx: Context::new(),
+            name: name.to_string(),
+            datum: Datum::wgs84(),
+            linear_unit: LinearUnit::metre(),
+        }
+    }
+
+    /// Set the datum of the CRS
+    pub fn set_datum(&mut self, datum: Datum) {
+        self.datum = datum;
+    }
+
+    /// Set the unit of the projected axes, which is also the unit of false eastings and northings
+    pub fn set_linear_unit(&mut self, unit: LinearUnit) {
+        self.linear_unit = unit;
+    }
+
+    /// Build a geographic CRS with Longitude, Latitude axes in degrees
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn geographic(&mut self) -> Result<Proj, ProjCreateError> {
+        let ctx = std::mem::take(&mut self.ctx);
+        let c_proj = self.create_geographic(&ctx, &self.name)?.into_raw();
+        into_proj(ctx, c_proj)
+    }
+
+    /// Build a projected CRS using the given map projection
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn projected(&mut self, conversion: Conversion) -> Result<Proj, ProjCreateError> {
+        let ctx = std::mem::take(&mut self.ctx);
+        let c_proj = self.create_projected(&ctx, conversion)?.into_raw();
+        into_proj(ctx, c_proj)
+    }
+
+    /// Build a vertical CRS of gravity-related heights in the linear unit, bound to a geoid model
+    ///
+    /// The geoid model relates the heights to ellipsoidal heights on the datum of the builder.
+    /// Combine the vertical CRS with a horizontal CRS using [`compound`](#method.compound) to
+    /// transform heights.
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn vertical(
+        &mut self,
+        vertical_datum: &str,
+        geoid: &GeoidModel,
+    ) -> Result<Proj, ProjCreateError> {
+        let ctx = std::mem::take(&mut self.ctx);
+        let c_proj = self
+            .create_vertical(&ctx, vertical_datum, geoid)?
+            .into_raw();
+        into_proj(ctx, c_proj)
+    }
+
+    /// Build a compound CRS from a horizontal CRS and a vertical CRS
+    ///
+    /// ```rust
+    /// use proj::{CrsBuilder, Datum, GeoidModel, Proj};
+    ///
+    /// let mut builder = CrsBuilder::new("Site heights");
+    /// builder.set_datum(Datum::nad83());
+    /// let heights = builder
+    ///     .vertical("Site vertical datum", &GeoidModel::new("us_noaa_g2018u0.tif"))
+    ///     .unwrap();
+    /// let nad83 = Proj::new("EPSG:4269").unwrap();
+    /// let site = CrsBuilder::new("NAD83 + Site heights")
+    ///     .compound(&nad83, &heights)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn compound(
+        &mut self,
+        horizontal: &Proj,
+        vertical: &Proj,
+    ) -> Result<Proj, ProjCreateError> {
+        let ctx = std::mem::take(&mut self.ctx);
+        let crs_name = c_string(&self.name)?;
+        let c_proj = create(&ctx, unsafe {
+            proj_create_compound_crs(
+                ctx.as_ptr(),
+                crs_name.as_ptr(),
+                horizontal.as_ptr(),
+                vertical.as_ptr(),
+            )
+        })?
+        .into_raw();
+        into_proj(ctx, c_proj)
+    }
+
+    fn create_vertical<'ctx>(
+        &self,
+        ctx: &'ctx Context,
+        vertical_datum: &str,
+        geoid: &GeoidModel,
+    ) -> Result<PjRef<'ctx>, ProjCreateError> {
+        let crs_name = c_string(&self.name)?;
+        let datum_name = c_string(vertical_datum)?;
+        let unit_name = c_string(&self.linear_unit.name)?;
+        // PROJ uses grids named this way directly, rather than looking the model up
+        let model_name = c_string(&format!("PROJ {}", geoid.grid))?;
+        let geoid_crs = self.create_geographic(ctx, &self.datum.name)?;
+        let accuracy = geoid
+            .accuracy
+            .map(|accuracy| c_string(&format!("ACCURACY={accuracy}")))
+            .transpose()?;
+        let opts = [
+            accuracy.as_ref().map_or(ptr::null(), |opt| opt.as_ptr()),
+            ptr::null(),
+        ];
+        create(ctx, unsafe {
+            proj_create_vertical_crs_ex(
+                ctx.as_ptr(),
+                crs_name.as_ptr(),
+                datum_name.as_ptr(),
+                ptr::null(),
+                ptr::null(),
+                unit_name.as_ptr(),
+                self.linear_unit.to_metre,
+                model_name.as_ptr(),
+                ptr::null(),
+                ptr::null(),
+                geoid_crs.as_ptr(),
+                opts.as_ptr(),
+            )
+        })
+    }
+
+    fn create_geographic<'ctx>(
+        &self,
+        ctx: &'ctx Context,
+        name: &str,
+    ) -> Result<PjRef<'ctx>, ProjCreateError> {
+        let crs_name = c_string(name)?;
+        let datum_name = c_string(&self.datum.name)?;
+        let ellps_name = c_string(&self.datum.ellipsoid_name)?;
+        let degree = c_string(DEGREE)?;
+        let greenwich = c_string("Greenwich")?;
+        let cs = create(ctx, unsafe {
+            proj_create_ellipsoidal_2D_cs(
+                ctx.as_ptr(),
+                PJ_ELLIPSOIDAL_CS_2D_TYPE_PJ_ELLPS2D_LONGITUDE_LATITUDE,
+                degree.as_ptr(),
+                DEGREE_TO_RADIAN,
+            )
+        })?;
+        create(ctx, unsafe {
+            proj_create_geographic_crs(
+                ctx.as_ptr(),
+                crs_name.as_ptr(),
+                datum_name.as_ptr(),
+                ellps_name.as_ptr(),
+                self.datum.semi_major_metre,
+                self.datum.inv_flattening,
+                greenwich.as_ptr(),
+                0.0,
+                degree.as_ptr(),
+                DEGREE_TO_RADIAN,
+                cs.as_ptr(),
+            )
+        })
+    }
+
+    fn create_projected<'ctx>(
+        &self,
+        ctx: &'ctx Context,
+        conversion: Conversion,
+    ) -> Result<PjRef<'ctx>, ProjCreateError> {
+        let crs_name = c_string(&self.name)?;
+        let base = self.create_geographic(ctx, &self.datum.name)?;
+        let conversion = self.create_conversion(ctx, conversion)?;
+        let unit_name = c_string(&self.linear_unit.name)?;
+        let cs = create(ctx, unsafe {
+            proj_create_cartesian_2D_cs(
+                ctx.as_ptr(),
+                PJ_CARTESIAN_CS_2D_TYPE_PJ_CART2D_EASTING_NORTHING,
+                unit_name.as_ptr(),
+                self.linear_unit.to_metre,
+            )
+        })?;
+        create(ctx, unsafe {
+            proj_create_projected_crs(
+                ctx.as_ptr(),
+                crs_name.as_ptr(),
+                base.as_ptr(),
+                conversion.as_ptr(),
+                cs.as_ptr(),
+            )
+        })
+    }
+
+    fn create_conversion<'ctx>(
+        &self,
+        ctx: &'ctx Context,
+        conversion: Conversion,
+    ) -> Result<PjRef<'ctx>, ProjCreateError> {
+        let ang = c_string(DEGREE)?;
+        let ang = ang.as_ptr();
+        let lin = c_string(&self.linear_unit.name)?;
+        let lin = lin.as_ptr();
+        let to_metre = self.linear_unit.to_metre;
+        let ctx_ptr = ctx.as_ptr();
+        let ptr = unsafe {
+            match conversion {
+                Conversion::Utm { zone, north } => {
+                    proj_create_conversion_utm(ctx_ptr, zone.into(), north.into())
+                }
+                Conversion::TransverseMercator {
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                } => proj_create_conversion_transverse_mercator(
+                    ctx_ptr,
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+                Conversion::LambertConicConformal1Sp {
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                } => proj_create_conversion_lambert_conic_conformal_1sp(
+                    ctx_ptr,
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+                Conversion::LambertConicConformal2Sp {
+                    lat_false_origin,
+                    lon_false_origin,
+                    lat_first_parallel,
+                    lat_second_parallel,
+                    easting_false_origin,
+                    northing_false_origin,
+                } => proj_create_conversion_lambert_conic_conformal_2sp(
+                    ctx_ptr,
+                    lat_false_origin,
+                    lon_false_origin,
+                    lat_first_parallel,
+                    lat_second_parallel,
+                    easting_false_origin,
+                    northing_false_origin,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+                Conversion::AlbersEqualArea {
+                    lat_false_origin,
+                    lon_false_origin,
+                    lat_first_parallel,
+                    lat_second_parallel,
+                    easting_false_origin,
+                    northing_false_origin,
+                } => proj_create_conversion_albers_equal_area(
+                    ctx_ptr,
+                    lat_false_origin,
+                    lon_false_origin,
+                    lat_first_parallel,
+                    lat_second_parallel,
+                    easting_false_origin,
+                    northing_false_origin,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+                Conversion::LambertAzimuthalEqualArea {
+                    lat_nat_origin,
+                    lon_nat_origin,
+                    false_easting,
+                    false_northing,
+                } => proj_create_conversion_lambert_azimuthal_equal_area(
+                    ctx_ptr,
+                    lat_nat_origin,
+                    lon_nat_origin,
+                    false_easting,
+                    false_northing,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+                Conversion::MercatorVariantA {
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                } => proj_create_conversion_mercator_variant_a(
+                    ctx_ptr,
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+                Conversion::ObliqueStereographic {
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                } => proj_create_conversion_oblique_stereographic(
+                    ctx_ptr,
+                    center_lat,
+                    center_lon,
+                    scale,
+                    false_easting,
+                    false_northing,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+                Conversion::PolarStereographicVariantB {
+                    lat_standard_parallel,
+                    lon_origin,
+                    false_easting,
+                    false_northing,
+                } => proj_create_conversion_polar_stereographic_variant_b(
+                    ctx_ptr,
+                    lat_standard_parallel,
+                    lon_origin,
+                    false_easting,
+                    false_northing,
+                    ang,
+                    DEGREE_TO_RADIAN,
+                    lin,
+                    to_metre,
+                ),
+            }
+        };
+        create(ctx, ptr)
+    }
+}
+
+fn as_projjson(ctx: *mut PJ_CONTEXT, pj: *const PJ) -> Result<String, ProjError> {
+    // we always have to terminate with a null pointer, even if the opts are empty
+    let opts: [*const c_char; 1] = [ptr::null()];
+    let out_ptr = unsafe { proj_as_projjson(ctx, pj, opts.as_ptr()) };
+    if out_ptr.is_null() {
+        Err(ProjError::ExportToJson)
+    } else {
+        Ok(unsafe { _string(out_ptr)? })
+    }
+}
+
+fn c_string(s: &str) -> Result<CString, ProjCreateError> {
+    CString::new(s).map_err(ProjCreateError::ArgumentNulError)
+}
+
+fn create(ctx: &Context, ptr: *mut PJ) -> Result<PjRef<'_>, ProjCreateError> {
+    unsafe { ptr.into_pj_ref(ctx) }
+}
+
+/// Hand the context and the newly created CRS over to a `Proj`, which takes care of destroying them
+fn into_proj(ctx: Context, c_proj: *mut PJ) -> Result<Proj, ProjCreateError> {
+    Ok(Proj::from_pj(unsafe {
+        PjRef::new_shared(c_proj, Rc::new(ctx))?
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Area, ProjBuilder};
+
+    #[test]
+    fn test_utm_matches_epsg() {
+        let mut builder = CrsBuilder::new("WGS 84 / UTM zone 33N");
+        let utm = builder
+            .projected(Conversion::Utm {
+                zone: 33,
+                north: true,
+            })
+            .unwrap();
+        let definition = utm.to_projjson(None, None, None).unwrap();
+        let custom = Proj::new_known_crs("EPSG:4326", &definition, None).unwrap();
+        let epsg = Proj::new_known_crs("EPSG:4326", "EPSG:32633", None).unwrap();
+        let expected = epsg.convert((15.0, 52.0)).unwrap();
+        let result = custom.convert((15.0, 52.0)).unwrap();
+        assert_relative_eq!(result.0, expected.0, epsilon = 1e-6);
+        assert_relative_eq!(result.1, expected.1, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_export_definitions() {
+        let exported = export_definitions(
+            &["EPSG:4326", "🦀", "EPSG:3857"],
+            ExportFormat::Wkt(WktVersion::Wkt1Gdal),
+        );
+        assert_eq!(exported.len(), 3);
+        assert!(exported[0]
+            .as_ref()
+            .unwrap()
+            .starts_with("GEOGCS[\"WGS 84\""));
+        assert!(matches!(exported[1], Err(ProjError::Create(_))));
+        assert!(exported[2].as_ref().unwrap().starts_with("PROJCS"));
+
+        let exported = export_definitions(&["EPSG:4326"], ExportFormat::ProjString);
+        assert!(exported[0].as_ref().unwrap().contains("+proj=longlat"));
+        let exported = export_definitions(&["EPSG:4326"], ExportFormat::ProjJson);
+        assert!(exported[0].as_ref().unwrap().contains("GeographicCRS"));
+    }
+
+    #[test]
+    fn test_user_input() {
+        let wkt = Proj::new("EPSG:4326")
+            .unwrap()
+            .as_wkt(WktVersion::Wkt2_2019)
+            .unwrap();
+        let projjson = Proj::new("EPSG:4326")
+            .unwrap()
+            .to_projjson(None, None, None)
+            .unwrap();
+        let inputs = [
+            (wkt.as_str(), InputKind::Wkt),
+            (projjson.as_str(), InputKind::ProjJson),
+            ("EPSG:4326", InputKind::AuthorityCode),
+            ("EPSG:4326+5773", InputKind::AuthorityCode),
+            ("urn:ogc:def:crs:EPSG::4326", InputKind::Urn),
+            ("http://www.opengis.net/def/crs/EPSG/0/4326", InputKind::Url),
+            (
+                "+proj=utm +zone=31 +datum=WGS84 +type=crs",
+                InputKind::ProjString,
+            ),
+            (
+                "proj=utm zone=31 datum=WGS84 type=crs",
+                InputKind::ProjString,
+            ),
+            ("NAD83(2011)", InputKind::Name),
+        ];
+        for (input, kind) in inputs {
+            assert_eq!(classify_input(input), kind, "{}", input);
+            assert!(Proj::from_user_input(input).is_ok(), "{}", input);
+        }
+        assert_eq!(classify_input(" \n"), InputKind::Empty);
+        assert!(Proj::from_user_input(" ").is_err());
+        assert_eq!(classify_input("ETRS89 / UTM zone 31N"), InputKind::Name);
+        assert!(Proj::from_user_input("🦀").is_err());
+    }
+
+    #[test]
+    fn test_geographic() {
+        let mut builder = CrsBuilder::new("My NAD83");
+        builder.set_datum(Datum::nad83());
+        let crs = builder.geographic().unwrap();
+        let json = crs.to_projjson(None, None, None).unwrap();
+        assert!(json.contains("GeographicCRS"));
+        assert!(json.contains("My NAD83"));
+    }
+
+    #[test]
+    fn test_foot_false_easting() {
+        let mut builder = CrsBuilder::new("Feet TM");
+        builder.set_linear_unit(LinearUnit::foot());
+        let tm = builder
+            .projected(Conversion::TransverseMercator {
+                center_lat: 0.0,
+                center_lon: 0.0,
+                scale: 1.0,
+                false_easting: 1000.0,
+                false_northing: 0.0,
+            })
+            .unwrap();
+        let definition = tm.to_projjson(None, None, None).unwrap();
+        let proj = Proj::new_known_crs("EPSG:4326", &definition, None).unwrap();
+        let result = proj.convert((0.0, 0.0)).unwrap();
+        assert_relative_eq!(result.0, 1000.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_vertical() {
+        let mut builder = CrsBuilder::new("Site heights");
+        builder.set_datum(Datum::nad83());
+        let mut geoid = GeoidModel::new("us_noaa_g2018u0.tif");
+        geoid.accuracy = Some(0.02);
+        let heights = builder.vertical("Site vertical datum", &geoid).unwrap();
+        let json = heights.to_projjson(None, None, None).unwrap();
+        assert!(json.contains("VerticalCRS"));
+        assert!(json.contains("PROJ us_noaa_g2018u0.tif"));
+        let nad83 = Proj::new("EPSG:4269").unwrap();
+        let site = CrsBuilder::new("NAD83 + Site heights")
+            .compound(&nad83, &heights)
+            .unwrap();
+        let json = site.to_projjson(None, None, None).unwrap();
+        assert!(json.contains("CompoundCRS"));
+        assert!(json.contains("NAD83 + Site heights"));
+        // transforming to ellipsoidal heights uses the geoid grid
+        let conus = Area::new(-124.0, 25.0, -67.0, 49.0);
+        let missing = ProjBuilder::new()
+            .missing_grids(Some(conus), &[(&json, "EPSG:6319")])
+            .unwrap();
+        assert!(missing.contains(&"us_noaa_g2018u0.tif".to_string()));
+    }
+}
@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use crs_definitions::Def;
+use proj_sys::{
+    proj_get_id_auth_name, proj_get_id_code, proj_get_source_crs, proj_get_type,
+    PJ_TYPE_PJ_TYPE_BOUND_CRS,
+};
+
+use crate::proj::_string;
+use crate::{IntoCrs, Proj, ProjCreateError, ProjError};
+
+impl TryFrom<Def> for Proj {
+    type Error = ProjCreateError;
+
+    /// Create a `Proj` holding the CRS of a `crs-definitions` definition, from its WKT
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use proj::Proj;
+    ///
+    /// let crs = Proj::try_from(crs_definitions::EPSG_2230).unwrap();
+    /// let def = crs_definitions::Def::try_from(&crs).unwrap();
+    /// assert_eq!(def.code, 2230);
+    /// ```
+    fn try_from(def: Def) -> Result<Proj, Self::Error> {
+        Proj::new(def.wkt)
+    }
+}
+
+impl TryFrom<&Proj> for Def {
+    type Error = ProjError;
+
+    /// Look up the `crs-definitions` definition of a CRS identified by an EPSG code
+    ///
+    /// Returns [`ProjError::NoCrsDefinition`] if the CRS isn't identified by an EPSG code, for
+    /// instance because it was created from a PROJ string, or if `crs-definitions` doesn't know
+    /// the code.
+    fn try_from(crs: &Proj) -> Result<Def, Self::Error> {
+        epsg_code(crs)
+            .and_then(crs_definitions::from_code)
+            .ok_or(ProjError::NoCrsDefinition)
+    }
+}
+
+/// The CRS is passed as WKT, like the conversion to a `Proj`
+impl IntoCrs for Def {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        Ok(Cow::Borrowed(self.wkt))
+    }
+}
+
+/// The EPSG code of a CRS, or of the base CRS of a bound CRS, as created from WKT1 definitions
+/// with a `TOWGS84` clause
+fn epsg_code(crs: &Proj) -> Option<u16> {
+    if unsafe { proj_get_type(crs.as_ptr()) } == PJ_TYPE_PJ_TYPE_BOUND_CRS {
+        let base = crs
+            .derive(|ctx| unsafe { proj_get_source_crs(ctx, crs.as_ptr()) })
+            .ok()?;
+        return epsg_code(&base);
+    }
+    unsafe {
+        let auth = proj_get_id_auth_name(crs.as_ptr(), 0);
+        let code = proj_get_id_code(crs.as_ptr(), 0);
+        if auth.is_null() || code.is_null() || _string(auth).ok()? != "EPSG" {
+            return None;
+        }
+        _string(code).ok()?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_crs_definitions() {
+        let crs = Proj::try_from(crs_definitions::EPSG_4326).unwrap();
+        assert_eq!(Def::try_from(&crs).unwrap(), crs_definitions::EPSG_4326);
+        let crs = Proj::new("EPSG:26946").unwrap();
+        assert_eq!(Def::try_from(&crs).unwrap().code, 26946);
+        // bound to WGS 84 by its TOWGS84 clause
+        let crs = Proj::try_from(crs_definitions::EPSG_2230).unwrap();
+        assert_eq!(Def::try_from(&crs).unwrap(), crs_definitions::EPSG_2230);
+
+        let crs = Proj::new("+proj=longlat +datum=WGS84 +type=crs").unwrap();
+        assert!(matches!(
+            Def::try_from(&crs),
+            Err(ProjError::NoCrsDefinition)
+        ));
+
+        let to_merc =
+            Proj::new_known_crs(crs_definitions::EPSG_4326, crs_definitions::EPSG_3857, None)
+                .unwrap();
+        let result = to_merc.convert((2.3522, 48.8566)).unwrap();
+        assert_relative_eq!(result.0, 261845.70624393807, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,326 @@
+//! The PROJ database: its metadata, recording the releases of the datasets it was built from,
+//! and the authorities and codes of the objects it contains
+//!
+//! ```rust
+//! use proj::db::{self, EpsgVersion};
+//!
+//! let metadata = db::metadata().unwrap();
+//! // fail at startup, rather than producing coordinates from an outdated dataset
+//! metadata.require_epsg(EpsgVersion::new(10, 0)).unwrap();
+//! println!("EPSG dataset {}", metadata.epsg_version.unwrap());
+//! ```
+use std::ffi::CString;
+use std::fmt;
+use std::path::PathBuf;
+use std::ptr;
+
+use proj_sys::{
+    proj_get_authorities_from_database, proj_get_codes_from_database, proj_get_units_from_database,
+    proj_string_list_destroy, proj_unit_list_destroy, PJ_TYPE, PJ_TYPE_PJ_TYPE_COMPOUND_CRS,
+    PJ_TYPE_PJ_TYPE_CONCATENATED_OPERATION, PJ_TYPE_PJ_TYPE_CONVERSION, PJ_TYPE_PJ_TYPE_CRS,
+    PJ_TYPE_PJ_TYPE_DATUM_ENSEMBLE, PJ_TYPE_PJ_TYPE_DYNAMIC_GEODETIC_REFERENCE_FRAME,
+    PJ_TYPE_PJ_TYPE_DYNAMIC_VERTICAL_REFERENCE_FRAME, PJ_TYPE_PJ_TYPE_ELLIPSOID,
+    PJ_TYPE_PJ_TYPE_GEOCENTRIC_CRS, PJ_TYPE_PJ_TYPE_GEODETIC_CRS,
+    PJ_TYPE_PJ_TYPE_GEODETIC_REFERENCE_FRAME, PJ_TYPE_PJ_TYPE_GEOGRAPHIC_2D_CRS,
+    PJ_TYPE_PJ_TYPE_GEOGRAPHIC_3D_CRS, PJ_TYPE_PJ_TYPE_GEOGRAPHIC_CRS,
+    PJ_TYPE_PJ_TYPE_OTHER_COORDINATE_OPERATION, PJ_TYPE_PJ_TYPE_PRIME_MERIDIAN,
+    PJ_TYPE_PJ_TYPE_PROJECTED_CRS, PJ_TYPE_PJ_TYPE_TRANSFORMATION, PJ_TYPE_PJ_TYPE_VERTICAL_CRS,
+    PJ_TYPE_PJ_TYPE_VERTICAL_REFERENCE_FRAME, PROJ_STRING_LIST,
+};
+
+use crate::proj::_string;
+use crate::{ProjBuilder, ProjError};
+
+/// A release of the EPSG dataset, such as `v11.004`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EpsgVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl EpsgVersion {
+    pub fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parse a version as recorded in the database
+    fn parse(version: &str) -> Option<Self> {
+        let (major, minor) = version.trim_start_matches('v').split_once('.')?;
+        Some(Self {
+            major: major.parse().ok()?,
+            minor: minor.parse().ok()?,
+        })
+    }
+}
+
+impl fmt::Display for EpsgVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}.{:03}", self.major, self.minor)
+    }
+}
+
+/// The metadata of a PROJ database
+///
+/// Obtained using [`metadata`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatabaseMetadata {
+    /// The location of the database
+    pub path: PathBuf,
+    /// The version of the database layout, as `(major, minor)`
+    pub layout_version: (u32, u32),
+    /// The release of the EPSG dataset
+    pub epsg_version: Option<EpsgVersion>,
+    /// The publication date of the EPSG dataset, such as `2024-02-24`
+    pub epsg_date: Option<String>,
+    /// The release of the PROJ-data package of grids the database refers to, such as `1.17`
+    pub proj_data_version: Option<String>,
+}
+
+impl DatabaseMetadata {
+    /// Return an error if the EPSG dataset is older than `minimum`, or its release is unknown
+    pub fn require_epsg(&self, minimum: EpsgVersion) -> Result<(), ProjError> {
+        match self.epsg_version {
+            Some(found) if found >= minimum => Ok(()),
+            found => Err(ProjError::UnsupportedEpsgVersion { found, minimum }),
+        }
+    }
+}
+
+/// Return the metadata of the PROJ database found using PROJ's default search paths
+///
+/// Use [`ProjBuilder::database_metadata`] for the database of a configured context.
+pub fn metadata() -> Result<DatabaseMetadata, ProjError> {
+    let builder = ProjBuilder::new();
+    let layout = |key| -> Result<u32, ProjError> {
+        builder
+            .database_metadata(key)?
+            .and_then(|version| version.parse().ok())
+            .ok_or(ProjError::DatabaseNotFound)
+    };
+    Ok(DatabaseMetadata {
+        path: builder.database_path()?,
+        layout_version: (
+            layout("DATABASE.LAYOUT.VERSION.MAJOR")?,
+            layout("DATABASE.LAYOUT.VERSION.MINOR")?,
+        ),
+        epsg_version: builder
+            .database_metadata("EPSG.VERSION")?
+            .as_deref()
+            .and_then(EpsgVersion::parse),
+        epsg_date: builder.database_metadata("EPSG.DATE")?,
+        proj_data_version: builder.database_metadata("PROJ_DATA.VERSION")?,
+    })
+}
+
+/// The types of objects which can be listed using [`codes`]
+///
+/// Each type includes its subtypes: [`Crs`](#variant.Crs) lists every CRS, and
+/// [`GeographicCrs`](#variant.GeographicCrs) both 2D and 3D geographic CRSs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectType {
+    Ellipsoid,
+    PrimeMeridian,
+    GeodeticReferenceFrame,
+    DynamicGeodeticReferenceFrame,
+    VerticalReferenceFrame,
+    DynamicVerticalReferenceFrame,
+    DatumEnsemble,
+    Crs,
+    GeodeticCrs,
+    GeocentricCrs,
+    GeographicCrs,
+    Geographic2dCrs,
+    Geographic3dCrs,
+    VerticalCrs,
+    ProjectedCrs,
+    CompoundCrs,
+    /// Any coordinate operation
+    CoordinateOperation,
+    Conversion,
+    Transformation,
+    ConcatenatedOperation,
+}
+
+impl ObjectType {
+    fn pj_type(self) -> PJ_TYPE {
+        match self {
+            ObjectType::Ellipsoid => PJ_TYPE_PJ_TYPE_ELLIPSOID,
+            ObjectType::PrimeMeridian => PJ_TYPE_PJ_TYPE_PRIME_MERIDIAN,
+            ObjectType::GeodeticReferenceFrame => PJ_TYPE_PJ_TYPE_GEODETIC_REFERENCE_FRAME,
+            ObjectType::DynamicGeodeticReferenceFrame => {
+                PJ_TYPE_PJ_TYPE_DYNAMIC_GEODETIC_REFERENCE_FRAME
+            }
+            ObjectType::VerticalReferenceFrame => PJ_TYPE_PJ_TYPE_VERTICAL_REFERENCE_FRAME,
+            ObjectType::DynamicVerticalReferenceFrame => {
+                PJ_TYPE_PJ_TYPE_DYNAMIC_VERTICAL_REFERENCE_FRAME
+            }
+            ObjectType::DatumEnsemble => PJ_TYPE_PJ_TYPE_DATUM_ENSEMBLE,
+            ObjectType::Crs => PJ_TYPE_PJ_TYPE_CRS,
+            ObjectType::GeodeticCrs => PJ_TYPE_PJ_TYPE_GEODETIC_CRS,
+            ObjectType::GeocentricCrs => PJ_TYPE_PJ_TYPE_GEOCENTRIC_CRS,
+            ObjectType::GeographicCrs => PJ_TYPE_PJ_TYPE_GEOGRAPHIC_CRS,
+            ObjectType::Geographic2dCrs => PJ_TYPE_PJ_TYPE_GEOGRAPHIC_2D_CRS,
+            ObjectType::Geographic3dCrs => PJ_TYPE_PJ_TYPE_GEOGRAPHIC_3D_CRS,
+            ObjectType::VerticalCrs => PJ_TYPE_PJ_TYPE_VERTICAL_CRS,
+            ObjectType::ProjectedCrs => PJ_TYPE_PJ_TYPE_PROJECTED_CRS,
+            ObjectType::CompoundCrs => PJ_TYPE_PJ_TYPE_COMPOUND_CRS,
+            ObjectType::CoordinateOperation => PJ_TYPE_PJ_TYPE_OTHER_COORDINATE_OPERATION,
+            ObjectType::Conversion => PJ_TYPE_PJ_TYPE_CONVERSION,
+            ObjectType::Transformation => PJ_TYPE_PJ_TYPE_TRANSFORMATION,
+            ObjectType::ConcatenatedOperation => PJ_TYPE_PJ_TYPE_CONCATENATED_OPERATION,
+        }
+    }
+}
+
+/// Return the authorities of the objects in the PROJ database found using PROJ's default search
+/// paths, such as `EPSG`, `ESRI` and `IGNF`
+///
+/// ```rust
+/// let mut authorities = proj::db::authorities().unwrap();
+/// assert!(authorities.any(|authority| authority == "EPSG"));
+/// ```
+///
+/// # Safety
+/// This method contains unsafe code.
+pub fn authorities() -> Result<impl Iterator<Item = String>, ProjError> {
+    let builder = ProjBuilder::new();
+    let list = unsafe { proj_get_authorities_from_database(builder.ctx()) };
+    string_list(&builder, list)
+}
+
+/// Return the codes of the objects of a type defined by an authority, excluding deprecated
+/// ones, in the PROJ database found using PROJ's default search paths
+///
+/// The list is empty if the authority is unknown.
+///
+/// ```rust
+/// use proj::db::{self, ObjectType};
+///
+/// let mut codes = db::codes("EPSG", ObjectType::ProjectedCrs).unwrap();
+/// // NAD83 / California zone 6 (ftUS)
+/// assert!(codes.any(|code| code == "2230"));
+/// assert_eq!(db::codes("NOPE", ObjectType::Crs).unwrap().count(), 0);
+/// ```
+///
+/// # Safety
+/// This method contains unsafe code.
+pub fn codes(
+    authority: &str,
+    object_type: ObjectType,
+) -> Result<impl Iterator<Item = String>, ProjError> {
+    let builder = ProjBuilder::new();
+    let authority = CString::new(authority)?;
+    let list = unsafe {
+        proj_get_codes_from_database(builder.ctx(), authority.as_ptr(), object_type.pj_type(), 0)
+    };
+    string_list(&builder, list)
+}
+
+/// Return the PROJ short names of the units in the database, such as `us-ft`, with their
+/// category, such as `linear` or `angular`
+pub(crate) fn unit_short_names(builder: &ProjBuilder) -> Result<Vec<(String, String)>, ProjError> {
+    let mut count = 0;
+    let list = unsafe {
+        proj_get_units_from_database(builder.ctx(), ptr::null(), ptr::null(), 1, &mut count)
+    };
+    if list.is_null() {
+        return Err(ProjError::Database(builder.database_path()?));
+    }
+    let mut units = vec![];
+    let mut result = Ok(());
+    for i in 0..count.max(0) as usize {
+        let unit = unsafe { &**list.add(i) };
+        if unit.proj_short_name.is_null() {
+            continue;
+        }
+        match unsafe { (_string(unit.proj_short_name), _string(unit.category)) } {
+            (Ok(name), Ok(category)) => units.push((name, category)),
+            (Err(e), _) | (_, Err(e)) => result = Err(e),
+        }
+    }
+    unsafe { proj_unit_list_destroy(list) };
+    result?;
+    Ok(units)
+}
+
+/// Copy and destroy a list returned by a database query, which is null if the database couldn't
+/// be read
+pub(crate) fn string_list(
+    builder: &ProjBuilder,
+    list: PROJ_STRING_LIST,
+) -> Result<std::vec::IntoIter<String>, ProjError> {
+    if list.is_null() {
+        return Err(ProjError::Database(builder.database_path()?));
+    }
+    let mut strings = vec![];
+    let mut result = Ok(());
+    unsafe {
+        let mut entry = list;
+        while !(*entry).is_null() {
+            match _string(*entry) {
+                Ok(string) => strings.push(string),
+                Err(e) => result = Err(e),
+            }
+            entry = entry.add(1);
+        }
+        proj_string_list_destroy(list);
+    }
+    result?;
+    Ok(strings.into_iter())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_metadata() {
+        let metadata = metadata().unwrap();
+        assert_eq!(metadata.layout_version.0, 1);
+        assert!(metadata.epsg_version.unwrap() >= EpsgVersion::new(11, 0));
+        assert!(metadata.epsg_date.is_some());
+        assert!(metadata.proj_data_version.is_some());
+        assert!(metadata.require_epsg(EpsgVersion::new(10, 98)).is_ok());
+        assert_eq!(
+            metadata
+                .require_epsg(EpsgVersion::new(99, 0))
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "EPSG dataset v99.000 or later is required, but the database has {}",
+                metadata.epsg_version.unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_codes() {
+        let authorities: Vec<String> = authorities().unwrap().collect();
+        assert!(authorities.contains(&"EPSG".to_string()));
+        assert!(authorities.contains(&"PROJ".to_string()));
+        let geographic: Vec<String> = codes("EPSG", ObjectType::GeographicCrs).unwrap().collect();
+        // WGS 84, 2D and 3D
+        assert!(geographic.contains(&"4326".to_string()));
+        assert!(geographic.contains(&"4979".to_string()));
+        let geographic_2d: Vec<String> = codes("EPSG", ObjectType::Geographic2dCrs)
+            .unwrap()
+            .collect();
+        assert!(!geographic_2d.contains(&"4979".to_string()));
+        assert!(geographic_2d.len() < geographic.len());
+        // deprecated objects are excluded: NAD83(CSRS98)
+        assert!(!geographic_2d.contains(&"4140".to_string()));
+        assert!(codes("EPSG", ObjectType::Ellipsoid)
+            .unwrap()
+            .any(|code| code == "7030"));
+        assert!(codes("EPSG\0", ObjectType::Crs).is_err());
+    }
+
+    #[test]
+    fn test_epsg_version() {
+        let version = EpsgVersion::parse("v11.004").unwrap();
+        assert_eq!(version, EpsgVersion::new(11, 4));
+        assert_eq!(version.to_string(), "v11.004");
+        assert!(version < EpsgVersion::new(11, 10));
+        assert_eq!(EpsgVersion::parse("unknown"), None);
+    }
+}
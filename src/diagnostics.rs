@@ -0,0 +1,266 @@
+//! Diagnostics explaining why a transformation couldn't be created
+use std::ffi::CString;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::ptr;
+
+use proj_sys::{
+    proj_area_create, proj_area_destroy, proj_context_errno, proj_context_errno_string,
+    proj_context_get_database_path, proj_context_is_network_enabled, proj_create,
+    proj_create_crs_to_crs, proj_create_operation_factory_context, proj_create_operations,
+    proj_destroy, proj_info, proj_list_destroy, proj_list_get_count,
+    proj_operation_factory_context_destroy, proj_operation_factory_context_set_area_of_interest,
+    proj_operation_factory_context_set_grid_availability_use,
+    proj_operation_factory_context_set_spatial_criterion, PJ,
+    PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_DISCARD_OPERATION_IF_MISSING_GRID,
+    PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_KNOWN_AVAILABLE,
+    PROJ_SPATIAL_CRITERION_PROJ_SPATIAL_CRITERION_PARTIAL_INTERSECTION,
+};
+
+use crate::proj::{_string, area_set_bbox};
+use crate::{Area, Context, PjPtrExt, ProjBuilder};
+
+/// The state of a context after it failed to create a transformation between two CRSs
+///
+/// Obtained using [`ProjBuilder::diagnose_known_crs`]. Failures of
+/// [`Proj::new_known_crs`](crate::Proj::new_known_crs) and similar functions are also
+/// described at the `debug` log level. The [`Display`] implementation summarises every field on
+/// one line:
+///
+/// ```rust
+/// let builder = proj::ProjBuilder::new();
+/// let diagnostics = builder.diagnose_known_crs("EPSG:4326", "EPSG:0", None);
+/// assert!(!diagnostics.target_valid);
+/// assert_eq!(diagnostics.candidate_operations, None);
+/// println!("{}", diagnostics);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreationDiagnostics {
+    /// The PROJ error number, or 0 if none was set
+    pub errno: i32,
+    /// PROJ's description of the error number, if any
+    pub message: Option<String>,
+    /// Whether the source CRS could be created on its own
+    pub source_valid: bool,
+    /// Whether the target CRS could be created on its own
+    pub target_valid: bool,
+    /// The PROJ database in use, or `None` if it wasn't found
+    pub database_path: Option<PathBuf>,
+    /// The resource file search paths
+    pub search_paths: Vec<PathBuf>,
+    /// Whether network access is enabled
+    pub network_enabled: bool,
+    /// The number of operations between the CRSs which can be used, considering the area of
+    /// interest and the available grids, or `None` if either CRS is invalid
+    pub candidate_operations: Option<usize>,
+}
+
+impl Display for CreationDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |valid| if valid { "valid" } else { "invalid" };
+        write!(
+            f,
+            "{} (errno {}); source CRS {}; target CRS {}; database: ",
+            self.message.as_deref().unwrap_or("no error"),
+            self.errno,
+            describe(self.source_valid),
+            describe(self.target_valid)
+        )?;
+        match &self.database_path {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => f.write_str("not found")?,
+        }
+        let search_paths = self
+            .search_paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "; search paths: [{}]; network {}; candidate operations: ",
+            search_paths.join(", "),
+            if self.network_enabled {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        )?;
+        match self.candidate_operations {
+            Some(count) => write!(f, "{}", count),
+            None => f.write_str("unknown"),
+        }
+    }
+}
+
+impl ProjBuilder {
+    /// Describe why a transformation between two CRSs can't be created using this builder, or
+    /// the state of its context if it can
+    ///
+    /// The transformation, the CRSs and the candidate operations are created in the builder's
+    /// context, whose settings are reported. The builder can still be used afterwards.
+    ///
+    /// ```rust
+    /// let builder = proj::ProjBuilder::new();
+    /// let diagnostics = builder.diagnose_known_crs("EPSG:2230", "EPSG:26946", None);
+    /// assert!(diagnostics.source_valid && diagnostics.target_valid);
+    /// assert!(diagnostics.candidate_operations.unwrap() > 0);
+    /// assert!(diagnostics.database_path.is_some());
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn diagnose_known_crs(
+        &self,
+        from: &str,
+        to: &str,
+        area: Option<Area>,
+    ) -> CreationDiagnostics {
+        let (Ok(from_c), Ok(to_c)) = (CString::new(from), CString::new(to)) else {
+            return diagnose(&self.ctx, 0, from, to, area);
+        };
+        let errno = unsafe {
+            let proj_area = proj_area_create();
+            area_set_bbox(proj_area, area);
+            let ptr = proj_create_crs_to_crs(self.ctx(), from_c.as_ptr(), to_c.as_ptr(), proj_area);
+            proj_area_destroy(proj_area);
+            if ptr.is_null() {
+                proj_context_errno(self.ctx())
+            } else {
+                proj_destroy(ptr);
+                0
+            }
+        };
+        diagnose(&self.ctx, errno, from, to, area)
+    }
+}
+
+/// Describe a context which failed to create a transformation from `from` to `to` with `errno`
+pub(crate) fn diagnose(
+    ctx: &Context,
+    errno: i32,
+    from: &str,
+    to: &str,
+    area: Option<Area>,
+) -> CreationDiagnostics {
+    let message = match errno {
+        0 => None,
+        errno => unsafe {
+            let message = proj_context_errno_string(ctx.as_ptr(), errno);
+            if message.is_null() {
+                None
+            } else {
+                _string(message).ok()
+            }
+        },
+    };
+    let create = |definition: &str| {
+        let definition = CString::new(definition).ok()?;
+        unsafe { proj_create(ctx.as_ptr(), definition.as_ptr()).into_pj_ref(ctx) }.ok()
+    };
+    let source = create(from);
+    let target = create(to);
+    let candidate_operations = match (&source, &target) {
+        (Some(source), Some(target)) => {
+            Some(unsafe { candidate_count(ctx, source.as_ptr(), target.as_ptr(), area) })
+        }
+        _ => None,
+    };
+    let database_path = unsafe {
+        let path = proj_context_get_database_path(ctx.as_ptr());
+        if path.is_null() {
+            None
+        } else {
+            _string(path).ok().map(PathBuf::from)
+        }
+    };
+    let search_paths = ctx.data.search_paths.clone().unwrap_or_else(|| {
+        let pathsep = if cfg!(windows) { ';' } else { ':' };
+        let searchpath = unsafe { _string(proj_info().searchpath) }.unwrap_or_default();
+        searchpath
+            .split(pathsep)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .collect()
+    });
+    let network_enabled = unsafe { proj_context_is_network_enabled(ctx.as_ptr()) } == 1;
+    CreationDiagnostics {
+        errno,
+        message,
+        source_valid: source.is_some(),
+        target_valid: target.is_some(),
+        database_path,
+        search_paths,
+        network_enabled,
+        candidate_operations,
+    }
+}
+
+/// Count the operations between two CRSs which `proj_create_crs_to_crs` would choose from
+unsafe fn candidate_count(
+    ctx: &Context,
+    source: *const PJ,
+    target: *const PJ,
+    area: Option<Area>,
+) -> usize {
+    let factory = proj_create_operation_factory_context(ctx.as_ptr(), ptr::null());
+    if let Some(area) = area {
+        proj_operation_factory_context_set_area_of_interest(
+            ctx.as_ptr(),
+            factory,
+            area.west,
+            area.south,
+            area.east,
+            area.north,
+        );
+    }
+    proj_operation_factory_context_set_spatial_criterion(
+        ctx.as_ptr(),
+        factory,
+        PROJ_SPATIAL_CRITERION_PROJ_SPATIAL_CRITERION_PARTIAL_INTERSECTION,
+    );
+    let grid_availability = if proj_context_is_network_enabled(ctx.as_ptr()) == 1 {
+        PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_KNOWN_AVAILABLE
+    } else {
+        PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_DISCARD_OPERATION_IF_MISSING_GRID
+    };
+    proj_operation_factory_context_set_grid_availability_use(
+        ctx.as_ptr(),
+        factory,
+        grid_availability,
+    );
+    let list = proj_create_operations(ctx.as_ptr(), source, target, factory);
+    proj_operation_factory_context_destroy(factory);
+    if list.is_null() {
+        return 0;
+    }
+    let count = proj_list_get_count(list);
+    proj_list_destroy(list);
+    count.max(0) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Proj;
+
+    #[test]
+    fn test_diagnose_known_crs() {
+        let builder = ProjBuilder::new();
+        let diagnostics = builder.diagnose_known_crs("EPSG:4326", "EPSG:3857", None);
+        assert_eq!(diagnostics.errno, 0);
+        assert_eq!(diagnostics.message, None);
+        assert!(diagnostics.candidate_operations.unwrap() > 0);
+        assert_eq!(diagnostics.search_paths, builder.search_paths().unwrap());
+        // the source CRS doesn't exist
+        assert!(Proj::new_known_crs("EPSG:4", "EPSG:3857", None).is_err());
+        let diagnostics = builder.diagnose_known_crs("EPSG:4", "EPSG:3857", None);
+        assert!(!diagnostics.source_valid);
+        assert!(diagnostics.target_valid);
+        assert_ne!(diagnostics.errno, 0);
+        assert!(diagnostics.message.is_some());
+        assert!(diagnostics.to_string().contains("source CRS invalid"));
+        builder
+            .proj_known_crs("EPSG:4326", "EPSG:3857", None)
+            .unwrap();
+    }
+}
@@ -0,0 +1,269 @@
+//! A pure-Rust implementation of trivially computable operations
+//!
+//! Transformations consisting only of axis swaps, horizontal unit conversions and the web
+//! mercator projection are executed without calling into libproj for every point. The
+//! operation is recognised from the PROJ string definition of the transformation: any step or
+//! parameter which isn't understood means that the fast path isn't used.
+//!
+//! Points which a step would reject (invalid latitudes, non-finite coordinates…) aren't
+//! handled here: the caller falls back to libproj, so that errors are reported exactly as
+//! they would be otherwise.
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// PROJ's tolerance for latitudes exceeding ±90°
+const EPS_LAT: f64 = 1e-12;
+
+/// The conversion factors to metres or radians of the units handled by `unitconvert`
+const UNITS: [(&str, f64); 10] = [
+    ("m", 1.0),
+    ("km", 1000.0),
+    ("dm", 0.1),
+    ("cm", 0.01),
+    ("mm", 0.001),
+    ("ft", 0.3048),
+    ("us-ft", 1200.0 / 3937.0),
+    ("rad", 1.0),
+    ("deg", 0.017453292519943296),
+    ("grad", 0.015707963267948967),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operation {
+    Noop,
+    /// The source axis of each output axis (0 or 1), and whether its sign is flipped
+    AxisSwap([(usize, bool); 2]),
+    UnitConvert(f64),
+    WebMercator {
+        a: f64,
+        lam0: f64,
+        x0: f64,
+        y0: f64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Step {
+    operation: Operation,
+    inverse: bool,
+}
+
+/// A transformation executed in pure Rust
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FastPath {
+    steps: Vec<Step>,
+}
+
+impl FastPath {
+    /// Recognise a PROJ string definition, as returned by `proj_pj_info`
+    pub(crate) fn parse(definition: &str) -> Option<Self> {
+        let tokens: Vec<&str> = definition.split_whitespace().collect();
+        let steps = match tokens.split_first() {
+            Some((&"proj=pipeline", rest)) => {
+                if rest.first() != Some(&"step") {
+                    return None;
+                }
+                rest[1..]
+                    .split(|token| *token == "step")
+                    .map(parse_step)
+                    .collect::<Option<Vec<_>>>()?
+            }
+            Some(_) => vec![parse_step(&tokens)?],
+            None => return None,
+        };
+        Some(FastPath { steps })
+    }
+
+    /// Transform a point, returning `None` if it must be handed to libproj instead
+    pub(crate) fn transform(&self, x: f64, y: f64, inverse: bool) -> Option<(f64, f64)> {
+        if !x.is_finite() || !y.is_finite() {
+            return None;
+        }
+        let mut point = (x, y);
+        if inverse {
+            for step in self.steps.iter().rev() {
+                point = step.operation.apply(point, !step.inverse)?;
+            }
+        } else {
+            for step in &self.steps {
+                point = step.operation.apply(point, step.inverse)?;
+            }
+        }
+        Some(point)
+    }
+}
+
+impl Operation {
+    fn apply(&self, (x, y): (f64, f64), inverse: bool) -> Option<(f64, f64)> {
+        match *self {
+            Operation::Noop => Some((x, y)),
+            Operation::AxisSwap(order) => {
+                let input = [x, y];
+                let mut output = [0.0; 2];
+                for (i, &(axis, negate)) in order.iter().enumerate() {
+                    let sign = if negate { -1.0 } else { 1.0 };
+                    if inverse {
+                        output[axis] = sign * input[i];
+                    } else {
+                        output[i] = sign * input[axis];
+                    }
+                }
+                Some((output[0], output[1]))
+            }
+            Operation::UnitConvert(factor) => {
+                if inverse {
+                    Some((x / factor, y / factor))
+                } else {
+                    Some((x * factor, y * factor))
+                }
+            }
+            Operation::WebMercator { a, lam0, x0, y0 } => {
+                if inverse {
+                    let x = (x - x0) * (1.0 / a);
+                    let y = (y - y0) * (1.0 / a);
+                    let phi = y.sinh().atan();
+                    Some((adjlon(x + lam0), phi))
+                } else {
+                    // libproj reports out of range coordinates
+                    if y.abs() - FRAC_PI_2 > EPS_LAT || !(-10.0..=10.0).contains(&x) {
+                        return None;
+                    }
+                    let phi = y.clamp(-FRAC_PI_2, FRAC_PI_2);
+                    let lam = adjlon(adjlon(x) - lam0);
+                    Some((a * lam + x0, a * phi.tan().asinh() + y0))
+                }
+            }
+        }
+    }
+}
+
+/// Wrap a longitude in radians to [-π, π], as libproj does
+fn adjlon(lon: f64) -> f64 {
+    if lon.abs() < PI + 1e-12 {
+        return lon;
+    }
+    let lon = lon + PI;
+    lon - 2.0 * PI * (lon / (2.0 * PI)).floor() - PI
+}
+
+fn parse_step(tokens: &[&str]) -> Option<Step> {
+    let mut inverse = false;
+    let mut name = None;
+    let mut params = vec![];
+    for token in tokens {
+        match token.split_once('=') {
+            Some(("proj", value)) if name.is_none() => name = Some(value),
+            Some(param) => params.push(param),
+            None if *token == "inv" && !inverse => inverse = true,
+            None => return None,
+        }
+    }
+    let operation = match name? {
+        "noop" => {
+            if params.iter().any(|(key, _)| *key != "ellps") {
+                return None;
+            }
+            Operation::Noop
+        }
+        "axisswap" => match params.as_slice() {
+            [("order", order)] => Operation::AxisSwap(parse_order(order)?),
+            _ => return None,
+        },
+        "unitconvert" => {
+            let mut factor = 1.0;
+            for (key, value) in params {
+                match key {
+                    "xy_in" => factor *= unit_factor(value)?,
+                    "xy_out" => factor /= unit_factor(value)?,
+                    // the default ellipsoid is added to the definition, but unused
+                    "ellps" => {}
+                    _ => return None,
+                }
+            }
+            Operation::UnitConvert(factor)
+        }
+        "webmerc" => {
+            let (mut a, mut lam0, mut x0, mut y0) = (None, 0.0, 0.0, 0.0);
+            for (key, value) in params {
+                match key {
+                    // the projection is defined at the equator regardless
+                    "lat_0" => {
+                        value.parse::<f64>().ok()?;
+                    }
+                    "lon_0" => lam0 = value.parse::<f64>().ok()?.to_radians(),
+                    "x_0" => x0 = value.parse().ok()?,
+                    "y_0" => y0 = value.parse().ok()?,
+                    "ellps" if value == "WGS84" && a.is_none() => a = Some(6378137.0),
+                    "a" if a.is_none() => a = Some(value.parse().ok()?),
+                    _ => return None,
+                }
+            }
+            Operation::WebMercator {
+                a: a?,
+                lam0,
+                x0,
+                y0,
+            }
+        }
+        _ => return None,
+    };
+    Some(Step { operation, inverse })
+}
+
+fn parse_order(order: &str) -> Option<[(usize, bool); 2]> {
+    let mut axes = order.split(',').map(|axis| match axis {
+        "1" => Some((0, false)),
+        "2" => Some((1, false)),
+        "-1" => Some((0, true)),
+        "-2" => Some((1, true)),
+        _ => None,
+    });
+    let first = axes.next()??;
+    let second = axes.next()??;
+    if axes.next().is_some() || first.0 == second.0 {
+        return None;
+    }
+    Some([first, second])
+}
+
+fn unit_factor(unit: &str) -> Option<f64> {
+    UNITS
+        .iter()
+        .find(|(name, _)| *name == unit)
+        .map(|(_, factor)| *factor)
+        .or_else(|| unit.parse().ok().filter(|factor: &f64| *factor > 0.0))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let fast = FastPath::parse(
+            "proj=pipeline step proj=axisswap order=2,1 step proj=unitconvert xy_in=deg \
+             xy_out=rad step proj=webmerc lat_0=0 lon_0=0 x_0=0 y_0=0 ellps=WGS84",
+        )
+        .unwrap();
+        assert_eq!(fast.steps.len(), 3);
+        assert_eq!(
+            fast.steps[0].operation,
+            Operation::AxisSwap([(1, false), (0, false)])
+        );
+        assert!(FastPath::parse("proj=noop ellps=GRS80").is_some());
+        assert!(FastPath::parse("proj=webmerc ellps=WGS84").is_some());
+        // unsupported steps and parameters
+        assert!(FastPath::parse("proj=merc ellps=WGS84").is_none());
+        assert!(FastPath::parse("proj=webmerc datum=WGS84 ellps=WGS84 towgs84=0,0,0").is_none());
+        assert!(FastPath::parse("proj=unitconvert xy_in=deg xy_out=rad z_in=m").is_none());
+        assert!(FastPath::parse("proj=pipeline step proj=axisswap order=2,1,3").is_none());
+        assert!(FastPath::parse("proj=pipeline step omit_inv proj=noop").is_none());
+        assert!(FastPath::parse("").is_none());
+    }
+
+    #[test]
+    fn test_adjlon() {
+        assert_eq!(adjlon(1.0), 1.0);
+        assert!((adjlon(3.0 * PI / 2.0) + PI / 2.0).abs() < 1e-15);
+        assert!((adjlon(-5.0 * PI / 2.0) + PI / 2.0).abs() < 1e-15);
+    }
+}
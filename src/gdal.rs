@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+
+use gdal::errors::GdalError;
+use gdal::spatial_ref::SpatialRef;
+
+use crate::{Proj, ProjCreateError, WktVersion};
+
+impl TryFrom<&SpatialRef> for Proj {
+    type Error = ProjCreateError;
+
+    /// Create a `Proj` holding the CRS of a GDAL spatial reference
+    ///
+    /// The CRS is exchanged as PROJJSON, which, unlike the WKT1 exported by GDAL by default,
+    /// preserves every detail of the definition.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gdal::spatial_ref::SpatialRef;
+    /// use proj::Proj;
+    ///
+    /// let srs = SpatialRef::from_epsg(2230).unwrap();
+    /// let crs = Proj::try_from(&srs).unwrap();
+    /// assert_eq!(SpatialRef::try_from(&crs).unwrap(), srs);
+    /// ```
+    fn try_from(srs: &SpatialRef) -> Result<Proj, Self::Error> {
+        let projjson = srs.to_projjson().map_err(|e| {
+            ProjCreateError::ProjError(format!("GDAL couldn't export the CRS: {}", e))
+        })?;
+        Proj::new(&projjson)
+    }
+}
+
+impl TryFrom<&Proj> for SpatialRef {
+    type Error = GdalError;
+
+    /// Create a GDAL spatial reference from the CRS held by a `Proj`, exchanged as WKT2
+    ///
+    /// Returns an error if the `Proj` isn't a CRS.
+    fn try_from(crs: &Proj) -> Result<SpatialRef, Self::Error> {
+        let wkt = crs
+            .as_wkt(WktVersion::Wkt2_2019)
+            .map_err(|e| GdalError::BadArgument(e.to_string()))?;
+        SpatialRef::from_wkt(&wkt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_spatial_ref() {
+        let srs = SpatialRef::from_epsg(2230).unwrap();
+        let crs = Proj::try_from(&srs).unwrap();
+        assert!(crs
+            .as_wkt(WktVersion::Wkt2_2019)
+            .unwrap()
+            .contains("NAD83 / California zone 6 (ftUS)"));
+        let roundtrip = SpatialRef::try_from(&crs).unwrap();
+        assert_eq!(roundtrip.auth_code().unwrap(), 2230);
+
+        let merc = Proj::new("+proj=merc +ellps=WGS84").unwrap();
+        assert!(SpatialRef::try_from(&merc).is_err());
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{Proj, ProjError, Transform};
+use crate::{CoordTransform, ProjError, Transform};
 use geo_types::{coord, Geometry};
 
+// Geometries made of several parts are transformed into a copy, which replaces them once every
+// part has been, so that they are left unchanged if one fails. Single parts are transformed in
+// place by `convert_array`, which leaves them unchanged on error.
+
 ///```rust
 /// # use approx::assert_relative_eq;
 /// extern crate proj;
@@ -61,13 +65,13 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = self.clone();
         output.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
         match self {
             Geometry::Point(g) => g.transform(proj),
             Geometry::Line(g) => g.transform(proj),
@@ -89,13 +93,13 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = *self;
         output.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
         *self = proj.convert(*self)?;
         Ok(())
     }
@@ -107,13 +111,13 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = *self;
         output.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
         self.0.transform(proj)
     }
 }
@@ -124,15 +128,15 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = *self;
-        output.transform(proj)?;
+        output.start.transform(proj)?;
+        output.end.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
-        self.start.transform(proj)?;
-        self.end.transform(proj)?;
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        *self = self.transformed(proj)?;
         Ok(())
     }
 }
@@ -143,13 +147,13 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = self.clone();
         output.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
         proj.convert_array(&mut self.0)?;
         Ok(())
     }
@@ -161,27 +165,27 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = self.clone();
-        output.transform(proj)?;
-        Ok(output)
-    }
-
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
         let mut exterior_result = Ok(());
-        self.exterior_mut(|exterior| {
+        output.exterior_mut(|exterior| {
             exterior_result = exterior.transform(proj);
         });
         exterior_result?;
 
         let mut interiors_result = Ok(());
-        self.interiors_mut(|interiors| {
+        output.interiors_mut(|interiors| {
             interiors_result = interiors
                 .iter_mut()
                 .try_for_each(|interior| interior.transform(proj))
         });
         interiors_result?;
 
+        Ok(output)
+    }
+
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        *self = self.transformed(proj)?;
         Ok(())
     }
 }
@@ -192,13 +196,13 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = self.clone();
         output.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
         proj.convert_array(&mut self.0)?;
         Ok(())
     }
@@ -210,16 +214,16 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = self.clone();
-        output.transform(proj)?;
+        for line_string in &mut output.0 {
+            line_string.transform(proj)?;
+        }
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
-        for line_string in &mut self.0 {
-            line_string.transform(proj)?;
-        }
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        *self = self.transformed(proj)?;
         Ok(())
     }
 }
@@ -230,16 +234,16 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = self.clone();
-        output.transform(proj)?;
+        for polygon in &mut output.0 {
+            polygon.transform(proj)?;
+        }
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
-        for polygon in &mut self.0 {
-            polygon.transform(proj)?;
-        }
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        *self = self.transformed(proj)?;
         Ok(())
     }
 }
@@ -250,16 +254,16 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = self.clone();
-        output.transform(proj)?;
+        for geometry in &mut output.0 {
+            geometry.transform(proj)?;
+        }
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
-        for geometry in &mut self.0 {
-            geometry.transform(proj)?;
-        }
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        *self = self.transformed(proj)?;
         Ok(())
     }
 }
@@ -270,13 +274,13 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = *self;
         output.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
         let a = self.min();
         let b = self.max();
         let new = geo_types::Rect::new(proj.convert(a)?, proj.convert(b)?);
@@ -291,16 +295,16 @@ where
 {
     type Output = Self;
 
-    fn transformed(&self, proj: &Proj) -> Result<Self, ProjError> {
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
         let mut output = *self;
-        output.transform(proj)?;
+        output.0.transform(proj)?;
+        output.1.transform(proj)?;
+        output.2.transform(proj)?;
         Ok(output)
     }
 
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError> {
-        self.0.transform(proj)?;
-        self.1.transform(proj)?;
-        self.2.transform(proj)?;
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        *self = self.transformed(proj)?;
         Ok(())
     }
 }
@@ -308,6 +312,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Proj;
     use geo_types::{point, MultiPoint, Rect};
 
     #[test]
@@ -320,6 +325,43 @@ mod tests {
         assert_relative_eq!(subject, expected, epsilon = 0.2);
     }
 
+    #[test]
+    fn test_point_epsg_codes() {
+        let subject = point!(x: 4760096.421921f64, y: 3744293.729449f64);
+        let nad83 = Proj::new("EPSG:26946").unwrap();
+        let expected = point!(x: 1450880.29f64, y: 1141263.01f64);
+        assert_relative_eq!(
+            subject.transformed_crs_to_crs(2230, 26946).unwrap(),
+            expected,
+            epsilon = 0.2
+        );
+        assert_relative_eq!(
+            subject.transformed_crs_to_crs(2230, &nad83).unwrap(),
+            expected,
+            epsilon = 0.2
+        );
+        assert!(subject
+            .transformed_crs_to_crs(2230, Proj::new("+proj=merc").unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_mapper() {
+        let proj = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+        let subject = geo_types::LineString::from(vec![
+            (4760096.421921f64, 3744293.729449f64),
+            (4760196.421921f64, 3744393.729449f64),
+        ]);
+        let mapper = proj.mapper();
+        let converted: geo_types::LineString<f64> = subject
+            .coords()
+            .map(|&coord| mapper(coord))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .into();
+        assert_relative_eq!(converted, subject.transformed(&proj).unwrap());
+    }
+
     #[test]
     fn test_rect() {
         let mut subject = {
@@ -358,6 +400,22 @@ mod tests {
         assert_relative_eq!(subject, expected, epsilon = 0.2);
     }
 
+    #[test]
+    fn test_convert_collect_multi_point() {
+        let proj = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+        let points = vec![
+            point!(x: 4760096.421921f64, y: 3744293.729449f64),
+            point!(x: 4760196.421921f64, y: 3744393.729449f64),
+        ];
+        let subject: MultiPoint<f64> = proj.convert_collect(points).unwrap();
+        let expected = {
+            let point_a = point!(x: 1450880.2910605022, y:  1141263.0111604782);
+            let point_b = point!(x: 1450910.771121464, y: 1141293.4912214363);
+            MultiPoint(vec![point_a, point_b])
+        };
+        assert_relative_eq!(subject, expected, epsilon = 0.2);
+    }
+
     #[test]
     fn test_geometry_collection() {
         let mut subject = {
@@ -392,4 +450,51 @@ mod tests {
         };
         assert_relative_eq!(subject, expected, epsilon = 0.2);
     }
+
+    #[test]
+    fn test_error_leaves_geometry_unchanged() {
+        let merc = Proj::new("+proj=merc +ellps=WGS84").unwrap();
+        // the hole has an invalid latitude, in radians
+        let polygon = geo_types::Polygon::new(
+            geo_types::LineString::from(vec![(0.0, 0.0), (0.1, 0.0), (0.1, 0.1), (0.0, 0.0)]),
+            vec![geo_types::LineString::from(vec![
+                (0.01, 0.01),
+                (0.02, 2.0),
+                (0.02, 0.02),
+                (0.01, 0.01),
+            ])],
+        );
+        let mut subject = geo_types::MultiPolygon(vec![polygon.clone(), polygon.clone()]);
+        let original = subject.clone();
+        assert!(subject.transform(&merc).is_err());
+        assert_eq!(subject, original);
+        let mut subject = Geometry::from(polygon.clone());
+        assert!(subject.transform(&merc).is_err());
+        assert_eq!(subject, Geometry::from(polygon));
+    }
+
+    #[test]
+    fn test_transform_at_epoch() {
+        let itrf_to_etrf = Proj::new_known_crs("EPSG:9000", "EPSG:9067", None).unwrap();
+        let line = geo_types::LineString::from(vec![(2.0, 48.0), (3.0, 49.0)]);
+        let at_2010 = line.transformed_at_epoch(&itrf_to_etrf, 2010.0).unwrap();
+        let at_2020 = line.transformed_at_epoch(&itrf_to_etrf, 2020.0).unwrap();
+        // ETRF2000 drifts away from ITRF2014 by a few millimetres a year
+        assert_relative_ne!(at_2010, at_2020, epsilon = 1e-7);
+        let mut moved = line.clone();
+        moved
+            .transform_crs_to_crs_at_epoch("EPSG:9000", "EPSG:9067", 2020.0)
+            .unwrap();
+        assert_relative_eq!(moved, at_2020, epsilon = 1e-12);
+        assert_relative_eq!(
+            line.transformed_crs_to_crs_at_epoch("EPSG:9000", "EPSG:9067", 2020.0)
+                .unwrap(),
+            at_2020,
+            epsilon = 1e-12
+        );
+        // an operation isn't a CRS
+        assert!(line
+            .transformed_crs_to_crs_at_epoch("+proj=merc", "EPSG:9067", 2020.0)
+            .is_err());
+    }
 }
@@ -0,0 +1,363 @@
+//! Geodesic lengths and areas of `geo-types` geometries, on the ellipsoid of a CRS
+use geo_types::{LineString, Polygon};
+use proj_sys::{
+    proj_create, proj_ellipsoid_get_parameters, proj_geod, proj_get_ellipsoid, proj_get_source_crs,
+    proj_is_crs, proj_lp_dist, PJ_COORD, PJ_LPZT,
+};
+use std::f64::consts::PI;
+use std::ffi::CString;
+use std::ptr;
+
+use crate::proj::CoordinateType;
+use crate::{PjPtrExt, Proj, ProjError};
+
+/// The maximum length of the segments edges are divided into, in metres, when computing areas
+const MAX_SEGMENT_LEN: f64 = 10_000.0;
+/// The length of edges below which they aren't divided, in metres
+const MIN_SEGMENT_LEN: f64 = 1_000.0;
+
+impl Proj {
+    /// The geodesic length of a line string, in metres, on the ellipsoid of this CRS
+    ///
+    /// The coordinates are longitudes and latitudes in degrees, on the ellipsoid of the CRS, or
+    /// of the source CRS of a transformation. Each segment is measured along the shortest
+    /// path on the ellipsoid, using PROJ's geodesic routines.
+    ///
+    /// ```rust
+    /// # use approx::assert_relative_eq;
+    /// use geo_types::LineString;
+    /// use proj::Proj;
+    ///
+    /// let wgs84 = Proj::new("EPSG:4326").unwrap();
+    /// // along the equator, from 0° to 90°E and back to 45°E
+    /// let line = LineString::from(vec![(0.0, 0.0), (90.0, 0.0), (45.0, 0.0)]);
+    /// let length = wgs84.geodesic_length(&line).unwrap();
+    /// assert_relative_eq!(length, 15028131.26, epsilon = 1e-2);
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    #[cfg_attr(docsrs, doc(cfg(feature = "geo-types")))]
+    pub fn geodesic_length<T>(&self, line: &LineString<T>) -> Result<f64, ProjError>
+    where
+        T: CoordinateType,
+    {
+        let geodesic = Geodesic::new(self)?;
+        let points = lon_lat_degrees(line)?;
+        let mut length = 0.0;
+        for segment in points.windows(2) {
+            let (distance, _) = geodesic.inverse(segment[0], segment[1])?;
+            length += distance;
+        }
+        Ok(length)
+    }
+
+    /// The geodesic area of a polygon, in square metres, on the ellipsoid of this CRS
+    ///
+    /// The coordinates are longitudes and latitudes in degrees, on the ellipsoid of the CRS, or
+    /// of the source CRS of a transformation. The edges of the polygon are the shortest paths
+    /// between its vertices on the ellipsoid, and the areas of its interiors are subtracted.
+    /// A ring encircling a pole delimits the smaller of the two areas on either side of it.
+    ///
+    /// The edges are divided into segments of at most 10 km, which are integrated in an
+    /// equal-area projection, so that the area is accurate to about a part in 10<sup>11</sup>.
+    ///
+    /// ```rust
+    /// # use approx::assert_relative_eq;
+    /// use geo_types::Polygon;
+    /// use proj::Proj;
+    ///
+    /// let wgs84 = Proj::new("EPSG:4326").unwrap();
+    /// // an eighth of the ellipsoid
+    /// let octant = Polygon::new(vec![(0.0, 0.0), (90.0, 0.0), (0.0, 90.0)].into(), vec![]);
+    /// let area = wgs84.geodesic_area(&octant).unwrap();
+    /// assert_relative_eq!(area, 510065621724088.5 / 8.0, max_relative = 1e-9);
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    #[cfg_attr(docsrs, doc(cfg(feature = "geo-types")))]
+    pub fn geodesic_area<T>(&self, polygon: &Polygon<T>) -> Result<f64, ProjError>
+    where
+        T: CoordinateType,
+    {
+        let geodesic = Geodesic::new(self)?;
+        let mut area = geodesic.ring_area(polygon.exterior())?;
+        for interior in polygon.interiors() {
+            area -= geodesic.ring_area(interior)?;
+        }
+        Ok(area.max(0.0))
+    }
+}
+
+/// The points of a line string, as longitudes and latitudes in degrees
+fn lon_lat_degrees<T: CoordinateType>(line: &LineString<T>) -> Result<Vec<(f64, f64)>, ProjError> {
+    line.coords()
+        .map(|coord| {
+            let lon = coord.x.to_f64().ok_or(ProjError::FloatConversion)?;
+            let lat = coord.y.to_f64().ok_or(ProjError::FloatConversion)?;
+            Ok((lon, lat))
+        })
+        .collect()
+}
+
+/// Geodesic computations on an ellipsoid, using operations defined on it
+struct Geodesic {
+    /// The semi-major axis, in metres
+    a: f64,
+    /// The semi-minor axis, in metres
+    b: f64,
+    /// Geographic coordinates, for which PROJ initialises its geodesic routines
+    lon_lat: Proj,
+    /// The cylindrical equal-area projection, with a scale of 1 at the equator
+    cea: Proj,
+}
+
+impl Geodesic {
+    /// Geodesic computations on the ellipsoid of a CRS, or of the source CRS of a
+    /// transformation
+    fn new(proj: &Proj) -> Result<Self, ProjError> {
+        let ctx = proj.context();
+        let (a, b) = unsafe {
+            let ellipsoid = if proj_is_crs(proj.as_ptr()) == 1 {
+                proj_get_ellipsoid(ctx.as_ptr(), proj.as_ptr())
+            } else {
+                // PROJ doesn't set an error number if there is no source CRS
+                let source = proj_get_source_crs(ctx.as_ptr(), proj.as_ptr());
+                if source.is_null() {
+                    ptr::null_mut()
+                } else {
+                    let source = source.into_pj_ref(ctx)?;
+                    proj_get_ellipsoid(ctx.as_ptr(), source.as_ptr())
+                }
+            };
+            if ellipsoid.is_null() {
+                return Err(ProjError::UnknownEllipsoid);
+            }
+            let ellipsoid = ellipsoid.into_pj_ref(ctx)?;
+            let (mut a, mut b) = (0.0, 0.0);
+            let found = proj_ellipsoid_get_parameters(
+                ctx.as_ptr(),
+                ellipsoid.as_ptr(),
+                &mut a,
+                &mut b,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+            if found != 1 {
+                return Err(ProjError::UnknownEllipsoid);
+            }
+            (a, b)
+        };
+        let lon_lat = create(proj, "+proj=longlat", a, b)?;
+        let cea = create(proj, "+proj=cea", a, b)?;
+        Ok(Geodesic { a, b, lon_lat, cea })
+    }
+
+    /// The length, in metres, and initial azimuth, in degrees, of the geodesic from `from` to
+    /// `to`
+    fn inverse(&self, from: (f64, f64), to: (f64, f64)) -> Result<(f64, f64), ProjError> {
+        let coord = |(lon, lat): (f64, f64)| PJ_COORD {
+            lpzt: PJ_LPZT {
+                lam: lon.to_radians(),
+                phi: lat.to_radians(),
+                z: 0.0,
+                t: 0.0,
+            },
+        };
+        let (from, to) = (coord(from), coord(to));
+        let (distance, azimuth) = unsafe {
+            let geod = proj_geod(self.lon_lat.as_ptr(), from, to);
+            (proj_lp_dist(self.lon_lat.as_ptr(), from, to), geod.v[1])
+        };
+        if distance.is_finite() {
+            Ok((distance, azimuth))
+        } else {
+            Err(ProjError::Conversion(
+                "The geodesic distance couldn't be computed".to_string(),
+            ))
+        }
+    }
+
+    /// The northing of a point in the cylindrical equal-area projection
+    fn northing(&self, (lon, lat): (f64, f64)) -> Result<f64, ProjError> {
+        let (_, y) = self.cea.convert((lon.to_radians(), lat.to_radians()))?;
+        Ok(y)
+    }
+
+    /// The integral of the northing along the geodesic from `from` to `to` in the cylindrical
+    /// equal-area projection, whose eastings are the longitudes in radians times the
+    /// semi-major axis, and the change of longitude along it in degrees
+    fn edge_integral(&self, from: (f64, f64), to: (f64, f64)) -> Result<(f64, f64), ProjError> {
+        let (mut integral, mut longitude) = (0.0, 0.0);
+        // the longitude of a pole is arbitrary: geodesics through it follow the meridian of
+        // the other end, so the change of longitude happens along the pole
+        let (mut start, mut end) = (from, to);
+        if from.1.abs() == 90.0 {
+            let delta = wrap(to.0 - from.0);
+            integral += delta.to_radians() * self.a * self.northing(from)?;
+            longitude += delta;
+            start = (to.0, from.1);
+        }
+        if to.1.abs() == 90.0 {
+            let delta = wrap(to.0 - from.0);
+            integral += delta.to_radians() * self.a * self.northing(to)?;
+            longitude += delta;
+            end = (from.0, to.1);
+        }
+        let (distance, azimuth) = self.inverse(start, end)?;
+        let mut eastings = vec![0.0];
+        let mut northings = vec![self.northing(start)?];
+        let mut push = |point: (f64, f64), previous: (f64, f64)| -> Result<(), ProjError> {
+            let delta = wrap(point.0 - previous.0);
+            longitude += delta;
+            eastings.push(eastings[eastings.len() - 1] + delta.to_radians() * self.a);
+            northings.push(self.northing(point)?);
+            Ok(())
+        };
+        if distance <= MIN_SEGMENT_LEN {
+            push(end, start)?;
+            return Ok((integral + trapezoid(&eastings, &northings), longitude));
+        }
+        // an even number of segments, integrated using Simpson's rule
+        let count = 2 * (distance / (2.0 * MAX_SEGMENT_LEN)).ceil() as usize;
+        // geodesics from the centre of an azimuthal equidistant projection are straight
+        let operation = format!("+proj=aeqd +lon_0={} +lat_0={}", start.0, start.1);
+        let aeqd = create(&self.lon_lat, &operation, self.a, self.b)?;
+        let (sin, cos) = azimuth.to_radians().sin_cos();
+        let mut previous = start;
+        for i in 1..count {
+            let s = distance * i as f64 / count as f64;
+            let (lon, lat) = aeqd.project((s * sin, s * cos), true)?;
+            let point = (lon.to_degrees(), lat.to_degrees());
+            push(point, previous)?;
+            previous = point;
+        }
+        push(end, previous)?;
+        for i in (0..count).step_by(2) {
+            let (x, y) = (&eastings[i..i + 3], &northings[i..i + 3]);
+            // the integral of y dx along the parabola through the three points
+            let (dx_start, dx_mid, dx_end) = (
+                4.0 * (x[1] - x[0]) - (x[2] - x[0]),
+                x[2] - x[0],
+                4.0 * (x[2] - x[1]) - (x[2] - x[0]),
+            );
+            integral += (y[0] * dx_start + 4.0 * y[1] * dx_mid + y[2] * dx_end) / 6.0;
+        }
+        Ok((integral, longitude))
+    }
+
+    /// The area enclosed by a ring, in square metres
+    fn ring_area<T: CoordinateType>(&self, ring: &LineString<T>) -> Result<f64, ProjError> {
+        let mut vertices = lon_lat_degrees(ring)?;
+        let Some(&first) = vertices.first() else {
+            return Ok(0.0);
+        };
+        if vertices.last() != Some(&first) {
+            vertices.push(first);
+        }
+        let (mut integral, mut longitude) = (0.0, 0.0);
+        for edge in vertices.windows(2) {
+            let (edge_integral, edge_longitude) = self.edge_integral(edge[0], edge[1])?;
+            integral += edge_integral;
+            longitude += edge_longitude;
+        }
+        if longitude.abs() < 180.0 {
+            return Ok(integral.abs());
+        }
+        // the ring encircles a pole: the areas north and south of it add up to the whole
+        // ellipsoid
+        let integral = integral * longitude.signum();
+        let band = 2.0 * PI * self.a * self.northing((0.0, 90.0))?;
+        Ok((band - integral).min(band + integral))
+    }
+}
+
+/// Wrap a difference of longitudes to [-180, 180)
+fn wrap(delta: f64) -> f64 {
+    (delta + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// The integral of y dx along the straight segments between points
+fn trapezoid(x: &[f64], y: &[f64]) -> f64 {
+    x.windows(2)
+        .zip(y.windows(2))
+        .map(|(x, y)| (x[1] - x[0]) * (y[0] + y[1]) / 2.0)
+        .sum()
+}
+
+/// Create `operation` on the ellipsoid with semi-axes `a` and `b`, in the context of `proj`
+fn create(proj: &Proj, operation: &str, a: f64, b: f64) -> Result<Proj, ProjError> {
+    let definition = CString::new(format!("{} +a={} +b={}", operation, a, b))?;
+    Ok(proj.derive(|ctx| unsafe { proj_create(ctx, definition.as_ptr()) })?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_geodesic_area() {
+        let wgs84 = Proj::new("EPSG:4326").unwrap();
+        let surface = 510065621724088.5;
+        // a ring encircling the north pole
+        let arctic = Polygon::new(
+            vec![(0.0, 60.0), (120.0, 60.0), (-120.0, 60.0)].into(),
+            vec![],
+        );
+        let area = wgs84.geodesic_area(&arctic).unwrap();
+        // as computed by geod_polygonarea
+        assert_relative_eq!(area, 15797025696584.125, max_relative = 1e-11);
+        assert!(area < surface / 2.0);
+        // the same ring, in the opposite direction and crossing the antimeridian
+        let reversed = Polygon::new(
+            vec![(-120.0, 60.0), (120.0, 60.0), (0.0, 60.0)].into(),
+            vec![],
+        );
+        assert_relative_eq!(
+            wgs84.geodesic_area(&reversed).unwrap(),
+            area,
+            max_relative = 1e-9
+        );
+        // a hole removes its area
+        let square = |size: f64| -> LineString<f64> {
+            vec![(0.0, 0.0), (size, 0.0), (size, size), (0.0, size)].into()
+        };
+        assert_relative_eq!(
+            wgs84
+                .geodesic_area(&Polygon::new(square(2.0), vec![]))
+                .unwrap(),
+            49231584297.43102,
+            max_relative = 1e-11
+        );
+        let with_hole = Polygon::new(square(2.0), vec![square(1.0)]);
+        let expected = wgs84
+            .geodesic_area(&Polygon::new(square(2.0), vec![]))
+            .unwrap()
+            - wgs84
+                .geodesic_area(&Polygon::new(square(1.0), vec![]))
+                .unwrap();
+        assert_relative_eq!(
+            wgs84.geodesic_area(&with_hole).unwrap(),
+            expected,
+            max_relative = 1e-9
+        );
+        // the ellipsoid of the source CRS of a transformation is used
+        let to_utm = Proj::new_known_crs("EPSG:4326", "EPSG:32631", None).unwrap();
+        assert_relative_eq!(
+            to_utm.geodesic_area(&with_hole).unwrap(),
+            expected,
+            max_relative = 1e-9
+        );
+        // PROJ strings have no CRS
+        let merc = Proj::new("+proj=merc").unwrap();
+        assert!(matches!(
+            merc.geodesic_area(&with_hole),
+            Err(ProjError::UnknownEllipsoid)
+        ));
+        assert!(matches!(
+            merc.geodesic_length(with_hole.exterior()),
+            Err(ProjError::UnknownEllipsoid)
+        ));
+    }
+}
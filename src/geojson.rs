@@ -0,0 +1,410 @@
+//! Transformation of GeoJSON objects
+//!
+//! [`Transform`] is implemented for the geometries, features and feature collections of the
+//! [`geojson` crate](https://docs.rs/geojson), walking their coordinates directly: the
+//! properties, identifiers and foreign members of the objects are kept as they are, and their
+//! bounding boxes, if any, are recomputed. Positions with a third value transform it as a height.
+//!
+//! GeoJSON ([RFC 7946](https://www.rfc-editor.org/rfc/rfc7946)) only allows WGS 84 longitudes
+//! and latitudes, but files in other CRSs are common: [`CrsMember`] reads and writes the `crs`
+//! member which the 2008 GeoJSON specification used to name it.
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use std::convert::TryFrom;
+//!
+//! use geojson::{FeatureCollection, GeoJson, Value};
+//! use proj::geojson::CrsMember;
+//! use proj::Transform;
+//!
+//! let mut geojson: GeoJson = r#"{
+//!     "type": "FeatureCollection",
+//!     "features": [{
+//!         "type": "Feature",
+//!         "properties": {"name": "Paris"},
+//!         "geometry": {"type": "Point", "coordinates": [2.3522, 48.8566]}
+//!     }],
+//!     "generator": "a foreign member"
+//! }"#
+//! .parse()
+//! .unwrap();
+//! geojson.transform_crs_to_crs("EPSG:4326", "EPSG:3857").unwrap();
+//! geojson.set_crs("urn:ogc:def:crs:EPSG::3857");
+//!
+//! let collection = FeatureCollection::try_from(geojson).unwrap();
+//! assert_eq!(collection.crs(), Some("urn:ogc:def:crs:EPSG::3857"));
+//! let geometry = collection.features[0].geometry.as_ref().unwrap();
+//! let Value::Point(point) = &geometry.value else {
+//!     unreachable!()
+//! };
+//! assert_relative_eq!(point[0], 261845.70624393807, epsilon = 1e-6);
+//! let members = collection.foreign_members.unwrap();
+//! assert_eq!(members["generator"], "a foreign member");
+//! ```
+use geojson::{Bbox, Feature, FeatureCollection, GeoJson, Geometry, JsonObject, Position, Value};
+use serde_json::json;
+
+use crate::{CoordTransform, ProjError, Transform};
+
+/// GeoJSON objects which can name their CRS in a `crs` member
+///
+/// The member is only read and written: transforming an object doesn't change it.
+pub trait CrsMember {
+    /// The foreign members of the object, which include the `crs` member
+    fn foreign_members(&self) -> Option<&JsonObject>;
+
+    /// The foreign members of the object, created if it has none
+    fn foreign_members_mut(&mut self) -> &mut JsonObject;
+
+    /// The name of the CRS of the object, such as `"urn:ogc:def:crs:EPSG::3857"`, if it has a
+    /// `crs` member naming one
+    fn crs(&self) -> Option<&str> {
+        self.foreign_members()?
+            .get("crs")?
+            .pointer("/properties/name")?
+            .as_str()
+    }
+
+    /// Name the CRS of the object in a `crs` member, replacing any previous one
+    fn set_crs(&mut self, name: &str) {
+        self.foreign_members_mut().insert(
+            "crs".to_string(),
+            json!({"type": "name", "properties": {"name": name}}),
+        );
+    }
+
+    /// Remove the `crs` member of the object, as RFC 7946 requires for WGS 84 coordinates
+    fn remove_crs(&mut self) {
+        self.foreign_members_mut().remove("crs");
+    }
+}
+
+impl CrsMember for Geometry {
+    fn foreign_members(&self) -> Option<&JsonObject> {
+        self.foreign_members.as_ref()
+    }
+
+    fn foreign_members_mut(&mut self) -> &mut JsonObject {
+        self.foreign_members.get_or_insert_with(JsonObject::new)
+    }
+}
+
+impl CrsMember for Feature {
+    fn foreign_members(&self) -> Option<&JsonObject> {
+        self.foreign_members.as_ref()
+    }
+
+    fn foreign_members_mut(&mut self) -> &mut JsonObject {
+        self.foreign_members.get_or_insert_with(JsonObject::new)
+    }
+}
+
+impl CrsMember for FeatureCollection {
+    fn foreign_members(&self) -> Option<&JsonObject> {
+        self.foreign_members.as_ref()
+    }
+
+    fn foreign_members_mut(&mut self) -> &mut JsonObject {
+        self.foreign_members.get_or_insert_with(JsonObject::new)
+    }
+}
+
+impl CrsMember for GeoJson {
+    fn foreign_members(&self) -> Option<&JsonObject> {
+        match self {
+            GeoJson::Geometry(g) => g.foreign_members(),
+            GeoJson::Feature(g) => g.foreign_members(),
+            GeoJson::FeatureCollection(g) => g.foreign_members(),
+        }
+    }
+
+    fn foreign_members_mut(&mut self) -> &mut JsonObject {
+        match self {
+            GeoJson::Geometry(g) => g.foreign_members_mut(),
+            GeoJson::Feature(g) => g.foreign_members_mut(),
+            GeoJson::FeatureCollection(g) => g.foreign_members_mut(),
+        }
+    }
+}
+
+impl Transform<f64> for Geometry {
+    type Output = Self;
+
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
+        let mut output = self.clone();
+        output.transform(proj)?;
+        Ok(output)
+    }
+
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        match &mut self.value {
+            Value::Point(position) => transform_positions(std::slice::from_mut(position), proj)?,
+            Value::MultiPoint(positions) | Value::LineString(positions) => {
+                transform_positions(positions, proj)?
+            }
+            // the parts are transformed into a copy, so that the geometry is left unchanged if
+            // one of them fails
+            value => *value = transformed_parts(value, proj)?,
+        }
+        update_bbox(&mut self.bbox, positions(&self.value));
+        Ok(())
+    }
+}
+
+impl Transform<f64> for Feature {
+    type Output = Self;
+
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
+        let mut output = self.clone();
+        output.transform(proj)?;
+        Ok(output)
+    }
+
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        if let Some(geometry) = &mut self.geometry {
+            geometry.transform(proj)?;
+        }
+        update_bbox(&mut self.bbox, geometry_positions(&self.geometry));
+        Ok(())
+    }
+}
+
+impl Transform<f64> for FeatureCollection {
+    type Output = Self;
+
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
+        let mut output = self.clone();
+        output.transform(proj)?;
+        Ok(output)
+    }
+
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        // the features are only modified once all their geometries have been transformed
+        let geometries = self
+            .features
+            .iter()
+            .map(|feature| {
+                feature
+                    .geometry
+                    .as_ref()
+                    .map(|geometry| geometry.transformed(proj))
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (feature, geometry) in self.features.iter_mut().zip(geometries) {
+            feature.geometry = geometry;
+            update_bbox(&mut feature.bbox, geometry_positions(&feature.geometry));
+        }
+        update_bbox(
+            &mut self.bbox,
+            self.features
+                .iter()
+                .flat_map(|feature| geometry_positions(&feature.geometry)),
+        );
+        Ok(())
+    }
+}
+
+impl Transform<f64> for GeoJson {
+    type Output = Self;
+
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self, ProjError> {
+        let mut output = self.clone();
+        output.transform(proj)?;
+        Ok(output)
+    }
+
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        match self {
+            GeoJson::Geometry(g) => g.transform(proj),
+            GeoJson::Feature(g) => g.transform(proj),
+            GeoJson::FeatureCollection(g) => g.transform(proj),
+        }
+    }
+}
+
+/// A copy of a geometry made of several parts, with every part transformed
+fn transformed_parts<P: CoordTransform>(value: &Value, proj: &P) -> Result<Value, ProjError> {
+    let mut value = value.clone();
+    match &mut value {
+        Value::Point(position) => transform_positions(std::slice::from_mut(position), proj)?,
+        Value::MultiPoint(positions) | Value::LineString(positions) => {
+            transform_positions(positions, proj)?
+        }
+        Value::MultiLineString(lines) | Value::Polygon(lines) => {
+            for positions in lines {
+                transform_positions(positions, proj)?;
+            }
+        }
+        Value::MultiPolygon(polygons) => {
+            for positions in polygons.iter_mut().flatten() {
+                transform_positions(positions, proj)?;
+            }
+        }
+        Value::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                geometry.transform(proj)?;
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// Convert positions in place, with their height if they have one
+fn transform_positions<P: CoordTransform>(
+    positions: &mut [Position],
+    proj: &P,
+) -> Result<(), ProjError> {
+    // positions with fewer than two values are invalid, and left as they are
+    let mut points: Vec<_> = positions
+        .iter()
+        .filter(|position| position.len() >= 2)
+        .map(|position| {
+            (
+                position[0],
+                position[1],
+                position.get(2).copied().unwrap_or(0.0),
+            )
+        })
+        .collect();
+    proj.convert_array(&mut points)?;
+    let valid = positions.iter_mut().filter(|position| position.len() >= 2);
+    for (position, (x, y, z)) in valid.zip(points) {
+        position[0] = x;
+        position[1] = y;
+        if let Some(height) = position.get_mut(2) {
+            *height = z;
+        }
+    }
+    Ok(())
+}
+
+/// The positions of a geometry, including those of the members of a collection
+fn positions(value: &Value) -> Box<dyn Iterator<Item = &Position> + '_> {
+    match value {
+        Value::Point(position) => Box::new(std::iter::once(position)),
+        Value::MultiPoint(positions) | Value::LineString(positions) => Box::new(positions.iter()),
+        Value::MultiLineString(lines) | Value::Polygon(lines) => Box::new(lines.iter().flatten()),
+        Value::MultiPolygon(polygons) => Box::new(polygons.iter().flatten().flatten()),
+        Value::GeometryCollection(geometries) => Box::new(
+            geometries
+                .iter()
+                .flat_map(|geometry| positions(&geometry.value)),
+        ),
+    }
+}
+
+/// The positions of the geometry of a feature, if it has one
+fn geometry_positions(geometry: &Option<Geometry>) -> impl Iterator<Item = &Position> {
+    geometry
+        .iter()
+        .flat_map(|geometry| positions(&geometry.value))
+}
+
+/// Recompute a bounding box from the transformed positions, keeping its number of dimensions
+fn update_bbox<'a, I>(bbox: &mut Option<Bbox>, positions: I)
+where
+    I: Iterator<Item = &'a Position>,
+{
+    let Some(bbox) = bbox else {
+        return;
+    };
+    let dims = bbox.len() / 2;
+    let mut extent = vec![f64::INFINITY; dims];
+    extent.extend(vec![f64::NEG_INFINITY; dims]);
+    for position in positions {
+        for (d, &value) in position.iter().enumerate().take(dims) {
+            extent[d] = extent[d].min(value);
+            extent[dims + d] = extent[dims + d].max(value);
+        }
+    }
+    // an object without positions keeps its bounding box
+    if extent.iter().all(|value| value.is_finite()) {
+        *bbox = extent;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Proj;
+
+    #[test]
+    fn test_transform_geojson() {
+        let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let (x, y) = to_merc.convert((2.0, 48.0)).unwrap();
+        let mut feature: Feature = r#"{
+            "type": "Feature",
+            "id": 7,
+            "bbox": [0.0, 0.0, 2.0, 48.0],
+            "properties": {"name": "a"},
+            "geometry": {
+                "type": "GeometryCollection",
+                "geometries": [
+                    {"type": "LineString", "coordinates": [[0.0, 0.0], [2.0, 48.0, 10.0]]},
+                    {"type": "MultiPolygon", "coordinates": [[[[0, 0], [2, 0], [2, 48], [0, 0]]]]}
+                ]
+            },
+            "crs": {"type": "name", "properties": {"name": "EPSG:4326"}}
+        }"#
+        .parse()
+        .unwrap();
+        let original = feature.clone();
+        assert_eq!(feature.crs(), Some("EPSG:4326"));
+        feature.transform(&to_merc).unwrap();
+        assert_eq!(feature.bbox, Some(vec![0.0, 0.0, x, y]));
+        assert_eq!(feature.properties, original.properties);
+        assert_eq!(feature.id, original.id);
+        // transforming doesn't change the crs member
+        assert_eq!(feature.crs(), Some("EPSG:4326"));
+        feature.set_crs("EPSG:3857");
+        assert_eq!(feature.crs(), Some("EPSG:3857"));
+        let Value::GeometryCollection(geometries) = &feature.geometry.as_ref().unwrap().value
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            geometries[0].value,
+            Value::LineString(vec![vec![0.0, 0.0], vec![x, y, 10.0]])
+        );
+        let Value::MultiPolygon(polygons) = &geometries[1].value else {
+            unreachable!()
+        };
+        assert_eq!(polygons[0][0][2], vec![x, y]);
+
+        // back to WGS 84
+        let mut geojson = GeoJson::Feature(feature);
+        geojson.transform(&to_merc.reversed().unwrap()).unwrap();
+        geojson.remove_crs();
+        assert_eq!(geojson.crs(), None);
+        let GeoJson::Feature(feature) = geojson else {
+            unreachable!()
+        };
+        assert_relative_eq!(feature.bbox.unwrap()[3], 48.0, epsilon = 1e-9);
+
+        // a failure
+        let mut point = Geometry::new(Value::Point(vec![0.0, 91.0]));
+        assert!(point.transform(&to_merc).is_err());
+        // leaves the objects unchanged, even if their other parts could be transformed
+        let mut collection: FeatureCollection = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": null,
+                 "geometry": {"type": "Point", "coordinates": [2.0, 48.0]}},
+                {"type": "Feature", "properties": null,
+                 "geometry": {"type": "Polygon", "coordinates": [
+                    [[0, 0], [2, 0], [2, 48], [0, 0]],
+                    [[1, 1], [1, 91], [1.5, 2], [1, 1]]
+                 ]}}
+            ]
+        }"#
+        .parse()
+        .unwrap();
+        let original = collection.clone();
+        assert!(collection.transform(&to_merc).is_err());
+        assert_eq!(collection, original);
+        let mut polygon = original.features[1].geometry.clone().unwrap();
+        assert!(polygon.transform(&to_merc).is_err());
+        assert_eq!(Some(polygon), original.features[1].geometry);
+    }
+}
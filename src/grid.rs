@@ -0,0 +1,438 @@
+//! Grid files used by transformations, and their provisioning for use without network access
+use libc::c_char;
+use proj_sys::{
+    proj_coordoperation_get_grid_used, proj_coordoperation_get_grid_used_count, proj_create,
+    proj_create_operation_factory_context, proj_create_operations, proj_grid_info,
+    proj_list_destroy, proj_list_get, proj_list_get_count, proj_operation_factory_context_destroy,
+    proj_operation_factory_context_set_area_of_interest,
+    proj_operation_factory_context_set_grid_availability_use, PJ,
+    PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_IGNORED,
+};
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::Mutex;
+
+use crate::proj::_string;
+use crate::{Area, Context, PjPtrExt, ProjError};
+
+/// The name of PROJ's cache database of downloaded grid chunks
+pub(crate) const CACHE_DB: &str = "cache.db";
+
+/// The extensions of the grid formats PROJ reads
+const GRID_EXTENSIONS: [&str; 4] = ["tif", "tiff", "gsb", "gtx"];
+
+/// A directory of grid files registered with a [`ProjBuilder`](crate::ProjBuilder)
+///
+/// Obtained using [`ProjBuilder::add_grid_bundle`](crate::ProjBuilder::add_grid_bundle).
+#[derive(Clone, Debug)]
+pub struct GridBundle {
+    dir: PathBuf,
+    grids: Vec<String>,
+}
+
+impl GridBundle {
+    /// Scan a directory for grid files
+    pub(crate) fn scan(dir: &Path) -> Result<Self, ProjError> {
+        let mut grids = vec![];
+        for entry in fs::read_dir(dir).map_err(ProjError::GridFiles)? {
+            let path = entry.map_err(ProjError::GridFiles)?.path();
+            let is_grid = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| GRID_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+            if let (true, Some(name)) = (is_grid, path.file_name().and_then(|n| n.to_str())) {
+                grids.push(name.to_string());
+            }
+        }
+        grids.sort();
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            grids,
+        })
+    }
+
+    /// The directory holding the grids
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The file names of the grids found in the directory, sorted
+    pub fn grids(&self) -> &[String] {
+        &self.grids
+    }
+
+    /// Whether the bundle contains a grid, such as `us_noaa_conus.tif`
+    pub fn contains(&self, grid: &str) -> bool {
+        self.grids.iter().any(|g| g == grid.trim_start_matches('@'))
+    }
+}
+
+/// The grids needed by a transformation, and whether they could be made available
+///
+/// Obtained using [`Proj::ensure_grids_available`](crate::Proj::ensure_grids_available).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GridReport {
+    /// Grids which were found locally
+    pub present: Vec<String>,
+    /// Grids which were downloaded to PROJ's user-writable directory
+    pub downloaded: Vec<String>,
+    /// Grids which weren't found, and weren't downloaded, because downloading wasn't requested,
+    /// network access is disabled, or they aren't distributed on the CDN
+    pub missing: Vec<String>,
+    /// Grids whose download failed
+    pub failed: Vec<String>,
+}
+
+impl GridReport {
+    /// Whether all the grids are available locally
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// The kind of shift applied by a grid, used by [`Proj::gridshift`](crate::Proj::gridshift)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GridShift {
+    /// Shift longitudes and latitudes, such as with an NTv2 grid, using
+    /// [`hgridshift`](https://proj.org/operations/transformations/hgridshift.html)
+    Horizontal,
+    /// Shift heights, such as with a geoid model, using
+    /// [`vgridshift`](https://proj.org/operations/transformations/vgridshift.html)
+    Vertical,
+}
+
+impl GridShift {
+    /// The name of the PROJ operation applying the shift
+    pub(crate) fn operation(self) -> &'static str {
+        match self {
+            GridShift::Horizontal => "hgridshift",
+            GridShift::Vertical => "vgridshift",
+        }
+    }
+}
+
+/// The description of a grid file, as returned by [`grid_info`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridInfo {
+    /// The name or path the grid was opened with
+    pub name: String,
+    /// The location of the file
+    pub path: Option<PathBuf>,
+    /// The format of the file, such as `"gtiff"`, `"gtx"` or `"ntv2"`
+    pub format: String,
+    /// The extent covered by the grid, in degrees
+    pub extent: Area,
+    /// The number of columns
+    pub width: u32,
+    /// The number of rows
+    pub height: u32,
+    /// The size of the cells in degrees, of longitude and latitude
+    pub resolution: (f64, f64),
+}
+
+/// `proj_grid_info` uses PROJ's default context, which mustn't be used concurrently
+static GRID_INFO_LOCK: Mutex<()> = Mutex::new(());
+
+/// Open a grid file using PROJ's grid readers, and describe it
+///
+/// `grid` is a path, or the name of a grid found in PROJ's default search paths (such as the
+/// `PROJ_DATA` directory). This allows grids to be validated once downloaded: a grid which
+/// PROJ can't read returns an error. Only the first grid of a file holding several subgrids,
+/// as NTv2 files can, is described.
+///
+/// PROJ's API doesn't expose the CRS or the units of a grid's values: the extent is always
+/// given in geographic coordinates.
+///
+/// ```rust
+/// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/test_nodata.gtx");
+/// let info = proj::grid_info(path).unwrap();
+/// assert_eq!(info.format, "gtx");
+/// assert!(info.extent.west < info.extent.east);
+/// ```
+///
+/// # Safety
+/// This method contains unsafe code.
+pub fn grid_info<P: AsRef<Path>>(grid: P) -> Result<GridInfo, ProjError> {
+    #[cfg(feature = "runtime-loading")]
+    proj_sys::load_libproj()?;
+    let name = grid.as_ref().to_str().ok_or(ProjError::Path)?;
+    let name_c = CString::new(name)?;
+    let info = {
+        let _lock = GRID_INFO_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { proj_grid_info(name_c.as_ptr()) }
+    };
+    let string = |chars: &[c_char]| unsafe { _string(chars.as_ptr()) };
+    let format = string(&info.format)?;
+    if format == "missing" {
+        return Err(ProjError::GridFiles(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} could not be opened as a grid", name),
+        )));
+    }
+    let path = string(&info.filename)?;
+    Ok(GridInfo {
+        name: name.to_string(),
+        path: Some(PathBuf::from(path)).filter(|path| !path.as_os_str().is_empty()),
+        format,
+        extent: Area::new(
+            info.lowerleft.lam.to_degrees(),
+            info.lowerleft.phi.to_degrees(),
+            info.upperright.lam.to_degrees(),
+            info.upperright.phi.to_degrees(),
+        ),
+        width: u32::try_from(info.n_lon).unwrap_or_default(),
+        height: u32::try_from(info.n_lat).unwrap_or_default(),
+        resolution: (info.cs_lon.to_degrees(), info.cs_lat.to_degrees()),
+    })
+}
+
+/// A grid used by a coordinate operation
+#[derive(Debug, PartialEq)]
+pub(crate) struct GridUsage {
+    pub(crate) name: String,
+    /// The location of the grid, if it was found locally
+    pub(crate) path: Option<PathBuf>,
+    /// The URL of the grid on the CDN, if it is distributed there
+    pub(crate) url: Option<String>,
+    /// Whether the grid can be downloaded directly from `url`
+    pub(crate) direct_download: bool,
+    /// Whether PROJ can find the grid, locally or through the network if it is enabled
+    pub(crate) available: bool,
+}
+
+/// List the grids used by any candidate operation between two CRSs
+pub(crate) fn grids_used(
+    ctx: &Context,
+    source: &str,
+    target: &str,
+    area: Option<Area>,
+) -> Result<Vec<GridUsage>, ProjError> {
+    let source = CString::new(source)?;
+    let target = CString::new(target)?;
+    unsafe {
+        let source = proj_create(ctx.as_ptr(), source.as_ptr()).into_pj_ref(ctx)?;
+        let target = proj_create(ctx.as_ptr(), target.as_ptr()).into_pj_ref(ctx)?;
+        Ok(candidate_grids(ctx, source.as_ptr(), target.as_ptr(), area))
+    }
+}
+
+/// List the grids used by any candidate operation between two CRS objects
+pub(crate) fn candidate_grids(
+    ctx: &Context,
+    source: *const PJ,
+    target: *const PJ,
+    area: Option<Area>,
+) -> Vec<GridUsage> {
+    let mut grids = vec![];
+    unsafe {
+        let factory = proj_create_operation_factory_context(ctx.as_ptr(), ptr::null());
+        // list operations whether or not their grids are available
+        proj_operation_factory_context_set_grid_availability_use(
+            ctx.as_ptr(),
+            factory,
+            PROJ_GRID_AVAILABILITY_USE_PROJ_GRID_AVAILABILITY_IGNORED,
+        );
+        if let Some(area) = area {
+            proj_operation_factory_context_set_area_of_interest(
+                ctx.as_ptr(),
+                factory,
+                area.west,
+                area.south,
+                area.east,
+                area.north,
+            );
+        }
+        let list = proj_create_operations(ctx.as_ptr(), source, target, factory);
+        proj_operation_factory_context_destroy(factory);
+        if list.is_null() {
+            return grids;
+        }
+        for i in 0..proj_list_get_count(list) {
+            if let Ok(op) = proj_list_get(ctx.as_ptr(), list, i).into_pj_ref(ctx) {
+                add_operation_grids(ctx, op.as_ptr(), &mut grids);
+            }
+        }
+        proj_list_destroy(list);
+    }
+    grids
+}
+
+/// Add the grids used by a coordinate operation to `grids`, unless they are already listed
+pub(crate) fn add_operation_grids(ctx: &Context, op: *const PJ, grids: &mut Vec<GridUsage>) {
+    unsafe {
+        for i in 0..proj_coordoperation_get_grid_used_count(ctx.as_ptr(), op) {
+            let mut short_name = ptr::null();
+            let mut full_name = ptr::null();
+            let mut url = ptr::null();
+            let mut direct_download = 0;
+            let mut available = 0;
+            let found = proj_coordoperation_get_grid_used(
+                ctx.as_ptr(),
+                op,
+                i,
+                &mut short_name,
+                &mut full_name,
+                ptr::null_mut(),
+                &mut url,
+                &mut direct_download,
+                ptr::null_mut(),
+                &mut available,
+            );
+            if found != 1 || short_name.is_null() {
+                continue;
+            }
+            let Ok(name) = _string(short_name) else {
+                continue;
+            };
+            let optional = |s: *const c_char| {
+                if s.is_null() {
+                    None
+                } else {
+                    _string(s).ok().filter(|s| !s.is_empty())
+                }
+            };
+            // the full name of a grid found through the network is its URL
+            let path = optional(full_name)
+                .filter(|name| !name.starts_with("http://") && !name.starts_with("https://"))
+                .map(PathBuf::from);
+            let grid = GridUsage {
+                name: name.trim_start_matches('@').to_string(),
+                path,
+                url: optional(url),
+                direct_download: direct_download == 1,
+                available: available == 1,
+            };
+            if !grids.contains(&grid) {
+                grids.push(grid);
+            }
+        }
+    }
+}
+
+/// Extract a zip archive, or a tar archive, optionally gzip-compressed, into a directory
+#[cfg(feature = "grid-archives")]
+pub(crate) fn extract_archive(archive: &Path, dir: &Path) -> Result<(), ProjError> {
+    let file = fs::File::open(archive).map_err(ProjError::GridFiles)?;
+    let name = archive.to_string_lossy();
+    if name.ends_with(".zip") {
+        zip::ZipArchive::new(file)
+            .and_then(|mut zip| zip.extract(dir))
+            .map_err(io::Error::from)
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        tar::Archive::new(flate2::read::GzDecoder::new(file)).unpack(dir)
+    } else {
+        tar::Archive::new(file).unpack(dir)
+    }
+    .map_err(ProjError::GridFiles)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ProjBuilder;
+
+    fn grid_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("proj-rs-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_grid_info() {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/test_hgrid_little_endian.gsb");
+        let info = grid_info(&path).unwrap();
+        assert_eq!(info.format, "ntv2");
+        assert_eq!(info.path.as_deref(), Some(path.as_path()));
+        assert!(info.width > 0 && info.height > 0);
+        let extent = info.extent;
+        assert_relative_eq!(
+            extent.east - extent.west,
+            f64::from(info.width - 1) * info.resolution.0,
+            epsilon = 1e-9
+        );
+        assert!(grid_info(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")).is_err());
+        assert!(grid_info("no_such_grid.tif").is_err());
+    }
+
+    #[test]
+    fn test_grids_used() {
+        let ctx = Context::new();
+        let conus = Area::new(-124.0, 25.0, -67.0, 49.0);
+        let grids = grids_used(&ctx, "EPSG:4267", "EPSG:4269", Some(conus)).unwrap();
+        let conus = grids
+            .iter()
+            .find(|grid| grid.name == "us_noaa_conus.tif")
+            .unwrap();
+        assert!(conus.url.as_ref().unwrap().starts_with("https://"));
+        let grids = grids_used(&ctx, "EPSG:4326", "EPSG:3857", None).unwrap();
+        assert!(grids.is_empty());
+    }
+
+    #[test]
+    fn test_grid_bundle() {
+        let dir = grid_dir("grid-bundle");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("us_noaa_conus.tif"), b"").unwrap();
+        fs::write(dir.join("README.txt"), b"").unwrap();
+        let mut builder = ProjBuilder::new();
+        let bundle = builder.add_grid_bundle(&dir).unwrap();
+        assert_eq!(bundle.grids(), ["us_noaa_conus.tif"]);
+        assert!(bundle.contains("@us_noaa_conus.tif"));
+        assert!(!bundle.contains("README.txt"));
+        let conus = Area::new(-124.0, 25.0, -67.0, 49.0);
+        let missing = builder
+            .missing_grids(Some(conus), &[("EPSG:4267", "EPSG:4269")])
+            .unwrap();
+        assert!(!missing.is_empty());
+        assert!(!missing.contains(&"us_noaa_conus.tif".to_string()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "grid-archives")]
+    #[test]
+    fn test_grid_archive() {
+        let dir = grid_dir("grid-archive");
+        let grids = dir.join("grids");
+        fs::create_dir_all(&grids).unwrap();
+        let archive = dir.join("grids.tar");
+        let mut builder = tar::Builder::new(fs::File::create(&archive).unwrap());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(0);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "nz_linz_nzgd2kgrid0005.tif", &[][..])
+            .unwrap();
+        builder.finish().unwrap();
+        drop(builder);
+        let bundle = ProjBuilder::new()
+            .add_grid_archive(&archive, &grids)
+            .unwrap();
+        assert_eq!(bundle.grids(), ["nz_linz_nzgd2kgrid0005.tif"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "grid-archives")]
+    #[test]
+    fn test_grid_archive_zip() {
+        let dir = grid_dir("grid-archive-zip");
+        let grids = dir.join("grids");
+        fs::create_dir_all(&grids).unwrap();
+        let archive = dir.join("grids.zip");
+        let mut writer = zip::ZipWriter::new(fs::File::create(&archive).unwrap());
+        writer
+            .start_file(
+                "nz_linz_nzgd2kgrid0005.tif",
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.finish().unwrap();
+        let bundle = ProjBuilder::new()
+            .add_grid_archive(&archive, &grids)
+            .unwrap();
+        assert_eq!(bundle.grids(), ["nz_linz_nzgd2kgrid0005.tif"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
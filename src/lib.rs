@@ -21,6 +21,9 @@
 //! feature](#feature-flags), enabled by default, which implements this trait for types in
 //! the [`geo-types` crate](https://docs.rs/geo-types).
 //!
+//! Geographic coordinates in degrees can be wrapped in a [`LonLat`], which rejects invalid
+//! coordinates such as swapped axes.
+//!
 //! Methods for [conversion](struct.Proj.html#method.convert_array) and
 //! [projection](struct.Proj.html#method.project_array) of slices of `Coord`s are also available.
 //!
@@ -74,6 +77,8 @@
 //! assert_relative_eq!(result.1, 1141263.01, epsilon=1e-2);
 //! ```
 //!
+//! Pipelines can also be composed programmatically using a [`PipelineBuilder`].
+//!
 //! # Usage
 //!
 //! There are two options for creating a transformation:
@@ -96,27 +101,103 @@
 //! 2. `Proj::new()` and `ProjBuilder::proj()` have the same signature;
 //! 3. `Proj::new_known_crs()` and `ProjBuilder::proj_known_crs()` have the same signature.
 //!
+//! # Modules
+//!
+//! The API is grouped into modules, whose items are also re-exported at the crate root:
+//!
+//! - [`op`]: transformations between CRSs, projections and conversions;
+//! - [`crs`]: construction and export of coordinate reference systems;
+//! - [`grid`]: the grid files used by transformations;
+//! - [`pipeline`]: programmatic construction of pipelines;
+//! - [`pool`]: sharing a transformation between threads;
+//! - `net`: grid downloads, when the `network` feature is enabled;
+//! - `projjson`: typed PROJJSON CRSs, when the `projjson` feature is enabled;
+//! - [`db`]: the metadata of the PROJ database.
+//!
+//! [`prelude`] re-exports the types and traits needed by most uses of the crate, and [`corpus`]
+//! contains transformations with known results, for validating integrations.
+//!
+//! # Performance
+//!
+//! Once a `Proj` has been created, converting and projecting points with `convert`,
+//! `convert_inverse`, `project` and their `_array` variants doesn't allocate when it succeeds:
+//! error messages are only built on failure, and the `_array` variants use a buffer on the stack
+//! unless their chunk length is raised (see [`Proj::set_array_chunk_len`]). PROJ itself may still
+//! allocate, for instance when an operation is first used, or opens or downloads a grid.
+//! Latency-sensitive users can warm a `Proj` up by converting a representative point after
+//! creating it.
+//!
+//! Simple transformations, such as between `EPSG:4326` and `EPSG:3857`, can avoid calling
+//! into PROJ for each point altogether: see [`Proj::enable_fast_path`].
+//!
 //! # Requirements
 //!
 //! By default, the crate requires `libproj` 9.2.x to be present on your system and will use `pkg-config`
 //! to attempt to locate it. If this fails, the crate will attempt to build libproj from its bundled source.
 //!
+//! Any release from 9.2 to 9.4 can be used: methods wrapping functions added in later releases,
+//! such as [`Proj::has_point_motion_operation`] (9.4), are only available if the libproj found is
+//! recent enough.
+//!
+//! The version of the libproj found at runtime is checked when the first transformation is
+//! created, which fails with [`ProjCreateError::UnsupportedVersion`] if it is incompatible: see
+//! [`supported_proj_versions`].
+//!
 //! # Feature Flags
 //!
 //! - `geo-types`: include [trait impls for
-//!   `geo-types`](proj/trait.Coord.html#impl-Coord%3CT%3E-for-Coordinate%3CT%3E). See
+//!   `geo-types`](proj/trait.Coord.html#impl-Coord%3CT%3E-for-Coordinate%3CT%3E), and the
+//!   geodesic lengths and areas of line strings and polygons. See
 //!   [example](#integration-with-geo-types).
 //! - `pkg_config`: enables the use of `pkg-config` when linking against `libproj` —
 //!   note that `pkg-config` must be available on your system.
 //! - `bundled_proj`: builds `libproj` from source bundled in the `proj-sys` crate.
 //!   Note that this feature requires Sqlite3 and `libtiff` to be present on your
 //!   system.
+//! - `bundled_static`: builds `libproj`, `libtiff`, Sqlite3 and zlib from source, and links them
+//!   statically, producing fully static binaries (for instance targeting musl) which can read
+//!   TIFF grids. See the `proj-sys` README for how the `libtiff` sources are found.
+//! - `runtime-loading`: doesn't link `libproj`, which is loaded when the program runs instead, so
+//!   that the same binary can use the `libproj` of each system, or run without it. If it can't be
+//!   loaded, creating a transformation fails with `ProjCreateError::Unavailable`, and functions
+//!   which can't fail, such as `ProjBuilder::new`, do nothing: call `load_libproj` to check.
 //! - `network`: exposes APIs which, when enabled, can fetch grid data from the internet to improve
 //!   projection accuracy. See [`enable_network`](struct.ProjBuilder.html#method.enable_network)
 //!   for details.
 //! - `tiff`: enables tiff support in the underlying libproj build. This is enabled via the
 //!   `network` feature for online fetching of grid data, but you can enable this explicitly,
 //!   without the `network` feature if you have pre-downloaded TIFF files.
+//! - `native-tls`: enables the `network` feature, and allows grid downloads to use the platform's
+//!   TLS implementation and certificate store. See
+//!   [`use_native_tls`](struct.ProjBuilder.html#method.use_native_tls).
+//! - `authority-axis-order`: transformations between known CRSs use the axis order defined by
+//!   the CRS authorities (e.g. Latitude, Longitude for EPSG:4326) by default, instead of
+//!   normalising it to Longitude, Latitude / Easting, Northing. See
+//!   [`set_normalize_axis_order`](struct.ProjBuilder.html#method.set_normalize_axis_order)
+//!   for details. The examples in this documentation use the normalised order.
+//! - `grid-archives`: allows pre-downloaded grids to be provisioned from a zip or tar archive. See
+//!   [`add_grid_archive`](struct.ProjBuilder.html#method.add_grid_archive).
+//! - `projjson`: adds the `projjson` module, representing CRSs as typed structs which can be
+//!   (de)serialized using `serde`.
+//! - `gdal`: adds `TryFrom` conversions between a [`Proj`] holding a CRS and GDAL's
+//!   `SpatialRef`. Note that this feature requires GDAL to be present on your system, and Rust
+//!   1.80 or later.
+//! - `crs-definitions`: adds `TryFrom` conversions between a [`Proj`] holding a CRS and the
+//!   `Def` structs of the `crs-definitions` crate.
+//! - `audit`: adds the `audit` module, describing every created transformation to a
+//!   user-provided sink.
+//! - `audit-sqlite`: enables the `audit` feature, and adds a sink storing the descriptions in
+//!   an SQLite database.
+//! - `mock`: adds the `mock` module, whose `MockTransformer` implements [`Transformer`] without
+//!   PROJ, so that code converting points can be unit tested without `libproj`.
+//! - `nalgebra`: implements [`Coord`] for the `Point2` and `Point3` types of the `nalgebra`
+//!   crate, transforming the height of a `Point3`.
+//! - `ndarray`: implements [`Transform`] for the two-dimensional arrays of the `ndarray` crate
+//!   with a point on each row, and two or three columns, transforming them in place.
+//! - `arrow`: adds the `arrow` module, transforming the points of Apache Arrow arrays in bulk,
+//!   for dataframe libraries such as Polars and DataFusion.
+//! - `geojson`: implements [`Transform`] for the objects of the `geojson` crate, and adds the
+//!   `geojson` module, reading and writing the name of their CRS.
 //!
 //! ## Network, Cache, and Search Path Functionality
 //!
@@ -132,11 +213,21 @@
 //! #### Grid File Cache
 //! Up to 300 mb of downloaded grids are cached to save bandwidth: This cache can be enabled or
 //! disabled using [`grid_cache_enable`](proj/struct.ProjBuilder.html#method.grid_cache_enable).
+//! Processes which should share a cache directory, such as the replicas of a deployment, can use
+//! [`set_shared_grid_cache`](proj/struct.ProjBuilder.html#method.set_shared_grid_cache), and
+//! populate it from a single job using
+//! [`warm_cache`](proj/struct.ProjBuilder.html#method.warm_cache).
 //!
 //! ### Search Path Modification
 //! The path used to search for resource files can be modified using
 //! [`set_search_paths`](proj/struct.ProjBuilder.html#method.set_search_paths)
 //!
+//! ### Offline Grids
+//! Deployments without network access can provision a directory of pre-downloaded grids using
+//! [`add_grid_bundle`](proj/struct.ProjBuilder.html#method.add_grid_bundle), and check that
+//! the grids their transformations may use are present using
+//! [`missing_grids`](proj/struct.ProjBuilder.html#method.missing_grids).
+//!
 //! ## Conform your own types
 //!
 //! If you have your own geometric types, you can conform them to the `Coord` trait and use `proj`
@@ -231,25 +322,126 @@ assert_relative_eq!(line[1], Coordinate { x: 538452.2313532799, y: 3602268.06571
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 #[cfg(feature = "network")]
-mod network;
+mod cache;
 
 #[cfg_attr(docsrs, feature(doc_cfg))]
 #[cfg(feature = "geo-types")]
 mod geo_types;
+#[cfg(feature = "geo-types")]
+mod geodesic;
+
+#[cfg(feature = "gdal")]
+mod gdal;
+
+#[cfg(feature = "crs-definitions")]
+mod crs_definitions;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
 
 #[cfg(test)]
 #[macro_use]
 extern crate approx;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+#[cfg(feature = "audit")]
+pub mod audit;
+pub mod batch;
+mod config;
+mod context;
+mod coord;
+pub mod corpus;
+pub mod crs;
+pub mod db;
+mod diagnostics;
+mod fast;
+#[cfg_attr(docsrs, doc(cfg(feature = "geojson")))]
+#[cfg(feature = "geojson")]
+pub mod geojson;
+pub mod grid;
+mod logging;
+mod lonlat;
+pub mod migrate;
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg_attr(docsrs, doc(cfg(feature = "network")))]
+#[cfg(feature = "network")]
+pub mod net;
+pub mod op;
+mod parameters;
+pub mod pipeline;
+mod pj;
+pub mod pool;
+mod precision;
+pub mod prelude;
 mod proj;
+#[cfg_attr(docsrs, doc(cfg(feature = "projjson")))]
+#[cfg(feature = "projjson")]
+pub mod projjson;
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod tile;
 mod transform;
+mod transformer;
+mod typed;
+mod version;
+pub mod wkb;
 pub use transform::{Transform, TransformError};
+pub use transformer::{CoordTransform, Transformer};
+
+pub use crate::config::{
+    configuration, configure, global_config, reset_configuration, set_global_config, GlobalConfig,
+};
+pub use crate::context::{cleanup, Context};
+pub use crate::coord::{Direction, ProjCoord};
+pub use crate::crs::{
+    classify_input, export_definitions, Conversion, CrsBuilder, Datum, ExportFormat, GeoidModel,
+    InputKind, IntoCrs, LinearUnit,
+};
+pub use crate::diagnostics::CreationDiagnostics;
+pub use crate::grid::{grid_info, GridBundle, GridInfo, GridReport, GridShift};
+
+pub use crate::logging::LogLevel;
+pub use crate::lonlat::{normalize_longitude, wrap_angles, LonLat};
+
+#[cfg(feature = "network")]
+pub use crate::net::{
+    CancellationToken, DownloadProgress, NetworkMetrics, NetworkTimeouts, RetryPolicy,
+};
+
+pub use crate::parameters::{OperationParameter, ProjectionConversion};
+pub use crate::pipeline::{
+    Affine, Helmert, HelmertRates, PipelineBuilder, ProjOp, RotationConvention, UnitConvert,
+};
+pub use crate::pj::{PjPtrExt, PjRef};
+pub use crate::pool::{PoolMetrics, PooledProj, ProjPool};
 
+pub use crate::precision::{OutputPrecision, Precision};
+pub use crate::proj::Accuracy;
 pub use crate::proj::Area;
+pub use crate::proj::CacheConfig;
 pub use crate::proj::Coord;
+pub use crate::proj::CoordinateType;
+pub use crate::proj::Errno;
 pub use crate::proj::Info;
+pub use crate::proj::OperationGrids;
 pub use crate::proj::Proj;
 pub use crate::proj::ProjBuilder;
 pub use crate::proj::ProjCreateError;
 pub use crate::proj::ProjError;
 pub use crate::proj::ProjInfo;
+pub use crate::proj::Uncertainty;
+pub use crate::proj::WktOptions;
+pub use crate::proj::WktVersion;
+pub use crate::typed::{CoordinateMetadata, Crs, Transformation};
+pub use crate::version::{supported_proj_versions, ProjVersion, SupportedVersions};
+#[cfg_attr(docsrs, doc(cfg(feature = "runtime-loading")))]
+#[cfg(feature = "runtime-loading")]
+pub use proj_sys::{is_libproj_loaded, load_libproj, load_libproj_from, LoadError};
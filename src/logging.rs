@@ -0,0 +1,130 @@
+//! Routing of PROJ's log messages into the [`log`] crate
+use libc::{c_char, c_int, c_void};
+use proj_sys::{
+    PJ_LOG_LEVEL, PJ_LOG_LEVEL_PJ_LOG_DEBUG, PJ_LOG_LEVEL_PJ_LOG_ERROR, PJ_LOG_LEVEL_PJ_LOG_NONE,
+    PJ_LOG_LEVEL_PJ_LOG_TRACE,
+};
+use std::cell::RefCell;
+
+use crate::proj::_string;
+
+/// The target of log records emitted on behalf of PROJ
+const TARGET: &str = "proj";
+
+/// The verbosity of PROJ's [logging](https://proj.org/development/reference/functions.html#c.proj_log_level)
+///
+/// Messages are emitted as [`log`] records with the `proj` target. PROJ errors are logged at the
+/// `Error` level, debug messages at the `Debug` level and trace messages at the `Trace` level.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    /// Don't log anything
+    None,
+    /// Log errors, including warnings such as missing grids. This is PROJ's default.
+    Error,
+    /// Log errors and debug messages, such as the operations considered for a transformation
+    Debug,
+    /// Log everything
+    Trace,
+}
+
+impl LogLevel {
+    pub(crate) fn as_pj_log_level(self) -> PJ_LOG_LEVEL {
+        match self {
+            LogLevel::None => PJ_LOG_LEVEL_PJ_LOG_NONE,
+            LogLevel::Error => PJ_LOG_LEVEL_PJ_LOG_ERROR,
+            LogLevel::Debug => PJ_LOG_LEVEL_PJ_LOG_DEBUG,
+            LogLevel::Trace => PJ_LOG_LEVEL_PJ_LOG_TRACE,
+        }
+    }
+}
+
+/// The last error message logged by PROJ on a context, used to explain failures which PROJ
+/// doesn't report through an error number
+pub(crate) type LastError = RefCell<Option<String>>;
+
+/// Logging callback, forwarding PROJ's messages to the `log` crate instead of stderr
+///
+/// `user_data` points to the [`LastError`] of the context, if any.
+pub(crate) unsafe extern "C" fn log_callback(
+    user_data: *mut c_void,
+    level: c_int,
+    msg: *const c_char,
+) {
+    let level = match level as PJ_LOG_LEVEL {
+        PJ_LOG_LEVEL_PJ_LOG_ERROR => log::Level::Error,
+        PJ_LOG_LEVEL_PJ_LOG_DEBUG => log::Level::Debug,
+        _ => log::Level::Trace,
+    };
+    let last_error = (user_data as *const LastError).as_ref();
+    let record_error = last_error.is_some() && level == log::Level::Error;
+    if !record_error && !log::log_enabled!(target: TARGET, level) {
+        return;
+    }
+    let Ok(msg) = _string(msg) else {
+        return;
+    };
+    let msg = msg.trim_end();
+    if record_error {
+        if let Some(Ok(mut last_error)) = last_error.map(RefCell::try_borrow_mut) {
+            *last_error = Some(msg.to_string());
+        }
+    }
+    log::log!(target: TARGET, level, "{}", msg);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Proj, ProjBuilder};
+    use std::sync::Mutex;
+
+    static RECORDS: Mutex<Vec<(log::Level, String)>> = Mutex::new(Vec::new());
+
+    struct TestLogger;
+
+    impl log::Log for TestLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == TARGET
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                RECORDS
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: TestLogger = TestLogger;
+
+    #[test]
+    fn test_log_records() {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        // other tests may log concurrently, so only look for a message no other test triggers
+        let not_found = |(level, msg): &(log::Level, String)| {
+            *level == log::Level::Error && msg.contains("crs not found")
+        };
+
+        assert!(Proj::new("EPSG:999999").is_err());
+        assert!(RECORDS.lock().unwrap().drain(..).any(|r| not_found(&r)));
+
+        let mut builder = ProjBuilder::new();
+        builder.set_log_level(LogLevel::Debug);
+        builder
+            .proj_known_crs("EPSG:4326", "EPSG:3857", None)
+            .unwrap();
+        let records = RECORDS.lock().unwrap().drain(..).collect::<Vec<_>>();
+        assert!(records.iter().any(|(level, _)| *level == log::Level::Debug));
+
+        let mut builder = ProjBuilder::new();
+        builder.set_log_level(LogLevel::None);
+        assert!(builder.proj("EPSG:999999").is_err());
+        assert!(!RECORDS.lock().unwrap().iter().any(not_found));
+    }
+}
@@ -0,0 +1,191 @@
+//! Geographic coordinates in degrees, and the normalisation of angles
+use crate::proj::CoordinateType;
+use crate::Coord;
+
+fn degrees<T: CoordinateType>(value: f64) -> T {
+    T::from(value).expect("angles in degrees are representable")
+}
+
+/// Wrap a longitude in degrees to the range [-180, 180)
+///
+/// Longitudes already within [-180, 180] are returned unchanged, and non-finite values are
+/// returned as NaN.
+///
+/// ```rust
+/// use proj::normalize_longitude;
+///
+/// assert_eq!(normalize_longitude(353.75), -6.25);
+/// assert_eq!(normalize_longitude(-190.0), 170.0);
+/// assert_eq!(normalize_longitude(180.0), 180.0);
+/// ```
+pub fn normalize_longitude<T: CoordinateType>(lon: T) -> T {
+    let half_turn = degrees::<T>(180.0);
+    let turn = degrees::<T>(360.0);
+    if !lon.is_finite() {
+        T::nan()
+    } else if lon >= -half_turn && lon <= half_turn {
+        lon
+    } else {
+        lon - turn * ((lon + half_turn) / turn).floor()
+    }
+}
+
+/// Wrap a longitude and a latitude in degrees to the ranges [-180, 180] and [-90, 90]
+///
+/// Latitudes beyond a pole continue down the opposite meridian, as when travelling over the
+/// pole: `(10, 100)` is wrapped to `(-170, 80)`.
+///
+/// ```rust
+/// use proj::wrap_angles;
+///
+/// assert_eq!(wrap_angles(10.0, 100.0), (-170.0, 80.0));
+/// assert_eq!(wrap_angles(370.0, -45.0), (10.0, -45.0));
+/// ```
+pub fn wrap_angles<T: CoordinateType>(lon: T, lat: T) -> (T, T) {
+    let quarter_turn = degrees::<T>(90.0);
+    let half_turn = degrees::<T>(180.0);
+    let lat = normalize_longitude(lat);
+    if lat > quarter_turn {
+        (normalize_longitude(lon + half_turn), half_turn - lat)
+    } else if lat < -quarter_turn {
+        (normalize_longitude(lon + half_turn), -half_turn - lat)
+    } else {
+        (normalize_longitude(lon), lat)
+    }
+}
+
+/// A longitude and a latitude in degrees
+///
+/// Creating a `LonLat` checks that the coordinates are valid geographic coordinates, which
+/// catches swapped axes and coordinates in radians or in projected units before they are
+/// silently transformed into unexpected results. Longitudes are normalised to [-180, 180).
+///
+/// `LonLat` implements [`Coord`], with the longitude as `x`, so it can be passed to
+/// transformations from geographic CRSs created by
+/// [`Proj::new_known_crs`](crate::Proj::new_known_crs):
+///
+/// ```rust
+/// # use approx::assert_relative_eq;
+/// use proj::{LonLat, Proj};
+///
+/// // a latitude of 2.35° is plausible, but a latitude of 48.86 must be a longitude
+/// assert!(LonLat::new(48.8566, 2.3522).is_some());
+/// assert!(LonLat::new(2.3522, 148.8566).is_none());
+///
+/// let paris = LonLat::new(2.3522, 48.8566).unwrap();
+/// let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+/// let (x, y) = to_merc.convert(paris.into()).unwrap();
+/// assert_relative_eq!(x, 261845.70624393807, epsilon = 1e-6);
+/// assert_relative_eq!(y, 6250564.349543125, epsilon = 1e-6);
+/// ```
+///
+/// When converting to a `LonLat`, the output of the transformation is wrapped using
+/// [`wrap_angles`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LonLat<T: CoordinateType = f64> {
+    lon: T,
+    lat: T,
+}
+
+impl<T: CoordinateType> LonLat<T> {
+    /// Create a point, returning `None` if the coordinates are non-finite or the latitude
+    /// isn't within [-90, 90]
+    ///
+    /// The longitude is normalised using [`normalize_longitude`].
+    pub fn new(lon: T, lat: T) -> Option<Self> {
+        let quarter_turn = degrees::<T>(90.0);
+        if !(lon.is_finite() && lat >= -quarter_turn && lat <= quarter_turn) {
+            return None;
+        }
+        Some(LonLat {
+            lon: normalize_longitude(lon),
+            lat,
+        })
+    }
+
+    /// Create a point, wrapping its coordinates using [`wrap_angles`]
+    ///
+    /// Returns `None` if the coordinates are non-finite.
+    pub fn wrapping(lon: T, lat: T) -> Option<Self> {
+        if !lon.is_finite() || !lat.is_finite() {
+            return None;
+        }
+        let (lon, lat) = wrap_angles(lon, lat);
+        Some(LonLat { lon, lat })
+    }
+
+    /// Create a point from a longitude and a latitude in radians
+    pub fn from_radians(lon: T, lat: T) -> Option<Self> {
+        Self::new(lon.to_degrees(), lat.to_degrees())
+    }
+
+    /// The longitude, in [-180, 180]
+    pub fn lon(&self) -> T {
+        self.lon
+    }
+
+    /// The latitude, in [-90, 90]
+    pub fn lat(&self) -> T {
+        self.lat
+    }
+
+    /// Return the longitude and the latitude in radians, as expected by
+    /// [`Proj::project`](crate::Proj::project)
+    pub fn to_radians(&self) -> (T, T) {
+        (self.lon.to_radians(), self.lat.to_radians())
+    }
+}
+
+impl<T: CoordinateType> Coord<T> for LonLat<T> {
+    fn x(&self) -> T {
+        self.lon
+    }
+    fn y(&self) -> T {
+        self.lat
+    }
+    fn from_xy(x: T, y: T) -> Self {
+        let (lon, lat) = wrap_angles(x, y);
+        LonLat { lon, lat }
+    }
+}
+
+impl<T: CoordinateType> From<LonLat<T>> for (T, T) {
+    fn from(point: LonLat<T>) -> Self {
+        (point.lon, point.lat)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Proj;
+
+    #[test]
+    fn test_normalize_longitude() {
+        assert_eq!(normalize_longitude(353.7), 353.7 - 360.0);
+        assert_eq!(normalize_longitude(540.0), -180.0);
+        assert_eq!(normalize_longitude(-180.0), -180.0);
+        assert_eq!(normalize_longitude(-540.0f32), -180.0);
+        assert!(normalize_longitude(f64::INFINITY).is_nan());
+        assert_eq!(wrap_angles(0.0, -100.0), (180.0, -80.0));
+        assert_eq!(wrap_angles(170.0, 270.0), (170.0, -90.0));
+    }
+
+    #[test]
+    fn test_lonlat() {
+        assert_eq!(LonLat::new(353.7, 45.0).unwrap().lon(), 353.7 - 360.0);
+        assert!(LonLat::new(0.0, 90.5).is_none());
+        assert!(LonLat::new(f64::NAN, 0.0).is_none());
+        assert!(LonLat::new(0.0, f64::NAN).is_none());
+        assert_eq!(
+            LonLat::wrapping(10.0, 100.0),
+            Some(LonLat::new(-170.0, 80.0).unwrap())
+        );
+        let point = LonLat::from_radians(0.5f64, -0.25).unwrap();
+        assert_eq!(point.to_radians(), (0.5, -0.25));
+
+        let merc = Proj::new_known_crs("EPSG:3857", "EPSG:4326", None).unwrap();
+        let result: LonLat = merc.convert(LonLat::from_xy(20037508.0, 0.0)).unwrap();
+        assert!(result.lon() <= 180.0);
+    }
+}
@@ -0,0 +1,219 @@
+//! Helpers for porting legacy `proj4` definition strings to modern CRS definitions.
+//!
+//! `proj4`-era libraries (pyproj 1, old GDAL configurations…) described CRSs using PROJ strings
+//! such as `+proj=tmerc … +datum=OSGB36 +nadgrids=@OSTN15_NTv2_OSGBtoETRS.gsb +no_defs`. Since
+//! PROJ 6 these are still accepted, but datum shifts are described by the CRS (and chosen from
+//! the PROJ database) rather than embedded in the definition, which changes the results and
+//! accuracy of transformations.
+//!
+//! ```rust
+//! use proj::migrate::{from_proj4_string, MigrationNote};
+//!
+//! let report = from_proj4_string("+proj=longlat +datum=WGS84 +no_defs").unwrap();
+//! assert_eq!(report.crs.as_deref(), Some("OGC:CRS84"));
+//! assert!(report
+//!     .notes
+//!     .contains(&MigrationNote::Obsolete("no_defs".to_string())));
+//! ```
+use proj_sys::{
+    proj_get_id_auth_name, proj_get_id_code, proj_identify, proj_int_list_destroy,
+    proj_list_destroy, proj_list_get, proj_list_get_count,
+};
+use std::fmt;
+use std::ptr;
+
+use crate::proj::_string;
+use crate::{PjPtrExt, Proj, ProjCreateError};
+
+/// Identifications below this confidence (in percent) are not reported
+const MIN_CONFIDENCE: i32 = 70;
+
+/// A remark about a part of a legacy definition which behaves differently in modern PROJ
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MigrationNote {
+    /// An `+init=` file reference. Init files are deprecated: use an `AUTHORITY:CODE` instead.
+    InitFile(String),
+    /// Explicit `+towgs84` parameters. The CRS becomes a BoundCRS and transformations will
+    /// use this Helmert shift instead of the best operation available in the database.
+    ToWgs84(String),
+    /// Grid-based datum shift. Transformations depend on the availability of these grids;
+    /// `@`-prefixed grids are optional and silently skipped when missing.
+    NadGrids(Vec<String>),
+    /// A `+datum` name. Modern PROJ no longer implies a particular datum shift from it.
+    Datum(String),
+    /// A parameter which has no effect in modern PROJ, such as `+no_defs` or `+wktext`.
+    Obsolete(String),
+}
+
+impl fmt::Display for MigrationNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationNote::InitFile(file) => write!(
+                f,
+                "+init={file} uses a deprecated init file: use an AUTHORITY:CODE instead"
+            ),
+            MigrationNote::ToWgs84(params) => write!(
+                f,
+                "+towgs84={params} forces a Helmert datum shift: transformations won't use the best operation from the database"
+            ),
+            MigrationNote::NadGrids(grids) => write!(
+                f,
+                "+nadgrids={} requires grids to be available: missing optional grids are silently skipped",
+                grids.join(",")
+            ),
+            MigrationNote::Datum(datum) => write!(
+                f,
+                "+datum={datum} no longer implies a datum shift: transformations are selected from the database"
+            ),
+            MigrationNote::Obsolete(param) => write!(f, "+{param} has no effect"),
+        }
+    }
+}
+
+/// The modern equivalent of a legacy `proj4` definition
+#[derive(Clone, Debug)]
+pub struct MigrationReport {
+    /// The `AUTHORITY:CODE` of an equivalent CRS in the database, if one could be identified
+    pub crs: Option<String>,
+    /// The confidence (in percent) of the identification of `crs`
+    pub confidence: Option<i32>,
+    /// The CRS described by the definition, as PROJJSON
+    pub projjson: String,
+    /// The pipeline PROJ uses to transform from this CRS to WGS 84, if it could be determined
+    pub pipeline_to_wgs84: Option<String>,
+    /// The accuracy, in metres, of the transformation to WGS 84, if known
+    pub accuracy: Option<f64>,
+    /// Remarks about parts of the definition which behave differently in modern PROJ
+    pub notes: Vec<MigrationNote>,
+}
+
+/// Report the modern equivalent of a legacy `proj4` definition string
+///
+/// # Safety
+/// This method contains unsafe code.
+pub fn from_proj4_string(definition: &str) -> Result<MigrationReport, ProjCreateError> {
+    let notes = notes(definition);
+    let crs_definition = if definition.contains("+type=crs") {
+        definition.to_string()
+    } else {
+        format!("{definition} +type=crs")
+    };
+    let crs = Proj::new(&crs_definition)?;
+    let projjson = crs
+        .to_projjson(None, None, None)
+        .map_err(|e| ProjCreateError::ProjError(e.to_string()))?;
+    let (identified, confidence) = match identify(&crs) {
+        Some((code, confidence)) => (Some(code), Some(confidence)),
+        None => (None, None),
+    };
+    let (pipeline_to_wgs84, accuracy) =
+        match Proj::new_known_crs(&crs_definition, "EPSG:4326", None) {
+            Ok(to_wgs84) => {
+                let info = to_wgs84.proj_info();
+                (info.definition, info.accuracy.metres())
+            }
+            Err(_) => (None, None),
+        };
+    Ok(MigrationReport {
+        crs: identified,
+        confidence,
+        projjson,
+        pipeline_to_wgs84,
+        accuracy,
+        notes,
+    })
+}
+
+/// Scan a definition for legacy parameters
+fn notes(definition: &str) -> Vec<MigrationNote> {
+    definition
+        .split_whitespace()
+        .filter_map(|token| {
+            let token = token.trim_start_matches('+');
+            let (key, value) = token.split_once('=').unwrap_or((token, ""));
+            match key {
+                "init" => Some(MigrationNote::InitFile(value.to_string())),
+                "towgs84" => Some(MigrationNote::ToWgs84(value.to_string())),
+                "nadgrids" => Some(MigrationNote::NadGrids(
+                    value.split(',').map(str::to_string).collect(),
+                )),
+                "datum" => Some(MigrationNote::Datum(value.to_string())),
+                "no_defs" | "wktext" => Some(MigrationNote::Obsolete(key.to_string())),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Find the best match for a CRS in the database, with its confidence
+fn identify(crs: &Proj) -> Option<(String, i32)> {
+    unsafe {
+        let mut confidence = ptr::null_mut();
+        let list = proj_identify(
+            crs.context().as_ptr(),
+            crs.as_ptr(),
+            ptr::null(),
+            ptr::null(),
+            &mut confidence,
+        );
+        if list.is_null() {
+            return None;
+        }
+        let mut best = None;
+        // candidates are sorted by decreasing confidence
+        if proj_list_get_count(list) > 0 && *confidence >= MIN_CONFIDENCE {
+            let candidate =
+                proj_list_get(crs.context().as_ptr(), list, 0).into_pj_ref(crs.context());
+            if let Ok(candidate) = candidate {
+                let auth = proj_get_id_auth_name(candidate.as_ptr(), 0);
+                let code = proj_get_id_code(candidate.as_ptr(), 0);
+                if !auth.is_null() && !code.is_null() {
+                    if let (Ok(auth), Ok(code)) = (_string(auth), _string(code)) {
+                        best = Some((format!("{auth}:{code}"), *confidence));
+                    }
+                }
+            }
+        }
+        proj_int_list_destroy(confidence);
+        proj_list_destroy(list);
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wgs84() {
+        let report = from_proj4_string("+proj=longlat +datum=WGS84 +no_defs").unwrap();
+        assert_eq!(report.crs.as_deref(), Some("OGC:CRS84"));
+        assert_eq!(
+            report.notes,
+            vec![
+                MigrationNote::Datum("WGS84".to_string()),
+                MigrationNote::Obsolete("no_defs".to_string())
+            ]
+        );
+        assert!(report.projjson.contains("GeographicCRS"));
+    }
+
+    #[test]
+    fn test_towgs84() {
+        let report = from_proj4_string(
+            "+proj=tmerc +lat_0=49 +lon_0=-2 +k=0.9996012717 +x_0=400000 +y_0=-100000 +ellps=airy
+            +towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489 +units=m +no_defs",
+        )
+        .unwrap();
+        assert!(report.notes.contains(&MigrationNote::ToWgs84(
+            "446.448,-125.157,542.06,0.15,0.247,0.842,-20.489".to_string()
+        )));
+        assert!(report.projjson.contains("BoundCRS"));
+        assert!(report.pipeline_to_wgs84.unwrap().contains("helmert"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(from_proj4_string("+proj=🦀").is_err());
+    }
+}
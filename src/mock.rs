@@ -0,0 +1,172 @@
+//! A [`Transformer`] which doesn't use PROJ, for unit tests
+//!
+//! Combined with the `runtime-loading` feature, which doesn't link `libproj`, tests using
+//! [`MockTransformer`] build and run on systems where PROJ isn't installed.
+use std::cell::Cell;
+use std::fmt;
+
+use crate::proj::CoordinateType;
+use crate::transformer::transform_coord;
+use crate::{Coord, CoordTransform, ProjError, Transformer};
+
+type PointFn = Box<dyn Fn(f64, f64) -> Result<(f64, f64), ProjError>>;
+
+/// A [`Transformer`] converting points using closures
+///
+/// ```rust
+/// use proj::mock::MockTransformer;
+/// use proj::Transformer;
+///
+/// // a transformation shifting points by 10 units east
+/// let shift = MockTransformer::new(|x, y| (x + 10.0, y)).with_inverse(|x, y| (x - 10.0, y));
+/// assert_eq!(shift.transform_point((1.0, 2.0), false).unwrap(), (11.0, 2.0));
+/// assert_eq!(shift.transform_point((1.0, 2.0), true).unwrap(), (-9.0, 2.0));
+/// assert_eq!(shift.calls(), 2);
+/// ```
+pub struct MockTransformer {
+    forward: PointFn,
+    inverse: Option<PointFn>,
+    calls: Cell<usize>,
+}
+
+impl MockTransformer {
+    /// A transformer converting points forwards using `forward`, and failing in the inverse
+    /// direction
+    pub fn new<F>(forward: F) -> Self
+    where
+        F: Fn(f64, f64) -> (f64, f64) + 'static,
+    {
+        Self::fallible(move |x, y| Ok(forward(x, y)))
+    }
+
+    /// A transformer returning points unchanged, in both directions
+    pub fn identity() -> Self {
+        Self::new(|x, y| (x, y)).with_inverse(|x, y| (x, y))
+    }
+
+    /// A transformer converting points forwards using `forward`, which can fail
+    ///
+    /// ```rust
+    /// use proj::mock::MockTransformer;
+    /// use proj::{ProjError, Transformer};
+    ///
+    /// let positive = MockTransformer::fallible(|x, y| {
+    ///     if x < 0.0 {
+    ///         Err(ProjError::Conversion("Invalid coordinate".to_string()))
+    ///     } else {
+    ///         Ok((x, y))
+    ///     }
+    /// });
+    /// let mut points = [(1.0, 1.0), (-1.0, 1.0)];
+    /// assert!(positive.transform_points(&mut points, false).is_err());
+    /// ```
+    pub fn fallible<F>(forward: F) -> Self
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64), ProjError> + 'static,
+    {
+        MockTransformer {
+            forward: Box::new(forward),
+            inverse: None,
+            calls: Cell::new(0),
+        }
+    }
+
+    /// Convert points in the inverse direction using `inverse`
+    pub fn with_inverse<F>(self, inverse: F) -> Self
+    where
+        F: Fn(f64, f64) -> (f64, f64) + 'static,
+    {
+        self.with_fallible_inverse(move |x, y| Ok(inverse(x, y)))
+    }
+
+    /// Convert points in the inverse direction using `inverse`, which can fail
+    pub fn with_fallible_inverse<F>(mut self, inverse: F) -> Self
+    where
+        F: Fn(f64, f64) -> Result<(f64, f64), ProjError> + 'static,
+    {
+        self.inverse = Some(Box::new(inverse));
+        self
+    }
+
+    /// The number of points converted so far, including those which failed
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+}
+
+impl Transformer for MockTransformer {
+    fn transform_point(&self, point: (f64, f64), inverse: bool) -> Result<(f64, f64), ProjError> {
+        self.calls.set(self.calls.get() + 1);
+        let (x, y) = point;
+        match (inverse, &self.inverse) {
+            (false, _) => (self.forward)(x, y),
+            (true, Some(inverse)) => inverse(x, y),
+            (true, None) => Err(ProjError::Conversion(
+                "the mock transformer has no inverse".to_string(),
+            )),
+        }
+    }
+}
+
+/// Converts points using the closures, so that mocks can be used with
+/// [`Transform`](crate::Transform)
+impl CoordTransform for MockTransformer {
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        transform_coord(self, point, false)
+    }
+
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        transform_coord(self, point, inverse)
+    }
+}
+
+impl fmt::Debug for MockTransformer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockTransformer")
+            .field("has_inverse", &self.inverse.is_some())
+            .field("calls", &self.calls.get())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mock_transformer() {
+        let double = MockTransformer::new(|x, y| (x * 2.0, y * 2.0));
+        let transformer: &dyn Transformer = &double;
+        let mut points = [(1.0, 2.0), (3.0, 4.0)];
+        transformer.transform_points(&mut points, false).unwrap();
+        assert_eq!(points, [(2.0, 4.0), (6.0, 8.0)]);
+        assert!(transformer.transform_point((1.0, 2.0), true).is_err());
+        assert_eq!(double.calls(), 3);
+        #[cfg(feature = "geo-types")]
+        {
+            use crate::Transform;
+            let mut line = geo_types::LineString::from(vec![(1.0, 2.0), (3.0, 4.0)]);
+            line.transform(&double).unwrap();
+            assert_eq!(
+                line,
+                geo_types::LineString::from(vec![(2.0, 4.0), (6.0, 8.0)])
+            );
+            // through a trait object
+            line.transform(&transformer).unwrap();
+            assert_eq!(double.calls(), 7);
+        }
+        let identity = MockTransformer::identity();
+        assert_eq!(
+            identity.transform_point((1.0, 2.0), true).unwrap(),
+            (1.0, 2.0)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use nalgebra::{Point2, Point3, Scalar};
+
+use crate::proj::CoordinateType;
+
+///```rust
+/// # use approx::assert_relative_eq;
+/// use nalgebra::Point2;
+/// use proj::Proj;
+///
+/// let nad_ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+/// let result = nad_ft_to_m
+///     .convert(Point2::new(4760096.421921f64, 3744293.729449f64))
+///     .unwrap();
+/// assert_relative_eq!(result.x, 1450880.29f64, epsilon = 1.0e-2);
+/// assert_relative_eq!(result.y, 1141263.01f64, epsilon = 1.0e-2);
+/// ```
+impl<T: CoordinateType + Scalar> crate::Coord<T> for Point2<T> {
+    fn x(&self) -> T {
+        self.x
+    }
+    fn y(&self) -> T {
+        self.y
+    }
+    fn from_xy(x: T, y: T) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+/// The third coordinate is the height, which is transformed along with the others
+///
+///```rust
+/// # use approx::assert_relative_eq;
+/// use nalgebra::Point3;
+/// use proj::Proj;
+///
+/// // WGS 84 to WGS 84 with ellipsoidal heights in feet
+/// let to_feet = Proj::new("+proj=unitconvert +z_in=m +z_out=ft").unwrap();
+/// let mut points = [Point3::new(2.0, 48.0, 100.0), Point3::new(3.0, 49.0, 10.0)];
+/// to_feet.convert_array(&mut points).unwrap();
+/// assert_relative_eq!(points[0].z, 328.0839895, epsilon = 1e-6);
+/// assert_relative_eq!(points[1].x, 3.0);
+/// ```
+impl<T: CoordinateType + Scalar> crate::Coord<T> for Point3<T> {
+    fn x(&self) -> T {
+        self.x
+    }
+    fn y(&self) -> T {
+        self.y
+    }
+    fn from_xy(x: T, y: T) -> Self {
+        Point3::new(x, y, T::zero())
+    }
+    fn z(&self) -> Option<T> {
+        Some(self.z)
+    }
+    fn from_xyz(x: T, y: T, z: T) -> Self {
+        Point3::new(x, y, z)
+    }
+}
@@ -0,0 +1,124 @@
+use ndarray::{Array2, ArrayBase, ArrayView2, Axis, DataMut, Ix2};
+
+use crate::proj::{CoordinateType, ARRAY_CHUNK_LEN};
+use crate::{CoordTransform, ProjError, Transform};
+
+/// A matrix with a point on each row: an easting and a northing, and optionally a height in a
+/// third column
+///
+/// The rows are copied and converted in chunks using
+/// [`CoordTransform::convert_array`], so the matrix doesn't need to be contiguous: views of
+/// the columns of a larger matrix can be transformed in place. Matrices without two or three
+/// columns are rejected with [`ProjError::ArrayShape`].
+///
+/// If an error is returned, the matrix is left unchanged: matrices with more rows than fit in
+/// a chunk are converted once to check that every row can be, before being converted again and
+/// written back.
+///
+///```rust
+/// # use approx::assert_relative_eq;
+/// use ndarray::array;
+/// use proj::{Proj, Transform};
+///
+/// let nad_ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+/// let mut points = array![
+///     [4760096.421921, 3744293.729449],
+///     [4760197.421921, 3744394.729449],
+/// ];
+/// points.transform(&nad_ft_to_m).unwrap();
+/// assert_relative_eq!(points[[0, 0]], 1450880.29, epsilon = 1.0e-2);
+/// assert_relative_eq!(points[[0, 1]], 1141263.01, epsilon = 1.0e-2);
+/// ```
+impl<T, S> Transform<T> for ArrayBase<S, Ix2>
+where
+    T: CoordinateType,
+    S: DataMut<Elem = T>,
+{
+    type Output = Array2<T>;
+
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Array2<T>, ProjError> {
+        let mut output = self.to_owned();
+        output.transform(proj)?;
+        Ok(output)
+    }
+
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError> {
+        let columns = self.ncols();
+        if columns != 2 && columns != 3 {
+            return Err(ProjError::ArrayShape(columns));
+        }
+        let mut buffer = Vec::with_capacity(ARRAY_CHUNK_LEN.min(self.nrows()));
+        if self.nrows() > ARRAY_CHUNK_LEN {
+            for chunk in self.axis_chunks_iter(Axis(0), ARRAY_CHUNK_LEN) {
+                fill(&mut buffer, chunk);
+                proj.convert_array(&mut buffer)?;
+            }
+        }
+        for mut chunk in self.axis_chunks_iter_mut(Axis(0), ARRAY_CHUNK_LEN) {
+            fill(&mut buffer, chunk.view());
+            proj.convert_array(&mut buffer)?;
+            for (mut row, &(x, y, z)) in chunk.rows_mut().into_iter().zip(&buffer) {
+                row[0] = x;
+                row[1] = y;
+                if let Some(height) = row.get_mut(2) {
+                    *height = z;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Copy the rows of a chunk to the buffer converted by `convert_array`
+fn fill<T: CoordinateType>(buffer: &mut Vec<(T, T, T)>, chunk: ArrayView2<T>) {
+    buffer.clear();
+    buffer.extend(
+        chunk
+            .rows()
+            .into_iter()
+            .map(|row| (row[0], row[1], row.get(2).copied().unwrap_or_else(T::zero))),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Proj;
+    use ndarray::{array, s};
+
+    #[test]
+    fn test_transform_ndarray() {
+        let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let expected = to_merc.convert((2.0, 48.0)).unwrap();
+        // more rows than a chunk
+        let mut points = Array2::from_shape_fn((ARRAY_CHUNK_LEN * 2 + 1, 2), |(_, column)| {
+            [2.0, 48.0][column]
+        });
+        let original = points.clone();
+        assert_eq!(points.transformed(&to_merc).unwrap().row(0)[0], expected.0);
+        assert_eq!(points, original);
+        points.transform(&to_merc).unwrap();
+        for row in points.rows() {
+            assert_eq!((row[0], row[1]), expected);
+        }
+
+        // the heights are transformed, in a view of the first three columns
+        let to_feet = Proj::new("+proj=unitconvert +z_in=m +z_out=ft").unwrap();
+        let mut points = array![[2.0f32, 48.0, 100.0, 7.0], [3.0, 49.0, 10.0, 7.0]];
+        points.slice_mut(s![.., ..3]).transform(&to_feet).unwrap();
+        assert_relative_eq!(points[[0, 2]], 328.08398, epsilon = 1e-3);
+        assert_eq!(points.column(3), array![7.0, 7.0]);
+
+        assert!(matches!(
+            array![[1.0], [2.0]].transform(&to_merc),
+            Err(ProjError::ArrayShape(1))
+        ));
+
+        // an invalid latitude in the last chunk leaves the matrix unchanged
+        let mut points = Array2::from_elem((ARRAY_CHUNK_LEN * 2 + 1, 2), 2.0);
+        points[[ARRAY_CHUNK_LEN * 2, 1]] = 91.0;
+        let original = points.clone();
+        assert!(points.transform(&to_merc).is_err());
+        assert_eq!(points, original);
+    }
+}
@@ -0,0 +1,1300 @@
+//! Download of grids from the network, and monitoring of the downloads
+//!
+//! Network access is enabled using
+//! [`ProjBuilder::enable_network`](crate::ProjBuilder::enable_network).
+#![deny(
+    clippy::cast_slice_from_raw_parts,
+    clippy::cast_slice_different_sizes,
+    clippy::invalid_null_ptr_usage,
+    clippy::ptr_as_ptr,
+    clippy::transmute_ptr_to_ref
+)]
+/// A module for native grid network functionality, so we don't have to depend on libcurl
+/// The crate-public functions are facades – they're designed for interaction with libproj –
+/// delegating actual functionality to non-public versions, prefixed by an underscore.
+///
+/// **Note**: `error_string_max_size` is set to 128 by libproj, so error messages are truncated.
+/// The full error is kept in a thread-local slot, and surfaced by the failing transformation.
+// This functionality based on https://github.com/OSGeo/PROJ/blob/master/src/networkfilemanager.cpp#L1675
+use proj_sys::{proj_context_set_network_callbacks, PJ_CONTEXT, PROJ_NETWORK_HANDLE};
+
+use rustls::crypto::ring;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::CertificateDer;
+use rustls::{ClientConfig, RootCertStore};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CString;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::ops::Range;
+use std::os::raw::c_ulonglong;
+use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use ureq::{Agent, AgentBuilder, Proxy, Request, Response};
+
+use crate::proj::{_string, ProjError};
+use libc::c_char;
+use libc::c_void;
+use std::boxed::Box;
+use std::iter;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const CLIENT: &str = concat!("proj-rs/", env!("CARGO_PKG_VERSION"));
+// S3 sometimes sends this in place of actual client errors, so retry instead of erroring
+const TOO_MANY_REQUESTS: u16 = 429;
+const SUCCESS_ERROR_CODES: Range<u16> = 200..300;
+const NOT_MODIFIED: u16 = 304;
+const CLIENT_ERROR_CODES: Range<u16> = 400..500;
+const SERVER_ERROR_CODES: Range<u16> = 500..600;
+// how often cancellation is checked while reading a response or waiting to retry a request
+const READ_CHUNK_SIZE: u64 = 64 * 1024;
+const CANCELLATION_POLL: Duration = Duration::from_millis(50);
+const REVALIDATION_CACHE_SIZE: usize = 8 * 1024 * 1024;
+
+thread_local! {
+    /// The last error raised by a network callback on this thread, which libproj only sees as
+    /// a truncated message
+    static CALLBACK_ERROR: RefCell<Option<ProjError>> = const { RefCell::new(None) };
+}
+
+/// Forget the error raised by a network callback on this thread, if any
+pub(crate) fn clear_callback_error() {
+    CALLBACK_ERROR.with(|slot| slot.borrow_mut().take());
+}
+
+/// Return the last error raised by a network callback on this thread since it was cleared
+pub(crate) fn take_callback_error() -> Option<ProjError> {
+    CALLBACK_ERROR.with(|slot| slot.borrow_mut().take())
+}
+
+/// Keep the error raised by a network callback, and write its message into the error string
+/// passed by libproj, truncated to `max_size` bytes including the terminating nul character
+///
+/// # Safety
+/// `out_error_string` must point to a buffer of at least `max_size` bytes.
+unsafe fn fail(e: ProjError, max_size: usize, out_error_string: *mut c_char) {
+    let mut msg = e.to_string().replace('\0', "");
+    let mut len = msg.len().min(max_size.saturating_sub(1));
+    while !msg.is_char_boundary(len) {
+        len -= 1;
+    }
+    msg.truncate(len);
+    if max_size > 0 {
+        out_error_string.copy_from_nonoverlapping(msg.as_ptr().cast(), msg.len());
+        out_error_string.add(msg.len()).write(0);
+    }
+    CALLBACK_ERROR.with(|slot| *slot.borrow_mut() = Some(e));
+}
+
+/// How failed grid download requests are retried
+///
+/// The delay before retry `n` is `base_delay * n²`. The default policy retries up to 8 times,
+/// with a base delay of 100 ms (i.e. up to 6400 ms of delay between the last two attempts, and
+/// 20400 ms in total), on any server error and on `429 Too Many Requests`.
+///
+/// ```rust
+/// use proj::{ProjBuilder, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let mut builder = ProjBuilder::new();
+/// // fail fast in CI
+/// builder.set_retry_policy(RetryPolicy {
+///     max_retries: 2,
+///     max_elapsed: Some(Duration::from_secs(1)),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries of a request
+    pub max_retries: u8,
+    /// The delay before the first retry
+    pub base_delay: Duration,
+    /// The maximum time spent retrying a request, if any. No retry is attempted if its delay
+    /// would exceed it.
+    pub max_elapsed: Option<Duration>,
+    /// The HTTP status codes which cause a request to be retried
+    pub retry_status_codes: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 8,
+            base_delay: Duration::from_millis(100),
+            max_elapsed: None,
+            retry_status_codes: iter::once(TOO_MANY_REQUESTS)
+                .chain(SERVER_ERROR_CODES)
+                .collect(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Return the delay before retry number `retry`, or `None` if it shouldn't be attempted
+    /// after `elapsed` has already been spent retrying
+    fn delay(&self, retry: u8, elapsed: Duration) -> Option<Duration> {
+        if retry == 0 || retry > self.max_retries {
+            return None;
+        }
+        let delay = self.base_delay.checked_mul(u32::from(retry).pow(2))?;
+        match self.max_elapsed {
+            Some(max_elapsed) if elapsed + delay > max_elapsed => None,
+            _ => Some(delay),
+        }
+    }
+}
+
+/// Timeouts of grid download requests
+///
+/// By default, requests never time out, so a stalled server blocks the transformation which
+/// triggered the download. A request which times out fails with [`ProjError::Timeout`], without
+/// being retried.
+///
+/// ```rust
+/// use proj::{NetworkTimeouts, ProjBuilder};
+/// use std::time::Duration;
+///
+/// let mut builder = ProjBuilder::new();
+/// builder
+///     .set_network_timeouts(NetworkTimeouts {
+///         connect: Some(Duration::from_secs(5)),
+///         read: Some(Duration::from_secs(10)),
+///         ..Default::default()
+///     })
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworkTimeouts {
+    /// The maximum time spent connecting to the server
+    pub connect: Option<Duration>,
+    /// The maximum time spent waiting for each read of the response
+    pub read: Option<Duration>,
+    /// The maximum duration of a request, from connecting to reading the whole response
+    pub request: Option<Duration>,
+}
+
+/// A flag which cancels the grid downloads of a context, and can be set from any thread
+///
+/// Once cancelled, pending and subsequent requests fail with [`ProjError::Cancelled`] (surfaced
+/// by the transformation which triggered the download) until the token is
+/// [reset](#method.reset). Clones share the same flag.
+///
+/// ```rust
+/// use proj::{CancellationToken, ProjBuilder};
+///
+/// let token = CancellationToken::new();
+/// let mut builder = ProjBuilder::new();
+/// builder.set_cancellation_token(token.clone());
+/// // e.g. from a UI thread, when the user gives up waiting
+/// token.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the downloads in progress and any later ones
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Allow downloads again
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+
+    fn check(&self) -> Result<(), ProjError> {
+        if self.is_cancelled() {
+            return Err(ProjError::Cancelled);
+        }
+        Ok(())
+    }
+}
+
+/// This struct is cast to `c_void`, then to `PROJ_NETWORK_HANDLE` so it can be passed around
+struct HandleData {
+    url: String,
+    headers: HashMap<String, String>,
+    // bytes of the file downloaded so far using this handle
+    fetched: u64,
+    // this raw pointer is handed out to libproj but never returned,
+    // so a copy of the pointer (raw pointers are Copy) is stored here.
+    // Note to future self: are you 100% sure that the pointer is never read again
+    // after network_close returns?
+    hptr: Option<NonNull<c_char>>,
+}
+
+impl HandleData {
+    fn new(url: String, headers: HashMap<String, String>, hptr: Option<NonNull<c_char>>) -> Self {
+        Self {
+            url,
+            headers,
+            fetched: 0,
+            hptr,
+        }
+    }
+}
+
+impl Drop for HandleData {
+    // whenever HandleData is dropped we check whether it has a pointer,
+    // dereferencing it if need be so the resource is freed
+    fn drop(&mut self) {
+        if let Some(header) = self.hptr {
+            let _ = unsafe { CString::from_raw(header.as_ptr().cast()) };
+        }
+    }
+}
+
+/// TLS and proxy settings used for grid downloads, shared by the callbacks of a context
+#[derive(Default)]
+pub(crate) struct NetworkSettings {
+    ca_bundle: Option<Vec<CertificateDer<'static>>>,
+    proxy: Option<Proxy>,
+    #[cfg(feature = "native-tls")]
+    native_tls: bool,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) progress: Option<Rc<ProgressFn>>,
+    pub(crate) metrics: NetworkMetrics,
+    pub(crate) shared_cache: Option<PathBuf>,
+    pub(crate) cancellation: Option<CancellationToken>,
+    // the URL endpoint set in PROJ, followed by its mirrors
+    endpoints: Vec<String>,
+    // the index of the endpoint which last served a request
+    active_endpoint: usize,
+    pub(crate) revalidation: RevalidationCache,
+    timeouts: NetworkTimeouts,
+    // built from the settings above whenever they change. If None, a default agent is used
+    agent: Option<Agent>,
+}
+
+impl NetworkSettings {
+    /// Trust only the certificates of a PEM bundle, instead of the default roots
+    pub(crate) fn set_ca_bundle(&mut self, path: &Path) -> Result<(), ProjError> {
+        let certs = CertificateDer::pem_file_iter(path)
+            .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| ProjError::TlsConfig(format!("{}: {e}", path.display())))?;
+        if certs.is_empty() {
+            return Err(ProjError::TlsConfig(format!(
+                "{}: no certificates found",
+                path.display()
+            )));
+        }
+        self.ca_bundle = Some(certs);
+        self.build_agent()
+    }
+
+    pub(crate) fn set_proxy(&mut self, proxy: &str) -> Result<(), ProjError> {
+        self.proxy = Some(Proxy::new(proxy)?);
+        self.build_agent()
+    }
+
+    #[cfg(feature = "native-tls")]
+    pub(crate) fn set_native_tls(&mut self, enable: bool) -> Result<(), ProjError> {
+        self.native_tls = enable;
+        self.build_agent()
+    }
+
+    /// Set the endpoints used in turn when a download fails, the first being the one set in PROJ
+    pub(crate) fn set_endpoints(&mut self, endpoints: &[&str]) {
+        self.endpoints = endpoints
+            .iter()
+            .map(|endpoint| endpoint.trim_end_matches('/').to_string())
+            .collect();
+        self.active_endpoint = 0;
+    }
+
+    /// The URLs from which the file at `url` can be downloaded, with the index of their
+    /// endpoint, starting with the endpoint which last served a request
+    fn candidates(&self, url: &str) -> Vec<(Option<usize>, String)> {
+        let path = self
+            .endpoints
+            .first()
+            .and_then(|primary| url.strip_prefix(primary.as_str()))
+            .filter(|path| path.starts_with('/'));
+        let Some(path) = path else {
+            return vec![(None, url.to_string())];
+        };
+        let count = self.endpoints.len();
+        (0..count)
+            .map(|i| (self.active_endpoint + i) % count)
+            .map(|i| (Some(i), format!("{}{path}", self.endpoints[i])))
+            .collect()
+    }
+
+    pub(crate) fn set_timeouts(&mut self, timeouts: NetworkTimeouts) -> Result<(), ProjError> {
+        self.timeouts = timeouts;
+        self.build_agent()
+    }
+
+    fn build_agent(&mut self) -> Result<(), ProjError> {
+        let mut builder = AgentBuilder::new();
+        if let Some(timeout) = self.timeouts.connect {
+            builder = builder.timeout_connect(timeout);
+        }
+        if let Some(timeout) = self.timeouts.read {
+            builder = builder.timeout_read(timeout);
+        }
+        if let Some(timeout) = self.timeouts.request {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        #[cfg(feature = "native-tls")]
+        if self.native_tls {
+            let tls_error = |e: native_tls::Error| ProjError::TlsConfig(e.to_string());
+            let mut tls = native_tls::TlsConnector::builder();
+            if let Some(certs) = &self.ca_bundle {
+                tls.disable_built_in_roots(true);
+                for cert in certs {
+                    tls.add_root_certificate(
+                        native_tls::Certificate::from_der(cert).map_err(tls_error)?,
+                    );
+                }
+            }
+            builder = builder.tls_connector(Arc::new(tls.build().map_err(tls_error)?));
+            self.agent = Some(builder.build());
+            return Ok(());
+        }
+        if let Some(certs) = &self.ca_bundle {
+            let mut roots = RootCertStore::empty();
+            roots.add_parsable_certificates(certs.iter().cloned());
+            let config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+                .with_safe_default_protocol_versions()
+                .map_err(|e| ProjError::TlsConfig(e.to_string()))?
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+            builder = builder.tls_config(Arc::new(config));
+        }
+        self.agent = Some(builder.build());
+        Ok(())
+    }
+}
+
+/// A range of a file downloaded from a server which sent validators (`ETag` or `Last-Modified`)
+#[derive(Clone)]
+struct CachedRange {
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+    total: Option<u64>,
+}
+
+impl CachedRange {
+    /// Add the validators of the range to a request, so the server can answer
+    /// `304 Not Modified` instead of sending the range again
+    fn revalidate(&self, mut request: Request) -> Request {
+        if let Some(etag) = self.headers.get("etag") {
+            request = request.set("If-None-Match", etag);
+        }
+        if let Some(last_modified) = self.headers.get("last-modified") {
+            request = request.set("If-Modified-Since", last_modified);
+        }
+        request
+    }
+}
+
+/// The ranges of files downloaded so far, kept to be revalidated when PROJ requests them again,
+/// e.g. once the TTL of its grid cache expires
+///
+/// The oldest ranges are evicted once their total size exceeds `max_size` bytes.
+pub(crate) struct RevalidationCache {
+    ranges: HashMap<(String, String), CachedRange>,
+    order: VecDeque<(String, String)>,
+    size: usize,
+    max_size: usize,
+}
+
+impl Default for RevalidationCache {
+    fn default() -> Self {
+        Self::new(REVALIDATION_CACHE_SIZE)
+    }
+}
+
+impl RevalidationCache {
+    fn new(max_size: usize) -> Self {
+        RevalidationCache {
+            ranges: HashMap::new(),
+            order: VecDeque::new(),
+            size: 0,
+            max_size,
+        }
+    }
+
+    /// Change the maximum size of the cache, evicting ranges if needed. `0` disables it.
+    pub(crate) fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        self.evict();
+    }
+
+    fn get(&self, key: &(String, String)) -> Option<&CachedRange> {
+        self.ranges.get(key)
+    }
+
+    fn insert(&mut self, key: (String, String), range: CachedRange) {
+        if !(range.headers.contains_key("etag") || range.headers.contains_key("last-modified"))
+            || range.body.len() > self.max_size
+        {
+            return;
+        }
+        self.size += range.body.len();
+        if let Some(replaced) = self.ranges.insert(key.clone(), range) {
+            self.size -= replaced.body.len();
+            self.order.retain(|k| *k != key);
+        }
+        self.order.push_back(key);
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        while self.size > self.max_size {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(range) = self.ranges.remove(&oldest) {
+                self.size -= range.body.len();
+            }
+        }
+    }
+}
+
+pub(crate) type ProgressFn = dyn Fn(&DownloadProgress);
+
+/// The progress of the download of a grid, reported after each range of the file is fetched
+///
+/// PROJ only downloads the parts of a grid it needs, so `bytes_fetched` may never reach `total`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DownloadProgress<'a> {
+    pub url: &'a str,
+    /// The number of bytes of the file downloaded so far
+    pub bytes_fetched: u64,
+    /// The size of the file, if the server reported it
+    pub total: Option<u64>,
+}
+
+/// Counters of the grid download requests made on behalf of a PROJ context
+///
+/// Grid chunks found in PROJ's cache don't involve any request, and aren't counted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworkMetrics {
+    /// The number of HTTP requests made, including retries
+    pub requests: u64,
+    /// The number of requests which were retries
+    pub retries: u64,
+    /// The number of downloads which failed, after any retries
+    pub failures: u64,
+    /// The number of bytes downloaded
+    pub bytes_downloaded: u64,
+    /// The number of requests for ranges downloaded before which the server answered with
+    /// `304 Not Modified`, so the ranges were served from memory
+    pub revalidated: u64,
+    /// The number of downloads which failed on an endpoint, and were attempted on a mirror
+    pub failovers: u64,
+}
+
+/// Return the settings passed as user data to the callbacks, if any
+unsafe fn settings<'a>(ud: *mut c_void) -> Option<&'a RefCell<NetworkSettings>> {
+    ud.cast::<RefCell<NetworkSettings>>().as_ref()
+}
+
+/// The agent, retry policy and cancellation token configured using the settings passed as user
+/// data to the callbacks
+struct Client {
+    agent: Agent,
+    policy: RetryPolicy,
+    cancellation: Option<CancellationToken>,
+}
+
+impl Client {
+    /// Fail if the downloads have been cancelled
+    fn check(&self) -> Result<(), ProjError> {
+        self.cancellation
+            .as_ref()
+            .map_or(Ok(()), CancellationToken::check)
+    }
+
+    /// Read at most `limit` bytes of the body of a response, checking for cancellation between
+    /// chunks
+    fn read_body(&self, res: Response, limit: u64) -> Result<Vec<u8>, ProjError> {
+        let mut buf = vec![];
+        self.copy_body(res, limit, &mut buf)?;
+        Ok(buf)
+    }
+
+    /// Copy at most `limit` bytes of the body of a response to `dest`, checking for cancellation
+    /// between chunks, and return the number of bytes copied
+    fn copy_body<W: Write>(
+        &self,
+        res: Response,
+        limit: u64,
+        dest: &mut W,
+    ) -> Result<u64, ProjError> {
+        let mut reader = res.into_reader().take(limit);
+        let mut copied = 0;
+        loop {
+            self.check()?;
+            let read =
+                io::copy(&mut (&mut reader).take(READ_CHUNK_SIZE), dest).map_err(|e| {
+                    match e.kind() {
+                        io::ErrorKind::TimedOut => ProjError::Timeout,
+                        _ => ProjError::ReadError(e),
+                    }
+                })?;
+            if read == 0 {
+                return Ok(copied);
+            }
+            copied += read;
+        }
+    }
+}
+
+fn client(settings: Option<&RefCell<NetworkSettings>>) -> Result<Client, ProjError> {
+    let Some(settings) = settings else {
+        return Ok(Client {
+            agent: Agent::new(),
+            policy: RetryPolicy::default(),
+            cancellation: None,
+        });
+    };
+    let settings = settings.try_borrow().map_err(|_| ProjError::Network)?;
+    Ok(Client {
+        agent: settings.agent.clone().unwrap_or_else(Agent::new),
+        policy: settings.retry.clone(),
+        cancellation: settings.cancellation.clone(),
+    })
+}
+
+/// Make a request, retrying it according to the policy, and record it in the metrics
+fn fetch(
+    request: Request,
+    client: &Client,
+    settings: Option<&RefCell<NetworkSettings>>,
+) -> Result<Response, ProjError> {
+    let in_case_of_error = request.clone();
+    let result = client
+        .check()
+        .and_then(|_| request.call().map_err(ProjError::from))
+        .and_then(|mut res| {
+            // hand the response and retry instance off to the error-handler, continue on success
+            let retries = error_handler(
+                &mut res,
+                in_case_of_error,
+                &client.policy,
+                client.cancellation.as_ref(),
+            )?;
+            Ok((res, retries))
+        });
+    if let Some(Ok(mut settings)) = settings.map(RefCell::try_borrow_mut) {
+        let retries = match &result {
+            Ok((_, retries)) | Err(ProjError::DownloadError(_, _, retries)) => *retries,
+            Err(_) => 0,
+        };
+        settings.metrics.requests += 1 + u64::from(retries);
+        settings.metrics.retries += u64::from(retries);
+        if result.is_err() {
+            settings.metrics.failures += 1;
+        }
+    }
+    result.map(|(res, _)| res)
+}
+
+/// Record downloaded bytes in the metrics, and report progress
+fn record_download(
+    settings: Option<&RefCell<NetworkSettings>>,
+    url: &str,
+    downloaded: usize,
+    bytes_fetched: u64,
+    total: Option<u64>,
+) {
+    let Some(settings) = settings else {
+        return;
+    };
+    let progress = match settings.try_borrow_mut() {
+        Ok(mut settings) => {
+            settings.metrics.bytes_downloaded += downloaded as u64;
+            settings.progress.clone()
+        }
+        Err(_) => None,
+    };
+    // the settings aren't borrowed while user code runs
+    if let Some(progress) = progress {
+        progress(&DownloadProgress {
+            url,
+            bytes_fetched,
+            total,
+        });
+    }
+}
+
+/// Return the total size of the file from a `Content-Range: bytes x-y/total` header
+fn total_size(res: &Response) -> Option<u64> {
+    res.header("Content-Range")?
+        .rsplit_once('/')?
+        .1
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Process CDN response: handle retries in case of server error, or early return for client errors
+/// Successful retry data is stored into res
+/// Return the number of retries on success
+fn error_handler(
+    res: &mut Response,
+    rb: Request,
+    policy: &RetryPolicy,
+    cancellation: Option<&CancellationToken>,
+) -> Result<u8, ProjError> {
+    let mut retries = 0;
+    let mut elapsed = Duration::ZERO;
+    // Check whether something went wrong on the server, or if it's an S3 retry code
+    if policy.retry_status_codes.contains(&res.status()) {
+        // Start retrying, as long as the policy allows it
+        while policy.retry_status_codes.contains(&res.status()) {
+            let Some(wait) = policy.delay(retries + 1, elapsed) else {
+                break;
+            };
+            retries += 1;
+            elapsed += wait;
+            sleep(wait, cancellation)?;
+            let retry = rb.clone();
+            *res = retry.call()?;
+        }
+    // Not a timeout or known S3 retry code: bail out
+    } else if CLIENT_ERROR_CODES.contains(&res.status()) {
+        return Err(ProjError::DownloadError(
+            res.status_text().to_string(),
+            res.get_url().to_string(),
+            retries,
+        ));
+    }
+    // Retries have been exhausted OR
+    // The loop ended prematurely due to a different error
+    if !SUCCESS_ERROR_CODES.contains(&res.status()) && res.status() != NOT_MODIFIED {
+        return Err(ProjError::DownloadError(
+            res.status_text().to_string(),
+            res.get_url().to_string(),
+            retries,
+        ));
+    }
+    Ok(retries)
+}
+
+/// Sleep for `duration`, waking up regularly to return early if the downloads are cancelled
+fn sleep(duration: Duration, cancellation: Option<&CancellationToken>) -> Result<(), ProjError> {
+    let Some(cancellation) = cancellation else {
+        thread::sleep(duration);
+        return Ok(());
+    };
+    let deadline = Instant::now() + duration;
+    loop {
+        cancellation.check()?;
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(());
+        }
+        thread::sleep(CANCELLATION_POLL.min(deadline - now));
+    }
+}
+
+/// Call `download` with the URL of the file at `url` on each endpoint in turn, until it
+/// succeeds or the downloads are cancelled
+fn with_failover<T, F>(
+    url: &str,
+    settings: Option<&RefCell<NetworkSettings>>,
+    mut download: F,
+) -> Result<T, ProjError>
+where
+    F: FnMut(&str) -> Result<T, ProjError>,
+{
+    let candidates = settings
+        .and_then(|settings| Some(settings.try_borrow().ok()?.candidates(url)))
+        .unwrap_or_else(|| vec![(None, url.to_string())]);
+    let mut error = None;
+    for (endpoint, candidate) in candidates {
+        if error.is_some() {
+            if let Some(Ok(mut settings)) = settings.map(RefCell::try_borrow_mut) {
+                settings.metrics.failovers += 1;
+            }
+        }
+        match download(&candidate) {
+            Ok(result) => {
+                if let (Some(endpoint), Some(Ok(mut settings))) =
+                    (endpoint, settings.map(RefCell::try_borrow_mut))
+                {
+                    settings.active_endpoint = endpoint;
+                }
+                return Ok(result);
+            }
+            Err(ProjError::Cancelled) => return Err(ProjError::Cancelled),
+            Err(e) => error = Some(e),
+        }
+    }
+    Err(error.expect("there's at least one candidate URL"))
+}
+
+/// The headers and body of a range of a file
+struct RangeResponse {
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+    total: Option<u64>,
+    // false if the range was revalidated, rather than downloaded
+    downloaded: bool,
+}
+
+/// Download `size_to_read` bytes of a file from `offset`, or revalidate them if they were
+/// downloaded before
+fn fetch_range(
+    url: &str,
+    offset: c_ulonglong,
+    size_to_read: usize,
+    settings: Option<&RefCell<NetworkSettings>>,
+) -> Result<RangeResponse, ProjError> {
+    // - 1 is used because the HTTP convention is to use inclusive start and end offsets
+    let end = offset as usize + size_to_read - 1;
+    // RANGE header definition is "bytes=x-y"
+    let hvalue = format!("bytes={offset}-{end}");
+    let key = (url.to_string(), hvalue);
+    let clt = client(settings)?;
+    let cached =
+        settings.and_then(|settings| settings.try_borrow().ok()?.revalidation.get(&key).cloned());
+    with_failover(url, settings, |url| {
+        let mut req = clt
+            .agent
+            .get(url)
+            .set("Range", &key.1)
+            .set("Client", CLIENT);
+        if let Some(cached) = &cached {
+            req = cached.revalidate(req);
+        }
+        let res = fetch(req, &clt, settings)?;
+        let headers: HashMap<_, _> = res
+            .headers_names()
+            .into_iter()
+            .filter_map(|h| {
+                Some({
+                    let v = res.header(&h)?.to_string();
+                    (h, v)
+                })
+            })
+            .collect();
+        if let (NOT_MODIFIED, Some(mut cached)) = (res.status(), cached.clone()) {
+            // the headers of the response supersede the cached ones
+            cached.headers.extend(headers);
+            if let Some(Ok(mut settings)) = settings.map(RefCell::try_borrow_mut) {
+                settings.metrics.revalidated += 1;
+                settings.revalidation.insert(key.clone(), cached.clone());
+            }
+            return Ok(RangeResponse {
+                headers: cached.headers,
+                body: cached.body,
+                total: cached.total,
+                downloaded: false,
+            });
+        }
+        let total = total_size(&res);
+        if !matches!(
+            res.header("Content-Length").map(str::parse::<usize>),
+            Some(Ok(_))
+        ) {
+            return Err(ProjError::ContentLength);
+        }
+        let body = clt.read_body(res, size_to_read as u64)?;
+        let range = CachedRange {
+            headers,
+            body,
+            total,
+        };
+        if let Some(Ok(mut settings)) = settings.map(RefCell::try_borrow_mut) {
+            settings.revalidation.insert(key.clone(), range.clone());
+        }
+        Ok(RangeResponse {
+            headers: range.headers,
+            body: range.body,
+            total: range.total,
+            downloaded: true,
+        })
+    })
+}
+
+/// Network callback: open
+///
+/// Should try to read the `size_to_read` first bytes at the specified offset of the file given by
+/// URL url, and write them to `buffer`. `out_size_read` should be updated with the actual amount
+/// of bytes read (== `size_to_read` if the file is larger than `size_to_read`). During this read,
+/// the implementation should make sure to store the HTTP headers from the server response to be
+/// able to respond to `proj_network_get_header_value_cbk_type` callback.
+/// `error_string_max_size` should be the maximum size that can be written into the `out_error_string`
+/// buffer (including terminating nul character).
+///
+/// Note that this function is a facade for _network_open
+pub(crate) unsafe extern "C" fn network_open(
+    pc: *mut PJ_CONTEXT,
+    url: *const c_char,
+    offset: c_ulonglong,
+    size_to_read: usize,
+    buffer: *mut c_void,
+    out_size_read: *mut usize,
+    error_string_max_size: usize,
+    out_error_string: *mut c_char,
+    ud: *mut c_void,
+) -> *mut PROJ_NETWORK_HANDLE {
+    match _network_open(
+        pc,
+        url,
+        offset,
+        size_to_read,
+        buffer,
+        out_size_read,
+        error_string_max_size,
+        out_error_string,
+        ud,
+    ) {
+        Ok(res) => res,
+        Err(e) => {
+            fail(e, error_string_max_size, out_error_string);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Where the ACTUAL work happens, taking advantage of Rust error-handling etc
+#[allow(clippy::too_many_arguments)]
+unsafe fn _network_open(
+    _: *mut PJ_CONTEXT,
+    url: *const c_char,
+    offset: c_ulonglong,
+    size_to_read: usize,
+    buffer: *mut c_void,
+    out_size_read: *mut usize,
+    _: usize,
+    out_error_string: *mut c_char,
+    ud: *mut c_void,
+) -> Result<*mut PROJ_NETWORK_HANDLE, ProjError> {
+    let url = _string(url)?;
+    let settings = settings(ud);
+    let range = fetch_range(&url, offset, size_to_read, settings)?;
+    let buf = range.body;
+    // Write the read length value into the pointer, and copy the bytes into the buffer
+    out_size_read.write(buf.len());
+    buf.as_ptr()
+        .copy_to_nonoverlapping(buffer.cast(), buf.len());
+    let mut hd = HandleData::new(url, range.headers, None);
+    hd.fetched = buf.len() as u64;
+    let downloaded = if range.downloaded { buf.len() } else { 0 };
+    record_download(settings, &hd.url, downloaded, hd.fetched, range.total);
+    // heap-allocate the struct and cast it to a void pointer so it can be passed around to PROJ
+    let hd_boxed = Box::new(hd);
+    let void: *mut c_void = Box::into_raw(hd_boxed).cast::<libc::c_void>();
+    let opaque: *mut PROJ_NETWORK_HANDLE = void.cast::<proj_sys::PROJ_NETWORK_HANDLE>();
+    // If everything's OK, set the error string to empty
+    let err_string = "";
+    out_error_string.copy_from_nonoverlapping(err_string.as_ptr().cast(), err_string.len());
+    out_error_string.add(err_string.len()).write(0);
+    Ok(opaque)
+}
+
+/// Network callback: close connection and drop handle data (client and headers)
+pub(crate) unsafe extern "C" fn network_close(
+    _: *mut PJ_CONTEXT,
+    handle: *mut PROJ_NETWORK_HANDLE,
+    _: *mut c_void,
+) {
+    // Because we created the raw pointer from a Box, we have to re-constitute the Box
+    // This is the exact reverse order seen in _network_open
+    let void = handle.cast::<libc::c_void>();
+    let hd = void.cast::<HandleData>();
+    let _: Box<HandleData> = Box::from_raw(hd);
+}
+
+/// Network callback: get header value
+///
+/// Note that this function is a facade for _network_get_header_value
+pub(crate) unsafe extern "C" fn network_get_header_value(
+    pc: *mut PJ_CONTEXT,
+    handle: *mut PROJ_NETWORK_HANDLE,
+    header_name: *const c_char,
+    ud: *mut c_void,
+) -> *const c_char {
+    let hd = &mut *(handle as *const c_void as *mut HandleData);
+    match _network_get_header_value(pc, handle, header_name, ud) {
+        Ok(res) => res,
+        Err(e) => {
+            CALLBACK_ERROR.with(|slot| *slot.borrow_mut() = Some(e));
+            // an empty value will cause an error upstream in libproj, which is the intention
+            let hvalue = "";
+            // unwrapping an empty str is fine
+            let cstr = CString::new(hvalue).unwrap();
+            let err = cstr.into_raw();
+            hd.hptr = Some(NonNull::new(err).expect("Failed to create non-Null pointer"));
+            err
+        }
+    }
+}
+
+/// Network callback: get header value
+unsafe fn _network_get_header_value(
+    _: *mut PJ_CONTEXT,
+    handle: *mut PROJ_NETWORK_HANDLE,
+    header_name: *const c_char,
+    _: *mut c_void,
+) -> Result<*const c_char, ProjError> {
+    let lookup = _string(header_name)?.to_lowercase();
+    let void = handle.cast::<libc::c_void>();
+    let hd = &mut *(void.cast::<HandleData>());
+    let hvalue = hd
+        .headers
+        .get(&lookup)
+        .ok_or_else(|| ProjError::HeaderError(lookup.to_string()))?;
+    let cstr = CString::new(&**hvalue).unwrap();
+    let header = cstr.into_raw();
+    // Raw pointers are Copy: the pointer returned by this function is never returned by libproj so
+    // in order to avoid a memory leak the pointer is copied and stored in the HandleData struct,
+    // which is dropped when close_network returns. As part of that drop, the pointer in hptr is returned to Rust
+    hd.hptr = Some(
+        NonNull::new(header).expect("Failed to create non-Null pointer when building header value"),
+    );
+    Ok(header)
+}
+
+/// Network: read range
+///
+/// Read size_to_read bytes from handle, starting at `offset`, into `buffer`. During this read,
+/// the implementation should make sure to store the HTTP headers from the server response to be
+/// able to respond to `proj_network_get_header_value_cbk_type` callback.
+/// `error_string_max_size` should be the maximum size that can be written into the
+/// `out_error_string` buffer (including terminating nul character).
+///
+/// Return value should be the actual number of bytes read, 0 in case of error.
+///
+/// Note that this function is a facade for _network_read_range
+pub(crate) unsafe extern "C" fn network_read_range(
+    pc: *mut PJ_CONTEXT,
+    handle: *mut PROJ_NETWORK_HANDLE,
+    offset: c_ulonglong,
+    size_to_read: usize,
+    buffer: *mut c_void,
+    error_string_max_size: usize,
+    out_error_string: *mut c_char,
+    ud: *mut c_void,
+) -> usize {
+    match _network_read_range(
+        pc,
+        handle,
+        offset,
+        size_to_read,
+        buffer,
+        error_string_max_size,
+        out_error_string,
+        ud,
+    ) {
+        Ok(res) => res,
+        Err(e) => {
+            // The assumption here is that if 0 is returned, whatever error is in out_error_string is displayed by libproj
+            fail(e, error_string_max_size, out_error_string);
+            0usize
+        }
+    }
+}
+
+/// Where the ACTUAL work happens
+#[allow(clippy::too_many_arguments)]
+fn _network_read_range(
+    _: *mut PJ_CONTEXT,
+    handle: *mut PROJ_NETWORK_HANDLE,
+    offset: c_ulonglong,
+    size_to_read: usize,
+    buffer: *mut c_void,
+    _: usize,
+    out_error_string: *mut c_char,
+    ud: *mut c_void,
+) -> Result<usize, ProjError> {
+    let hd = unsafe { &mut *(handle as *const c_void as *mut HandleData) };
+    let settings = unsafe { settings(ud) };
+    let range = fetch_range(&hd.url, offset, size_to_read, settings)?;
+    let buf = range.body;
+    // Copy the bytes into the buffer so it can be passed around
+    unsafe {
+        buf.as_ptr()
+            .copy_to_nonoverlapping(buffer.cast::<u8>(), buf.len());
+    }
+    let err_string = "";
+    unsafe {
+        out_error_string.copy_from_nonoverlapping(err_string.as_ptr().cast(), err_string.len());
+        out_error_string.add(err_string.len()).write(0);
+    }
+    hd.headers = range.headers;
+    hd.fetched += buf.len() as u64;
+    let downloaded = if range.downloaded { buf.len() } else { 0 };
+    record_download(settings, &hd.url, downloaded, hd.fetched, range.total);
+    Ok(buf.len())
+}
+
+/// Download a complete file, streaming it to `dest`, which is removed if the download fails
+pub(crate) fn download_file(
+    settings: &RefCell<NetworkSettings>,
+    url: &str,
+    dest: &Path,
+) -> Result<(), ProjError> {
+    let clt = client(Some(settings))?;
+    let result = with_failover(url, Some(settings), |url| {
+        let req = clt.agent.get(url).set("Client", CLIENT);
+        let res = fetch(req, &clt, Some(settings))?;
+        // an attempt on another endpoint starts from an empty file
+        let mut file = BufWriter::new(File::create(dest)?);
+        let len = clt.copy_body(res, u64::MAX, &mut file)?;
+        file.flush()?;
+        Ok(len)
+    });
+    match result {
+        Ok(len) => {
+            record_download(Some(settings), url, len as usize, len, Some(len));
+            Ok(())
+        }
+        Err(e) => {
+            let _ = fs::remove_file(dest);
+            Err(e)
+        }
+    }
+}
+
+/// Set up and initialise the grid download callback functions for all subsequent PROJ contexts
+///
+/// `ud` must point to the context's `RefCell<NetworkSettings>`, which must outlive it.
+pub(crate) fn set_network_callbacks(ctx: *mut PJ_CONTEXT, ud: *mut c_void) -> i32 {
+    unsafe {
+        proj_context_set_network_callbacks(
+            ctx,
+            Some(network_open),
+            Some(network_close),
+            Some(network_get_header_value),
+            Some(network_read_range),
+            ud,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(0, Duration::ZERO), None);
+        assert_eq!(
+            policy.delay(1, Duration::ZERO),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.delay(8, Duration::ZERO),
+            Some(Duration::from_millis(6400))
+        );
+        assert_eq!(policy.delay(9, Duration::ZERO), None);
+
+        let policy = RetryPolicy {
+            max_elapsed: Some(Duration::from_millis(500)),
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.delay(2, Duration::from_millis(100)),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(policy.delay(3, Duration::from_millis(500)), None);
+    }
+
+    #[test]
+    fn test_error_handler_without_retries() {
+        let policy = RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let request = Agent::new().get("http://localhost/grid.tif");
+        let mut res = Response::new(503, "Service Unavailable", "").unwrap();
+        assert!(matches!(
+            error_handler(&mut res, request.clone(), &policy, None),
+            Err(ProjError::DownloadError(_, _, 0))
+        ));
+        let mut res = Response::new(404, "Not Found", "").unwrap();
+        assert!(matches!(
+            error_handler(&mut res, request.clone(), &policy, None),
+            Err(ProjError::DownloadError(_, _, 0))
+        ));
+        let mut res = Response::new(206, "Partial Content", "").unwrap();
+        assert_eq!(error_handler(&mut res, request, &policy, None).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_record_download() {
+        let settings = RefCell::new(NetworkSettings::default());
+        let reported = Rc::new(RefCell::new(vec![]));
+        let progress = reported.clone();
+        settings.borrow_mut().progress = Some(Rc::new(move |p: &DownloadProgress| {
+            progress.borrow_mut().push((p.bytes_fetched, p.total))
+        }));
+        record_download(
+            Some(&settings),
+            "http://localhost/grid.tif",
+            10,
+            10,
+            Some(30),
+        );
+        record_download(Some(&settings), "http://localhost/grid.tif", 5, 15, None);
+        assert_eq!(settings.borrow().metrics.bytes_downloaded, 15);
+        assert_eq!(*reported.borrow(), vec![(10, Some(30)), (15, None)]);
+    }
+
+    #[test]
+    fn test_revalidation() {
+        // a server which sends a range, then only answers conditional requests with 304
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/grid.tif", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut conditional = vec![];
+            for response in [
+                "HTTP/1.1 206 Partial Content\r\nContent-Length: 4\r\n\
+                 Content-Range: bytes 0-3/100\r\nETag: \"v1\"\r\n\r\ngrid",
+                "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\n\r\n",
+            ] {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = vec![0; 4096];
+                let len = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..len]).to_lowercase();
+                conditional.push(request.contains("if-none-match: \"v1\""));
+                std::io::Write::write_all(&mut stream, response.as_bytes()).unwrap();
+            }
+            conditional
+        });
+        let settings = RefCell::new(NetworkSettings::default());
+        let first = fetch_range(&url, 0, 4, Some(&settings)).unwrap();
+        let second = fetch_range(&url, 0, 4, Some(&settings)).unwrap();
+        assert!(first.downloaded && !second.downloaded);
+        assert_eq!(second.body, b"grid");
+        assert_eq!(second.total, Some(100));
+        assert_eq!(second.headers["content-range"], "bytes 0-3/100");
+        assert_eq!(server.join().unwrap(), vec![false, true]);
+        let metrics = settings.borrow().metrics;
+        assert_eq!((metrics.requests, metrics.revalidated), (2, 1));
+
+        let mut cache = RevalidationCache::new(6);
+        let range = |body: &[u8]| CachedRange {
+            headers: iter::once(("etag".to_string(), "\"v1\"".to_string())).collect(),
+            body: body.to_vec(),
+            total: None,
+        };
+        let key = |r: &str| (url.clone(), r.to_string());
+        cache.insert(key("a"), range(b"abc"));
+        cache.insert(key("b"), range(b"def"));
+        // the oldest range is evicted
+        cache.insert(key("c"), range(b"ghi"));
+        assert!(cache.get(&key("a")).is_none());
+        assert!(cache.get(&key("c")).is_some());
+        // ranges without validators aren't kept
+        cache.insert(
+            key("d"),
+            CachedRange {
+                headers: HashMap::new(),
+                ..range(b"")
+            },
+        );
+        assert!(cache.get(&key("d")).is_none());
+        cache.set_max_size(0);
+        assert_eq!(
+            (cache.size, cache.ranges.len(), cache.order.len()),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_failover() {
+        let mut settings = NetworkSettings::default();
+        let url = "https://cdn.proj.org/uk_os_OSTN15_NTv2_OSGBtoETRS.tif";
+        assert_eq!(settings.candidates(url), vec![(None, url.to_string())]);
+        settings.set_endpoints(&["https://cdn.proj.org", "https://mirror.example.com/proj/"]);
+        settings.active_endpoint = 1;
+        assert_eq!(
+            settings.candidates(url),
+            vec![
+                (
+                    Some(1),
+                    "https://mirror.example.com/proj/uk_os_OSTN15_NTv2_OSGBtoETRS.tif".to_string()
+                ),
+                (Some(0), url.to_string()),
+            ]
+        );
+        // only the URLs of the primary endpoint are redirected
+        let other = "https://cdn.proj.org.example.com/grid.tif";
+        assert_eq!(settings.candidates(other), vec![(None, other.to_string())]);
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mirror = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![0; 4096];
+            let len = stream.read(&mut request).unwrap();
+            let response = "HTTP/1.1 206 Partial Content\r\nContent-Length: 4\r\n\
+                            Content-Range: bytes 0-3/100\r\n\r\ngrid";
+            std::io::Write::write_all(&mut stream, response.as_bytes()).unwrap();
+            String::from_utf8_lossy(&request[..len]).into_owned()
+        });
+        let settings = RefCell::new(NetworkSettings::default());
+        settings.borrow_mut().retry.max_retries = 0;
+        // nothing listens on the discard port of the primary endpoint
+        settings
+            .borrow_mut()
+            .set_endpoints(&["http://127.0.0.1:9", &mirror]);
+        let range = fetch_range("http://127.0.0.1:9/grid.tif", 0, 4, Some(&settings)).unwrap();
+        assert_eq!(range.body, b"grid");
+        assert!(server.join().unwrap().starts_with("GET /grid.tif "));
+        let settings = settings.borrow();
+        assert_eq!(settings.active_endpoint, 1);
+        assert_eq!(
+            (settings.metrics.failures, settings.metrics.failovers),
+            (1, 1)
+        );
+    }
+
+    #[test]
+    fn test_total_size() {
+        let res = "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 0-16383/2847293\r\n\r\n"
+            .parse::<Response>()
+            .unwrap();
+        assert_eq!(total_size(&res), Some(2847293));
+        let res = Response::new(200, "OK", "").unwrap();
+        assert_eq!(total_size(&res), None);
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn test_download_file() {
+        let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data");
+        let server = crate::test_util::GridServer::new(&data).unwrap();
+        let settings = RefCell::new(NetworkSettings::default());
+        let dest = std::env::temp_dir().join(format!("proj-rs-download-{}", std::process::id()));
+        let url = format!("{}/test_nodata.gtx", server.url());
+        download_file(&settings, &url, &dest).unwrap();
+        assert_eq!(
+            fs::read(&dest).unwrap(),
+            fs::read(data.join("test_nodata.gtx")).unwrap()
+        );
+        let len = fs::metadata(&dest).unwrap().len();
+        assert_eq!(settings.borrow().metrics.bytes_downloaded, len);
+        // a failed download doesn't leave a partial file behind
+        let url = format!("{}/no_such_grid.tif", server.url());
+        assert!(download_file(&settings, &url, &dest).is_err());
+        assert!(!dest.exists());
+    }
+}
@@ -0,0 +1,9 @@
+//! Coordinate operations: transformations between CRSs, projections and conversions
+pub use crate::coord::{Direction, ProjCoord};
+pub use crate::parameters::{OperationParameter, ProjectionConversion};
+pub use crate::proj::{
+    Accuracy, Area, Coord, OperationGrids, Proj, ProjBuilder, ProjInfo, Uncertainty,
+};
+pub use crate::transform::{Transform, TransformError};
+pub use crate::transformer::{CoordTransform, Transformer};
+pub use crate::typed::Transformation;
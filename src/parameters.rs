@@ -0,0 +1,305 @@
+//! The parameters of coordinate operations, as recorded in their metadata
+use libc::c_char;
+use proj_sys::{
+    proj_concatoperation_get_step, proj_concatoperation_get_step_count,
+    proj_coordoperation_get_method_info, proj_coordoperation_get_param,
+    proj_coordoperation_get_param_count, proj_crs_get_coordoperation, proj_get_id_auth_name,
+    proj_get_id_code, proj_get_name, proj_get_type, proj_is_crs,
+    proj_trans_get_last_used_operation, PJ, PJ_TYPE_PJ_TYPE_CONCATENATED_OPERATION,
+};
+use std::ptr;
+
+use crate::proj::_string;
+use crate::{Context, PjPtrExt, Proj};
+
+/// A parameter of a coordinate operation, such as the longitude of the natural origin of a
+/// projection, or a translation of a Helmert transformation
+///
+/// Obtained using [`Proj::operation_parameters`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationParameter {
+    /// The name of the operation the parameter belongs to, such as `"UTM zone 31N"`
+    pub operation: Option<String>,
+    /// The name of the parameter, such as `"Longitude of natural origin"`
+    pub name: String,
+    /// The authority defining the parameter, such as `"EPSG"`
+    pub authority: Option<String>,
+    /// The code of the parameter in its authority, such as `"8802"`
+    pub code: Option<String>,
+    /// The numeric value of the parameter, in its unit, or `0.0` if it isn't numeric
+    pub value: f64,
+    /// The value of a parameter which isn't numeric, such as the name of a grid file
+    pub value_string: Option<String>,
+    /// The name of the unit of the value, such as `"degree"`
+    pub unit_name: Option<String>,
+    /// The factor converting the value to the SI unit of its category, such as
+    /// `0.0174532925199433` for degrees, or `0.0` if it has no unit
+    pub unit_conversion_factor: f64,
+    /// The authority defining the unit
+    pub unit_authority: Option<String>,
+    /// The code of the unit in its authority
+    pub unit_code: Option<String>,
+    /// The category of the unit, such as `"linear"`, `"angular"`, `"scale"` or `"time"`
+    pub unit_category: Option<String>,
+}
+
+/// The conversion a projected or other derived CRS is defined by, such as a map projection
+///
+/// Obtained using [`Proj::projection_conversion`]. Unlike [`Conversion`](crate::Conversion),
+/// which describes the projections of the CRSs built by [`CrsBuilder`](crate::CrsBuilder), any
+/// method known to PROJ can be described.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectionConversion {
+    /// The name of the conversion, such as `"UTM zone 31N"`
+    pub name: Option<String>,
+    /// The authority defining the conversion, such as `"EPSG"`
+    pub authority: Option<String>,
+    /// The code of the conversion in its authority, such as `"16031"`
+    pub code: Option<String>,
+    /// The name of the method of the conversion, such as `"Transverse Mercator"`
+    pub method_name: String,
+    /// The authority defining the method, such as `"EPSG"`
+    pub method_authority: Option<String>,
+    /// The code of the method in its authority, such as `"9807"`
+    pub method_code: Option<String>,
+    /// The parameters of the conversion, with their values and units
+    pub parameters: Vec<OperationParameter>,
+}
+
+impl Proj {
+    /// The conversion a projected or other derived CRS is defined by, with its method and
+    /// parameters, or `None` if this isn't a derived CRS
+    ///
+    /// This tells cartographic software which projection a CRS uses without parsing its WKT.
+    ///
+    /// ```rust
+    /// use proj::Proj;
+    ///
+    /// let conversion = Proj::new("EPSG:32631").unwrap().projection_conversion().unwrap();
+    /// assert_eq!(conversion.method_name, "Transverse Mercator");
+    /// assert_eq!(conversion.method_code.as_deref(), Some("9807"));
+    /// assert_eq!(conversion.code.as_deref(), Some("16031"));
+    /// let scale_factor = &conversion.parameters[2];
+    /// assert_eq!(scale_factor.name, "Scale factor at natural origin");
+    /// assert_eq!(scale_factor.value, 0.9996);
+    ///
+    /// assert!(Proj::new("EPSG:4326").unwrap().projection_conversion().is_none());
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn projection_conversion(&self) -> Option<ProjectionConversion> {
+        let ctx = self.context();
+        unsafe {
+            if proj_is_crs(self.as_ptr()) != 1 {
+                return None;
+            }
+            // this returns null without setting an error if the CRS isn't derived
+            let op = proj_crs_get_coordoperation(ctx.as_ptr(), self.as_ptr());
+            if op.is_null() {
+                return None;
+            }
+            let op = op.into_pj_ref(ctx).ok()?;
+            let mut method_name = ptr::null();
+            let mut method_authority = ptr::null();
+            let mut method_code = ptr::null();
+            proj_coordoperation_get_method_info(
+                ctx.as_ptr(),
+                op.as_ptr(),
+                &mut method_name,
+                &mut method_authority,
+                &mut method_code,
+            );
+            let mut parameters = vec![];
+            add_parameters(ctx, op.as_ptr(), &mut parameters);
+            Some(ProjectionConversion {
+                name: optional(proj_get_name(op.as_ptr())),
+                authority: optional(proj_get_id_auth_name(op.as_ptr(), 0)),
+                code: optional(proj_get_id_code(op.as_ptr(), 0)),
+                method_name: optional(method_name)?,
+                method_authority: optional(method_authority),
+                method_code: optional(method_code),
+                parameters,
+            })
+        }
+    }
+
+    /// The parameters of the coordinate operation, with their values and units
+    ///
+    /// The parameters of every step of a concatenated operation are returned, in order. For a
+    /// projected or other derived CRS, the parameters are those of the conversion it is derived
+    /// with. When the operation was created between two CRSs and has several candidate
+    /// operations, the parameters are those of the operation used for the last coordinate
+    /// converted, or none if no coordinate has been converted yet. Operations only defined by
+    /// a PROJ string have no parameters.
+    ///
+    /// ```rust
+    /// use proj::Proj;
+    ///
+    /// let utm31n = Proj::new("EPSG:32631").unwrap();
+    /// let parameters = utm31n.operation_parameters();
+    /// let central_meridian = parameters
+    ///     .iter()
+    ///     .find(|parameter| parameter.code.as_deref() == Some("8802"))
+    ///     .unwrap();
+    /// assert_eq!(central_meridian.name, "Longitude of natural origin");
+    /// assert_eq!(central_meridian.value, 3.0);
+    /// assert_eq!(central_meridian.unit_name.as_deref(), Some("degree"));
+    ///
+    /// // ED50 to WGS 84 (18), a Position Vector transformation
+    /// let ed50_to_wgs84 = Proj::new("urn:ogc:def:coordinateOperation:EPSG::1311").unwrap();
+    /// let translations: Vec<f64> = ed50_to_wgs84
+    ///     .operation_parameters()
+    ///     .iter()
+    ///     .take(3)
+    ///     .map(|parameter| parameter.value)
+    ///     .collect();
+    /// assert_eq!(translations, [-89.5, -93.8, -123.1]);
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn operation_parameters(&self) -> Vec<OperationParameter> {
+        let ctx = self.context();
+        let mut parameters = vec![];
+        unsafe {
+            let is_crs = proj_is_crs(self.as_ptr()) == 1;
+            // these return null without setting an error if there is no such operation
+            let op = if is_crs {
+                proj_crs_get_coordoperation(ctx.as_ptr(), self.as_ptr())
+            } else {
+                proj_trans_get_last_used_operation(self.as_ptr())
+            };
+            if op.is_null() {
+                if !is_crs {
+                    add_parameters(ctx, self.as_ptr(), &mut parameters);
+                }
+            } else if let Ok(op) = op.into_pj_ref(ctx) {
+                add_parameters(ctx, op.as_ptr(), &mut parameters);
+            }
+        }
+        parameters
+    }
+}
+
+/// Add the parameters of an operation, or of each step of a concatenated operation, to
+/// `parameters`
+unsafe fn add_parameters(ctx: &Context, op: *const PJ, parameters: &mut Vec<OperationParameter>) {
+    if proj_get_type(op) == PJ_TYPE_PJ_TYPE_CONCATENATED_OPERATION {
+        for i in 0..proj_concatoperation_get_step_count(ctx.as_ptr(), op) {
+            let step = proj_concatoperation_get_step(ctx.as_ptr(), op, i);
+            if step.is_null() {
+                continue;
+            }
+            if let Ok(step) = step.into_pj_ref(ctx) {
+                add_parameters(ctx, step.as_ptr(), parameters);
+            }
+        }
+        return;
+    }
+    let operation = optional(proj_get_name(op));
+    for i in 0..proj_coordoperation_get_param_count(ctx.as_ptr(), op) {
+        let mut name = ptr::null();
+        let mut authority = ptr::null();
+        let mut code = ptr::null();
+        let mut value = 0.0;
+        let mut value_string = ptr::null();
+        let mut unit_conversion_factor = 0.0;
+        let mut unit_name = ptr::null();
+        let mut unit_authority = ptr::null();
+        let mut unit_code = ptr::null();
+        let mut unit_category = ptr::null();
+        let found = proj_coordoperation_get_param(
+            ctx.as_ptr(),
+            op,
+            i,
+            &mut name,
+            &mut authority,
+            &mut code,
+            &mut value,
+            &mut value_string,
+            &mut unit_conversion_factor,
+            &mut unit_name,
+            &mut unit_authority,
+            &mut unit_code,
+            &mut unit_category,
+        );
+        let Some(name) = optional(name).filter(|_| found == 1) else {
+            continue;
+        };
+        parameters.push(OperationParameter {
+            operation: operation.clone(),
+            name,
+            authority: optional(authority),
+            code: optional(code),
+            value,
+            value_string: optional(value_string),
+            unit_name: optional(unit_name),
+            unit_conversion_factor,
+            unit_authority: optional(unit_authority),
+            unit_code: optional(unit_code),
+            unit_category: optional(unit_category),
+        });
+    }
+}
+
+/// A string returned by PROJ, or `None` if it is null or empty
+///
+/// # Safety
+/// `s` must be null or point to a nul-terminated string.
+unsafe fn optional(s: *const c_char) -> Option<String> {
+    if s.is_null() {
+        None
+    } else {
+        _string(s).ok().filter(|s| !s.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_operation_parameters() {
+        // a geographic CRS has no deriving conversion
+        assert!(Proj::new("EPSG:4326")
+            .unwrap()
+            .operation_parameters()
+            .is_empty());
+        let parameters = Proj::new("EPSG:32631").unwrap().operation_parameters();
+        assert_eq!(parameters.len(), 5);
+        assert!(parameters
+            .iter()
+            .all(|parameter| parameter.operation.as_deref() == Some("UTM zone 31N")));
+        let false_easting = &parameters[3];
+        assert_eq!(false_easting.name, "False easting");
+        assert_eq!(false_easting.value, 500000.0);
+        assert_eq!(false_easting.unit_category.as_deref(), Some("linear"));
+        assert_eq!(false_easting.unit_conversion_factor, 1.0);
+        // the operation used for the last coordinate
+        let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+        ft_to_m.convert((4760096.421921, 3744293.729449)).unwrap();
+        let parameters = ft_to_m.operation_parameters();
+        assert!(parameters
+            .iter()
+            .any(|parameter| parameter.code.as_deref() == Some("8823")));
+        // a CRS's conversion
+        let conversion = Proj::new("EPSG:3857")
+            .unwrap()
+            .projection_conversion()
+            .unwrap();
+        assert_eq!(
+            conversion.method_name,
+            "Popular Visualisation Pseudo Mercator"
+        );
+        assert_eq!(conversion.method_code.as_deref(), Some("1024"));
+        assert_eq!(conversion.parameters.len(), 4);
+        // operations aren't CRSs
+        assert!(ft_to_m.projection_conversion().is_none());
+        // PROJ strings have no metadata
+        assert!(Proj::new("+proj=merc")
+            .unwrap()
+            .operation_parameters()
+            .is_empty());
+    }
+}
@@ -0,0 +1,1103 @@
+//! Programmatic construction of PROJ [pipelines](https://proj.org/operations/pipeline.html)
+use proj_sys::{
+    proj_create, proj_destroy, proj_get_name, proj_get_source_crs, proj_get_target_crs,
+    proj_is_crs, proj_is_equivalent_to_with_ctx,
+    PJ_COMPARISON_CRITERION_PJ_COMP_EQUIVALENT_EXCEPT_AXIS_ORDER_GEOGCRS,
+};
+use std::ffi::CString;
+use std::fmt::{self, Display};
+use std::iter;
+
+use crate::db::unit_short_names;
+use crate::proj::_string;
+use crate::{PjPtrExt, Proj, ProjBuilder, ProjCreateError};
+
+/// An operation used as a pipeline step
+///
+/// Angles are in degrees, and lengths in metres unless stated otherwise. Operations which don't
+/// have a variant can be described using [`Custom`](#variant.Custom).
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProjOp {
+    /// Reorder, and optionally negate, the axes: `[2, 1]` swaps the first two axes
+    AxisSwap { order: Vec<i8> },
+    /// Convert horizontal units, such as `"deg"` to `"rad"`, or `"us-ft"` to `"m"`
+    UnitConvert { xy_in: String, xy_out: String },
+    /// Lambert Conformal Conic
+    Lcc {
+        lat_0: f64,
+        lon_0: f64,
+        lat_1: f64,
+        lat_2: f64,
+        x_0: f64,
+        y_0: f64,
+        ellps: String,
+    },
+    /// Transverse Mercator
+    TransverseMercator {
+        lat_0: f64,
+        lon_0: f64,
+        k_0: f64,
+        x_0: f64,
+        y_0: f64,
+        ellps: String,
+    },
+    /// Universal Transverse Mercator
+    Utm {
+        zone: u8,
+        south: bool,
+        ellps: String,
+    },
+    /// Web Mercator, on the WGS 84 ellipsoid
+    WebMercator,
+    /// Conversion between geodetic and geocentric cartesian coordinates
+    Cart { ellps: String },
+    /// Seven-parameter Helmert transformation, with translations in metres, rotations in arc
+    /// seconds and the scale difference in parts per million
+    Helmert {
+        x: f64,
+        y: f64,
+        z: f64,
+        rx: f64,
+        ry: f64,
+        rz: f64,
+        s: f64,
+        /// `"position_vector"` or `"coordinate_frame"`
+        convention: String,
+    },
+    /// Horizontal grid shift, using a comma-separated list of grids
+    HGridShift { grids: String },
+    /// Vertical grid shift, using a comma-separated list of grids
+    VGridShift { grids: String, multiplier: f64 },
+    /// Any operation, with its parameters: a parameter without a value is a flag, such as
+    /// `south`
+    Custom {
+        name: String,
+        params: Vec<(String, Option<String>)>,
+    },
+}
+
+impl ProjOp {
+    /// The name and parameters of the operation
+    fn params(&self) -> (&str, Vec<(&str, Option<String>)>) {
+        fn num(value: f64) -> Option<String> {
+            Some(value.to_string())
+        }
+        fn text(value: &str) -> Option<String> {
+            Some(value.to_string())
+        }
+        match self {
+            ProjOp::AxisSwap { order } => {
+                let order = order
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                ("axisswap", vec![("order", Some(order))])
+            }
+            ProjOp::UnitConvert { xy_in, xy_out } => (
+                "unitconvert",
+                vec![("xy_in", text(xy_in)), ("xy_out", text(xy_out))],
+            ),
+            ProjOp::Lcc {
+                lat_0,
+                lon_0,
+                lat_1,
+                lat_2,
+                x_0,
+                y_0,
+                ellps,
+            } => (
+                "lcc",
+                vec![
+                    ("lat_0", num(*lat_0)),
+                    ("lon_0", num(*lon_0)),
+                    ("lat_1", num(*lat_1)),
+                    ("lat_2", num(*lat_2)),
+                    ("x_0", num(*x_0)),
+                    ("y_0", num(*y_0)),
+                    ("ellps", text(ellps)),
+                ],
+            ),
+            ProjOp::TransverseMercator {
+                lat_0,
+                lon_0,
+                k_0,
+                x_0,
+                y_0,
+                ellps,
+            } => (
+                "tmerc",
+                vec![
+                    ("lat_0", num(*lat_0)),
+                    ("lon_0", num(*lon_0)),
+                    ("k_0", num(*k_0)),
+                    ("x_0", num(*x_0)),
+                    ("y_0", num(*y_0)),
+                    ("ellps", text(ellps)),
+                ],
+            ),
+            ProjOp::Utm { zone, south, ellps } => {
+                let mut params = vec![("zone", Some(zone.to_string()))];
+                if *south {
+                    params.push(("south", None));
+                }
+                params.push(("ellps", text(ellps)));
+                ("utm", params)
+            }
+            ProjOp::WebMercator => ("webmerc", vec![("ellps", text("WGS84"))]),
+            ProjOp::Cart { ellps } => ("cart", vec![("ellps", text(ellps))]),
+            ProjOp::Helmert {
+                x,
+                y,
+                z,
+                rx,
+                ry,
+                rz,
+                s,
+                convention,
+            } => (
+                "helmert",
+                vec![
+                    ("x", num(*x)),
+                    ("y", num(*y)),
+                    ("z", num(*z)),
+                    ("rx", num(*rx)),
+                    ("ry", num(*ry)),
+                    ("rz", num(*rz)),
+                    ("s", num(*s)),
+                    ("convention", text(convention)),
+                ],
+            ),
+            ProjOp::HGridShift { grids } => ("hgridshift", vec![("grids", text(grids))]),
+            ProjOp::VGridShift { grids, multiplier } => (
+                "vgridshift",
+                vec![("grids", text(grids)), ("multiplier", num(*multiplier))],
+            ),
+            ProjOp::Custom { name, params } => (
+                name,
+                params
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.clone()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Return whether `name` can be used as an operation or parameter name
+fn valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name
+            .chars()
+            .any(|c| c.is_whitespace() || c == '=' || c == '+' || c == '"')
+}
+
+/// Quote a parameter value if needed, doubling its quotes
+fn escape(value: &str) -> String {
+    if value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Return the PROJ string definition of a single operation, whose names are known to be valid
+fn op_definition(op: &ProjOp) -> String {
+    let (name, params) = op.params();
+    let mut definition = format!("+proj={}", name);
+    for (key, value) in params {
+        match value {
+            Some(value) => definition.push_str(&format!(" +{}={}", key, escape(&value))),
+            None => definition.push_str(&format!(" +{}", key)),
+        }
+    }
+    definition
+}
+
+/// A builder of PROJ pipeline definitions
+///
+/// Parameter values are formatted and quoted as needed, so pipelines can be composed without
+/// writing PROJ strings by hand:
+///
+/// ```rust
+/// # use approx::assert_relative_eq;
+/// use proj::{PipelineBuilder, ProjOp};
+///
+/// let lcc = |x_0, y_0| ProjOp::Lcc {
+///     lat_0: 32.16666666666666,
+///     lon_0: -116.25,
+///     lat_1: 33.88333333333333,
+///     lat_2: 32.78333333333333,
+///     x_0,
+///     y_0,
+///     ellps: "GRS80".to_string(),
+/// };
+/// let ft_to_m = PipelineBuilder::new()
+///     .step(ProjOp::UnitConvert {
+///         xy_in: "us-ft".to_string(),
+///         xy_out: "m".to_string(),
+///     })
+///     .step_inv(lcc(2000000.0001016, 500000.0001016001))
+///     .step(lcc(2000000.0, 500000.0))
+///     .build()
+///     .unwrap();
+///
+/// // The Presidio, approximately
+/// let result = ft_to_m.convert((4760096.421921, 3744293.729449)).unwrap();
+/// assert_relative_eq!(result.0, 1450880.29, epsilon = 1e-2);
+/// assert_relative_eq!(result.1, 1141263.01, epsilon = 1e-2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PipelineBuilder {
+    steps: Vec<(ProjOp, bool)>,
+}
+
+impl PipelineBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a step running `op` forwards
+    pub fn step(mut self, op: ProjOp) -> Self {
+        self.steps.push((op, false));
+        self
+    }
+
+    /// Add a step running `op` in the inverse direction
+    pub fn step_inv(mut self, op: ProjOp) -> Self {
+        self.steps.push((op, true));
+        self
+    }
+
+    /// Return the PROJ string definition of the pipeline
+    ///
+    /// Returns an error if an operation or parameter name is empty, or contains whitespace,
+    /// `=`, `+` or `"`.
+    pub fn definition(&self) -> Result<String, ProjCreateError> {
+        let mut definition = String::from("+proj=pipeline");
+        for (op, inverse) in &self.steps {
+            let (name, params) = op.params();
+            let mut names = iter::once(name).chain(params.iter().map(|(key, _)| *key));
+            if let Some(invalid) = names.find(|name| !valid_name(name)) {
+                return Err(ProjCreateError::ProjError(format!(
+                    "Invalid pipeline operation or parameter name: {:?}",
+                    invalid
+                )));
+            }
+            definition.push_str(" +step");
+            if *inverse {
+                definition.push_str(" +inv");
+            }
+            definition.push(' ');
+            definition.push_str(&op_definition(op));
+        }
+        Ok(definition)
+    }
+
+    /// Create the pipeline
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn build(&self) -> Result<Proj, ProjCreateError> {
+        Proj::new(&self.definition()?)
+    }
+
+    /// Create the pipeline using a [`ProjBuilder`], for instance to find grids in its search
+    /// paths
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn build_with(&self, builder: ProjBuilder) -> Result<Proj, ProjCreateError> {
+        builder.proj(&self.definition()?)
+    }
+}
+
+impl Display for PipelineBuilder {
+    /// Format the PROJ string definition, or nothing if it is invalid
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.definition() {
+            Ok(definition) => f.write_str(&definition),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl Proj {
+    /// Combine this transformation with `other` into one transformation, which converts points
+    /// using this transformation, then `other`
+    ///
+    /// The transformations are concatenated into a pipeline. Each must be a single operation
+    /// which can be exported as a PROJ string: CRSs, and transformations between CRSs with
+    /// several candidate operations, are rejected. When both the target CRS of this
+    /// transformation and the source CRS of `other` are known, they must be equivalent, and
+    /// both transformations must use the same axis order.
+    ///
+    /// ```rust
+    /// # use approx::assert_relative_eq;
+    /// use proj::{Affine, Proj};
+    ///
+    /// // a local grid of 0.01 degree cells, whose origin is at 2°E, 49°N
+    /// let local = Affine::new(
+    ///     [2.0, 49.0, 0.0],
+    ///     [[0.01, 0.0, 0.0], [0.0, 0.01, 0.0], [0.0, 0.0, 1.0]],
+    /// );
+    /// let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+    /// let local_to_merc = local.build().unwrap().then(&to_merc).unwrap();
+    /// let result = local_to_merc.convert((50.0, 100.0)).unwrap();
+    /// let expected = to_merc.convert((2.5, 50.0)).unwrap();
+    /// assert_relative_eq!(result.0, expected.0, epsilon = 1e-6);
+    /// assert_relative_eq!(result.1, expected.1, epsilon = 1e-6);
+    ///
+    /// // the output of the first transformation isn't the input of the second
+    /// let to_utm = Proj::new_known_crs("EPSG:4326", "EPSG:32631", None).unwrap();
+    /// assert!(to_merc.then(&to_utm).is_err());
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn then(&self, other: &Proj) -> Result<Proj, ProjCreateError> {
+        check_chainable(self, other)?;
+        let definition = format!(
+            "+proj=pipeline {} {}",
+            pipeline_steps(self)?,
+            pipeline_steps(other)?
+        );
+        let definition = CString::new(definition).map_err(ProjCreateError::ArgumentNulError)?;
+        self.derive(|ctx| unsafe { proj_create(ctx, definition.as_ptr()) })
+    }
+}
+
+/// Check that the target CRS of `first` and the source CRS of `second` are equivalent, if both
+/// are known
+fn check_chainable(first: &Proj, second: &Proj) -> Result<(), ProjCreateError> {
+    let ctx = first.context();
+    unsafe {
+        // PROJ doesn't set an error number if the object has no source or target CRS
+        let target = proj_get_target_crs(ctx.as_ptr(), first.as_ptr());
+        let source = proj_get_source_crs(ctx.as_ptr(), second.as_ptr());
+        if target.is_null() || source.is_null() {
+            proj_destroy(target);
+            proj_destroy(source);
+            return Ok(());
+        }
+        let (target, source) = (target.into_pj_ref(ctx)?, source.into_pj_ref(ctx)?);
+        let equivalent = proj_is_equivalent_to_with_ctx(
+            ctx.as_ptr(),
+            target.as_ptr(),
+            source.as_ptr(),
+            PJ_COMPARISON_CRITERION_PJ_COMP_EQUIVALENT_EXCEPT_AXIS_ORDER_GEOGCRS,
+        ) == 1;
+        if !equivalent {
+            return Err(ProjCreateError::ProjError(format!(
+                "The target CRS {:?} is not the source CRS {:?} of the next transformation",
+                _string(proj_get_name(target.as_ptr())).unwrap_or_default(),
+                _string(proj_get_name(source.as_ptr())).unwrap_or_default(),
+            )));
+        }
+    }
+    if first.normalized != second.normalized {
+        return Err(ProjCreateError::ProjError(
+            "The transformations don't use the same axis order".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Return the steps of a transformation, as they appear in a pipeline
+fn pipeline_steps(proj: &Proj) -> Result<String, ProjCreateError> {
+    if unsafe { proj_is_crs(proj.as_ptr()) } == 1 {
+        return Err(ProjCreateError::ProjError(
+            "A CRS is not a transformation".to_string(),
+        ));
+    }
+    let definition = proj.as_proj_string().map_err(|_| {
+        ProjCreateError::ProjError(
+            "The transformation can't be expressed as a single pipeline".to_string(),
+        )
+    })?;
+    let Some(steps) = definition.strip_prefix("+proj=pipeline") else {
+        return Ok(format!("+step {}", definition));
+    };
+    // pipelines can't be nested, so their steps are inlined, which is only possible when
+    // no parameter applies to all the steps
+    let steps = steps.trim_start();
+    if !steps.starts_with("+step") {
+        return Err(ProjCreateError::ProjError(format!(
+            "The pipeline has global parameters: {}",
+            definition
+        )));
+    }
+    Ok(steps.to_string())
+}
+
+/// A conversion between units, such as US survey feet to metres, or degrees to radians
+///
+/// Unit names are PROJ's short names, such as `"m"`, `"us-ft"`, `"ft"`, `"km"`, `"deg"` or
+/// `"rad"`, and are checked against the PROJ database when the conversion is built, so that a
+/// typo is reported rather than producing a wrong conversion:
+///
+/// ```rust
+/// # use approx::assert_relative_eq;
+/// use proj::UnitConvert;
+///
+/// let us_ft_to_m = UnitConvert::new("us-ft", "m").build().unwrap();
+/// let result = us_ft_to_m.convert((3937.0, 100.0)).unwrap();
+/// assert_relative_eq!(result.0, 1200.0, epsilon = 1e-9);
+/// assert_relative_eq!(result.1, 30.48006, epsilon = 1e-5);
+///
+/// assert!(UnitConvert::new("us_ft", "m").build().is_err());
+/// // horizontal lengths can't be converted to angles
+/// assert!(UnitConvert::new("m", "deg").build().is_err());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitConvert {
+    xy: Option<(String, String)>,
+    z: Option<(String, String)>,
+}
+
+impl UnitConvert {
+    /// Convert horizontal coordinates from `from_unit` to `to_unit`, which are both linear or
+    /// both angular units
+    pub fn new(from_unit: &str, to_unit: &str) -> Self {
+        UnitConvert {
+            xy: Some((from_unit.to_string(), to_unit.to_string())),
+            z: None,
+        }
+    }
+
+    /// Convert vertical coordinates from `from_unit` to `to_unit`, which are linear units,
+    /// leaving horizontal coordinates unchanged
+    ///
+    /// ```rust
+    /// use proj::UnitConvert;
+    ///
+    /// let ft_to_m = UnitConvert::vertical("ft", "m");
+    /// assert_eq!(ft_to_m.definition(), "+proj=unitconvert +z_in=ft +z_out=m");
+    /// assert!(ft_to_m.build().is_ok());
+    /// ```
+    pub fn vertical(from_unit: &str, to_unit: &str) -> Self {
+        UnitConvert {
+            xy: None,
+            z: Some((from_unit.to_string(), to_unit.to_string())),
+        }
+    }
+
+    /// Also convert vertical coordinates from `from_unit` to `to_unit`
+    pub fn with_vertical(mut self, from_unit: &str, to_unit: &str) -> Self {
+        self.z = Some((from_unit.to_string(), to_unit.to_string()));
+        self
+    }
+
+    /// Return the PROJ string definition of the conversion, without checking the units
+    pub fn definition(&self) -> String {
+        let mut definition = String::from("+proj=unitconvert");
+        if let Some((from, to)) = &self.xy {
+            definition.push_str(&format!(" +xy_in={} +xy_out={}", escape(from), escape(to)));
+        }
+        if let Some((from, to)) = &self.z {
+            definition.push_str(&format!(" +z_in={} +z_out={}", escape(from), escape(to)));
+        }
+        definition
+    }
+
+    /// Create the conversion
+    ///
+    /// Returns an error if a unit isn't in the PROJ database, or if units of different kinds,
+    /// such as metres and degrees, are converted.
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn build(&self) -> Result<Proj, ProjCreateError> {
+        self.build_with(ProjBuilder::new())
+    }
+
+    /// Create the conversion using a [`ProjBuilder`], whose database is used to check the units
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn build_with(&self, builder: ProjBuilder) -> Result<Proj, ProjCreateError> {
+        let units = unit_short_names(&builder)
+            .map_err(|e| ProjCreateError::ProjError(format!("Couldn't list units: {}", e)))?;
+        let category = |unit: &str| {
+            units
+                .iter()
+                .find(|(name, _)| name == unit)
+                .map(|(_, category)| category.as_str())
+                .ok_or_else(|| ProjCreateError::ProjError(format!("Unknown unit: {:?}", unit)))
+        };
+        let pairs = self.xy.iter().map(|pair| (pair, false));
+        for ((from, to), vertical) in pairs.chain(self.z.iter().map(|pair| (pair, true))) {
+            let (from_category, to_category) = (category(from)?, category(to)?);
+            if from_category != to_category || (vertical && from_category != "linear") {
+                return Err(ProjCreateError::ProjError(format!(
+                    "Can't convert {} from {:?} ({}) to {:?} ({})",
+                    if vertical { "heights" } else { "coordinates" },
+                    from,
+                    from_category,
+                    to,
+                    to_category
+                )));
+            }
+        }
+        builder.proj(&self.definition())
+    }
+}
+
+/// The convention used to interpret the rotations of a [`Helmert`] transformation
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RotationConvention {
+    /// Position vector rotations, as in EPSG method 9606
+    PositionVector,
+    /// Coordinate frame rotations, as in EPSG method 9607
+    CoordinateFrame,
+}
+
+impl RotationConvention {
+    fn name(self) -> &'static str {
+        match self {
+            RotationConvention::PositionVector => "position_vector",
+            RotationConvention::CoordinateFrame => "coordinate_frame",
+        }
+    }
+}
+
+/// The yearly rates of change of the parameters of a time-dependent [`Helmert`]
+/// transformation
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct HelmertRates {
+    /// Translation rates along the X, Y and Z axes, in metres per year
+    pub translation: [f64; 3],
+    /// Rotation rates around the X, Y and Z axes, in arc seconds per year
+    pub rotation: [f64; 3],
+    /// Scale difference rate, in parts per million per year
+    pub scale: f64,
+    /// The reference epoch of the parameters, as a decimal year
+    pub epoch: f64,
+}
+
+/// A Helmert transformation of geocentric cartesian coordinates, with 3, 7 or 14 parameters
+///
+/// Parameters published by a local authority can be used without formatting a PROJ string. The
+/// transformation can be built on its own, or used as a pipeline step between geodetic and
+/// geocentric conversions to shift geographic coordinates from one datum to another:
+///
+/// ```rust
+/// # use approx::assert_relative_eq;
+/// use proj::{Helmert, PipelineBuilder, ProjOp, RotationConvention};
+///
+/// // ED50 to WGS 84, for the UK offshore sector (EPSG:1311)
+/// let ed50_to_wgs84 = Helmert::seven_parameter(
+///     [-89.5, -93.8, -123.1],
+///     [0.0, 0.0, -0.156],
+///     1.2,
+///     RotationConvention::PositionVector,
+/// );
+/// let pipeline = PipelineBuilder::new()
+///     .step(ProjOp::UnitConvert {
+///         xy_in: "deg".to_string(),
+///         xy_out: "rad".to_string(),
+///     })
+///     .step(ProjOp::Cart { ellps: "intl".to_string() })
+///     .step(ed50_to_wgs84.into())
+///     .step_inv(ProjOp::Cart { ellps: "WGS84".to_string() })
+///     .step(ProjOp::UnitConvert {
+///         xy_in: "rad".to_string(),
+///         xy_out: "deg".to_string(),
+///     })
+///     .build()
+///     .unwrap();
+/// let result = pipeline.convert((1.0, 58.0)).unwrap();
+/// assert_relative_eq!(result.0, 0.99840, epsilon = 1e-5);
+/// assert_relative_eq!(result.1, 57.99937, epsilon = 1e-5);
+///
+/// // the parameters can be read back
+/// assert_eq!(Helmert::from_proj(&pipeline), Some(ed50_to_wgs84));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Helmert {
+    /// Translations along the X, Y and Z axes, in metres
+    pub translation: [f64; 3],
+    /// Rotations around the X, Y and Z axes, in arc seconds
+    pub rotation: [f64; 3],
+    /// Scale difference, in parts per million
+    pub scale: f64,
+    /// The interpretation of the rotations, which is required if there are any
+    pub convention: Option<RotationConvention>,
+    /// The rates of change of the parameters, for a 14-parameter transformation
+    pub rates: Option<HelmertRates>,
+}
+
+impl Helmert {
+    /// A 3-parameter transformation, consisting of translations in metres
+    pub fn translation(translation: [f64; 3]) -> Self {
+        Helmert {
+            translation,
+            ..Default::default()
+        }
+    }
+
+    /// A 7-parameter transformation, with translations in metres, rotations in arc seconds and
+    /// the scale difference in parts per million
+    pub fn seven_parameter(
+        translation: [f64; 3],
+        rotation: [f64; 3],
+        scale: f64,
+        convention: RotationConvention,
+    ) -> Self {
+        Helmert {
+            translation,
+            rotation,
+            scale,
+            convention: Some(convention),
+            rates: None,
+        }
+    }
+
+    /// Make this a 14-parameter, time-dependent transformation
+    ///
+    /// Time-dependent transformations use the epoch of each coordinate, so they aren't
+    /// meaningful for coordinates converted without one.
+    pub fn with_rates(mut self, rates: HelmertRates) -> Self {
+        self.rates = Some(rates);
+        self
+    }
+
+    /// The parameters of the operation, in PROJ's terms
+    fn params(&self) -> Vec<(String, Option<String>)> {
+        let mut params = vec![];
+        let mut push =
+            |key: &str, value: f64| params.push((key.to_string(), Some(value.to_string())));
+        for (key, value) in ["x", "y", "z"].iter().zip(self.translation) {
+            push(key, value);
+        }
+        if self.convention.is_some() || self.rotation != [0.0; 3] || self.scale != 0.0 {
+            for (key, value) in ["rx", "ry", "rz"].iter().zip(self.rotation) {
+                push(key, value);
+            }
+            push("s", self.scale);
+        }
+        if let Some(rates) = &self.rates {
+            for (key, value) in ["dx", "dy", "dz"].iter().zip(rates.translation) {
+                push(key, value);
+            }
+            for (key, value) in ["drx", "dry", "drz"].iter().zip(rates.rotation) {
+                push(key, value);
+            }
+            push("ds", rates.scale);
+            push("t_epoch", rates.epoch);
+        }
+        if let Some(convention) = self.convention {
+            params.push((
+                "convention".to_string(),
+                Some(convention.name().to_string()),
+            ));
+        }
+        params
+    }
+
+    /// Return the PROJ string definition of the transformation
+    pub fn definition(&self) -> String {
+        op_definition(&ProjOp::from(*self))
+    }
+
+    /// Create the transformation, of geocentric coordinates in metres
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn build(&self) -> Result<Proj, ProjCreateError> {
+        Proj::new(&self.definition())
+    }
+
+    /// Read the parameters of the first Helmert step of an operation, such as a transformation
+    /// from the database or a pipeline
+    ///
+    /// Returns `None` if the operation has no such step, if its definition isn't available
+    /// (for instance if there are several candidate operations between two CRSs), or if the
+    /// step is inverted or has parameters which can't be represented, such as a 2D rotation.
+    ///
+    /// ```rust
+    /// use proj::{Helmert, Proj};
+    ///
+    /// // ED50 to WGS 84 (18), a Position Vector transformation
+    /// let operation = Proj::new("urn:ogc:def:coordinateOperation:EPSG::1311").unwrap();
+    /// let helmert = Helmert::from_proj(&operation).unwrap();
+    /// assert_eq!(helmert.translation, [-89.5, -93.8, -123.1]);
+    /// assert_eq!(helmert.scale, 1.2);
+    /// ```
+    pub fn from_proj(proj: &Proj) -> Option<Self> {
+        let definition = proj.proj_info().definition?;
+        let params = find_step(&definition, "helmert")?;
+        let mut helmert = Helmert::default();
+        let mut rates = HelmertRates::default();
+        let mut has_rates = false;
+        for (key, value) in params {
+            let value = value?;
+            if key == "convention" {
+                helmert.convention = Some(match value {
+                    "position_vector" => RotationConvention::PositionVector,
+                    "coordinate_frame" => RotationConvention::CoordinateFrame,
+                    _ => return None,
+                });
+                continue;
+            }
+            let value: f64 = value.parse().ok()?;
+            has_rates |= key.starts_with('d') || key == "t_epoch";
+            match key {
+                "x" => helmert.translation[0] = value,
+                "y" => helmert.translation[1] = value,
+                "z" => helmert.translation[2] = value,
+                "rx" => helmert.rotation[0] = value,
+                "ry" => helmert.rotation[1] = value,
+                "rz" => helmert.rotation[2] = value,
+                "s" => helmert.scale = value,
+                "dx" => rates.translation[0] = value,
+                "dy" => rates.translation[1] = value,
+                "dz" => rates.translation[2] = value,
+                "drx" => rates.rotation[0] = value,
+                "dry" => rates.rotation[1] = value,
+                "drz" => rates.rotation[2] = value,
+                "ds" => rates.scale = value,
+                "t_epoch" => rates.epoch = value,
+                _ => return None,
+            }
+        }
+        if has_rates {
+            helmert.rates = Some(rates);
+        }
+        Some(helmert)
+    }
+}
+
+impl From<Helmert> for ProjOp {
+    fn from(helmert: Helmert) -> Self {
+        ProjOp::Custom {
+            name: "helmert".to_string(),
+            params: helmert.params(),
+        }
+    }
+}
+
+/// An affine transformation: `offset + matrix × (x, y, z)`
+///
+/// ```rust
+/// use proj::Affine;
+///
+/// // a local grid, rotated by 90° and shifted
+/// let affine = Affine::new([1000.0, 2000.0, 0.0], [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+/// let local = affine.build().unwrap();
+/// assert_eq!(local.convert((10.0, 20.0)).unwrap(), (980.0, 2010.0));
+/// assert_eq!(Affine::from_proj(&local), Some(affine));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Affine {
+    /// The offsets added to the X, Y and Z coordinates
+    pub offset: [f64; 3],
+    /// The matrix the coordinates are multiplied by, in rows
+    pub matrix: [[f64; 3]; 3],
+}
+
+impl Default for Affine {
+    /// The identity
+    fn default() -> Self {
+        Affine {
+            offset: [0.0; 3],
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+}
+
+/// The names of the matrix parameters of PROJ's `affine` operation
+const AFFINE_MATRIX: [[&str; 3]; 3] = [
+    ["s11", "s12", "s13"],
+    ["s21", "s22", "s23"],
+    ["s31", "s32", "s33"],
+];
+
+impl Affine {
+    pub fn new(offset: [f64; 3], matrix: [[f64; 3]; 3]) -> Self {
+        Affine { offset, matrix }
+    }
+
+    /// Return the PROJ string definition of the transformation
+    pub fn definition(&self) -> String {
+        op_definition(&ProjOp::from(*self))
+    }
+
+    /// Create the transformation
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn build(&self) -> Result<Proj, ProjCreateError> {
+        Proj::new(&self.definition())
+    }
+
+    /// Read the parameters of the first affine step of an operation
+    ///
+    /// Returns `None` if the operation has no such step, if its definition isn't available, or
+    /// if the step is inverted or transforms time coordinates.
+    pub fn from_proj(proj: &Proj) -> Option<Self> {
+        let definition = proj.proj_info().definition?;
+        let params = find_step(&definition, "affine")?;
+        let mut affine = Affine::default();
+        for (key, value) in params {
+            let value: f64 = value?.parse().ok()?;
+            match key {
+                "xoff" => affine.offset[0] = value,
+                "yoff" => affine.offset[1] = value,
+                "zoff" => affine.offset[2] = value,
+                _ => {
+                    let (row, column) = AFFINE_MATRIX.iter().enumerate().find_map(|(i, row)| {
+                        row.iter().position(|name| *name == key).map(|j| (i, j))
+                    })?;
+                    affine.matrix[row][column] = value;
+                }
+            }
+        }
+        Some(affine)
+    }
+}
+
+impl From<Affine> for ProjOp {
+    fn from(affine: Affine) -> Self {
+        let mut params = vec![];
+        for (key, value) in ["xoff", "yoff", "zoff"].iter().zip(affine.offset) {
+            params.push((key.to_string(), Some(value.to_string())));
+        }
+        for (names, row) in AFFINE_MATRIX.iter().zip(affine.matrix) {
+            for (key, value) in names.iter().zip(row) {
+                params.push((key.to_string(), Some(value.to_string())));
+            }
+        }
+        ProjOp::Custom {
+            name: "affine".to_string(),
+            params,
+        }
+    }
+}
+
+/// Return the parameters of the first step of a definition, as returned by `proj_pj_info`,
+/// using the operation `name`, or `None` if there is none or it is inverted
+fn find_step<'a>(definition: &'a str, name: &str) -> Option<Vec<(&'a str, Option<&'a str>)>> {
+    let tokens: Vec<&str> = definition.split_whitespace().collect();
+    let step = tokens.split(|token| *token == "step").find(|step| {
+        step.iter()
+            .any(|token| token.split_once('=') == Some(("proj", name)))
+    })?;
+    let mut params = vec![];
+    for token in step {
+        match token.split_once('=') {
+            // PROJ adds the default ellipsoid to operations created on their own
+            Some(("proj", _)) | Some(("ellps", _)) => {}
+            Some((key, value)) => params.push((key, Some(value))),
+            None if *token == "inv" => return None,
+            None => params.push((token, None)),
+        }
+    }
+    Some(params)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::assert_relative_eq;
+    use proj_sys::{proj_trans, PJ_COORD, PJ_DIRECTION_PJ_FWD, PJ_XYZT};
+
+    #[test]
+    fn test_definition() {
+        let pipeline = PipelineBuilder::new()
+            .step(ProjOp::AxisSwap { order: vec![2, 1] })
+            .step(ProjOp::UnitConvert {
+                xy_in: "deg".to_string(),
+                xy_out: "rad".to_string(),
+            })
+            .step_inv(ProjOp::Utm {
+                zone: 33,
+                south: true,
+                ellps: "WGS84".to_string(),
+            })
+            .step(ProjOp::Custom {
+                name: "hgridshift".to_string(),
+                params: vec![("grids".to_string(), Some("my \"grid\".tif".to_string()))],
+            });
+        assert_eq!(
+            pipeline.definition().unwrap(),
+            "+proj=pipeline +step +proj=axisswap +order=2,1 \
+             +step +proj=unitconvert +xy_in=deg +xy_out=rad \
+             +step +inv +proj=utm +zone=33 +south +ellps=WGS84 \
+             +step +proj=hgridshift +grids=\"my \"\"grid\"\".tif\""
+        );
+        assert_eq!(pipeline.to_string(), pipeline.definition().unwrap());
+        assert_eq!(
+            PipelineBuilder::new()
+                .step(ProjOp::Helmert {
+                    x: 0.5,
+                    y: -1.0,
+                    z: 1e-7,
+                    rx: 0.0,
+                    ry: 0.0,
+                    rz: 0.0,
+                    s: 0.0,
+                    convention: "position_vector".to_string(),
+                })
+                .definition()
+                .unwrap(),
+            "+proj=pipeline +step +proj=helmert +x=0.5 +y=-1 +z=0.0000001 +rx=0 +ry=0 +rz=0 \
+             +s=0 +convention=position_vector"
+        );
+    }
+
+    #[test]
+    fn test_invalid_name() {
+        let pipeline = PipelineBuilder::new().step(ProjOp::Custom {
+            name: "merc +inv".to_string(),
+            params: vec![],
+        });
+        assert!(pipeline.definition().is_err());
+        assert!(pipeline.build().is_err());
+        assert_eq!(pipeline.to_string(), "");
+    }
+
+    #[test]
+    fn test_unit_convert() {
+        let convert = UnitConvert::new("deg", "rad").with_vertical("us-ft", "m");
+        assert_eq!(
+            convert.definition(),
+            "+proj=unitconvert +xy_in=deg +xy_out=rad +z_in=us-ft +z_out=m"
+        );
+        let convert = convert.build().unwrap();
+        let point = PJ_COORD {
+            xyzt: PJ_XYZT {
+                x: 180.0,
+                y: 90.0,
+                z: 3937.0,
+                t: f64::INFINITY,
+            },
+        };
+        let result = unsafe { proj_trans(convert.as_ptr(), PJ_DIRECTION_PJ_FWD, point).xyzt };
+        assert_relative_eq!(result.x, std::f64::consts::PI, epsilon = 1e-12);
+        assert_relative_eq!(result.y, std::f64::consts::FRAC_PI_2, epsilon = 1e-12);
+        assert_relative_eq!(result.z, 1200.0, epsilon = 1e-9);
+        // heights can't be angles
+        assert!(UnitConvert::vertical("deg", "rad").build().is_err());
+        let err = UnitConvert::new("m", "furlong").build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The underlying PROJ call failed: Unknown unit: \"furlong\""
+        );
+        assert!(UnitConvert::default().build().is_ok());
+    }
+
+    #[test]
+    fn test_build() {
+        let wgs84_to_merc = PipelineBuilder::new()
+            .step(ProjOp::UnitConvert {
+                xy_in: "deg".to_string(),
+                xy_out: "rad".to_string(),
+            })
+            .step(ProjOp::WebMercator)
+            .build()
+            .unwrap();
+        let expected = crate::proj::normalized_known_crs("EPSG:4326", "EPSG:3857", None)
+            .unwrap()
+            .convert((2.3522, 48.8566))
+            .unwrap();
+        let result = wgs84_to_merc.convert((2.3522, 48.8566)).unwrap();
+        assert_relative_eq!(result.0, expected.0, epsilon = 1e-6);
+        assert_relative_eq!(result.1, expected.1, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_helmert() {
+        let helmert = Helmert::translation([-87.0, -98.0, -121.0]);
+        assert_eq!(helmert.definition(), "+proj=helmert +x=-87 +y=-98 +z=-121");
+        let proj = helmert.build().unwrap();
+        assert_eq!(Helmert::from_proj(&proj), Some(helmert));
+        let mut coord = PJ_COORD {
+            xyzt: PJ_XYZT {
+                x: 4000000.0,
+                y: 100000.0,
+                z: 4900000.0,
+                t: 0.0,
+            },
+        };
+        coord = unsafe { proj_trans(proj.as_ptr(), PJ_DIRECTION_PJ_FWD, coord) };
+        let result = unsafe { coord.xyzt };
+        assert_relative_eq!(result.x, 3999913.0, epsilon = 1e-6);
+        assert_relative_eq!(result.z, 4899879.0, epsilon = 1e-6);
+
+        // 14 parameters, ITRF2014 to ETRF2014
+        let helmert =
+            Helmert::seven_parameter([0.0; 3], [0.0; 3], 0.0, RotationConvention::PositionVector)
+                .with_rates(HelmertRates {
+                    rotation: [0.085, 0.531, -0.77],
+                    epoch: 1989.0,
+                    ..Default::default()
+                });
+        let proj = helmert.build().unwrap();
+        assert_eq!(Helmert::from_proj(&proj), Some(helmert));
+        // inverted steps and other operations aren't read
+        let pipeline = PipelineBuilder::new()
+            .step_inv(helmert.into())
+            .step_inv(ProjOp::Cart {
+                ellps: "GRS80".to_string(),
+            })
+            .build()
+            .unwrap();
+        assert_eq!(Helmert::from_proj(&pipeline), None);
+        assert_eq!(Affine::from_proj(&pipeline), None);
+    }
+
+    #[test]
+    fn test_affine() {
+        let affine = Affine::new(
+            [0.0, 0.0, 10.0],
+            [[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 1.0]],
+        );
+        let pipeline = PipelineBuilder::new()
+            .step(ProjOp::WebMercator)
+            .step(affine.into())
+            .build()
+            .unwrap();
+        assert_eq!(Affine::from_proj(&pipeline), Some(affine));
+        assert_eq!(Helmert::from_proj(&pipeline), None);
+        assert_eq!(
+            Affine::default()
+                .build()
+                .unwrap()
+                .convert((1.0, 2.0))
+                .unwrap(),
+            (1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_then() {
+        let to_rad = UnitConvert::new("deg", "rad").build().unwrap();
+        let merc = Proj::new("+proj=merc +ellps=WGS84").unwrap();
+        let deg_to_merc = to_rad.then(&merc).unwrap();
+        let expected = crate::proj::normalized_known_crs("EPSG:4326", "EPSG:3395", None)
+            .unwrap()
+            .convert((2.0, 49.0))
+            .unwrap();
+        let result = deg_to_merc.convert((2.0, 49.0)).unwrap();
+        assert_relative_eq!(result.0, expected.0, epsilon = 1e-6);
+        assert_relative_eq!(result.1, expected.1, epsilon = 1e-6);
+        // pipelines are inlined
+        let to_merc = crate::proj::normalized_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let from_merc = crate::proj::normalized_known_crs("EPSG:3857", "EPSG:4326", None).unwrap();
+        let round_trip = to_merc.then(&from_merc).unwrap();
+        let result = round_trip.convert((2.0, 49.0)).unwrap();
+        assert_relative_eq!(result.0, 2.0, epsilon = 1e-9);
+        assert_relative_eq!(result.1, 49.0, epsilon = 1e-9);
+        // a CRS isn't a transformation
+        let wgs84 = Proj::new("EPSG:4326").unwrap();
+        assert!(to_merc.then(&wgs84).is_err());
+        // the axis order differs
+        let mut builder = ProjBuilder::new();
+        builder.set_normalize_axis_order(!to_merc.normalized);
+        let authority_order = builder
+            .proj_known_crs("EPSG:3857", "EPSG:4326", None)
+            .unwrap();
+        assert!(to_merc.then(&authority_order).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use proj_sys::{proj_assign_context, proj_destroy, PJconsts};
+use std::mem;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+use crate::proj::result_from_create;
+use crate::{Context, ProjCreateError};
+
+/// A PROJ object which cannot outlive the [`Context`] it was created in
+///
+/// The object is destroyed when it is dropped. `PjRef`s are obtained from raw `proj-sys`
+/// pointers using [`PjPtrExt::into_pj_ref`]. A [`Proj`](crate::Proj) owns one, which keeps its
+/// context alive instead of borrowing it.
+///
+/// ```compile_fail
+/// use proj::{Context, PjPtrExt};
+/// use std::ffi::CString;
+///
+/// let wgs84 = {
+///     let ctx = Context::new();
+///     let definition = CString::new("EPSG:4326").unwrap();
+///     unsafe { proj_sys::proj_create(ctx.as_ptr(), definition.as_ptr()).into_pj_ref(&ctx) }
+///     // error: `ctx` does not live long enough
+/// };
+/// ```
+pub struct PjRef<'ctx> {
+    ptr: NonNull<PJconsts>,
+    // dropped, and therefore destroyed if it isn't shared, after the object
+    ctx: ContextRef<'ctx>,
+}
+
+/// The context of a [`PjRef`]: borrowed, or shared by the [`Proj`](crate::Proj) objects
+/// created in it
+enum ContextRef<'ctx> {
+    Borrowed(&'ctx Context),
+    Shared(Rc<Context>),
+}
+
+impl<'ctx> PjRef<'ctx> {
+    /// Return the raw object pointer, for use with `proj-sys`
+    ///
+    /// The pointer is only valid as long as `self` is alive.
+    pub fn as_ptr(&self) -> *mut PJconsts {
+        self.ptr.as_ptr()
+    }
+
+    /// Return the context the object was created in
+    pub fn context(&self) -> &Context {
+        match &self.ctx {
+            ContextRef::Borrowed(ctx) => ctx,
+            ContextRef::Shared(ctx) => ctx,
+        }
+    }
+
+    /// Release ownership of the object, which then has to be destroyed using `proj_destroy`
+    pub fn into_raw(self) -> *mut PJconsts {
+        let ptr = self.as_ptr();
+        mem::forget(self);
+        ptr
+    }
+}
+
+impl PjRef<'static> {
+    /// Take ownership of an object, keeping the context it was created in alive for as long as
+    /// the object
+    ///
+    /// A null pointer is treated as a failed creation, and the error is read from `ctx`.
+    ///
+    /// # Safety
+    /// The object must have been created in `ctx`, and must not be owned by anything else.
+    pub(crate) unsafe fn new_shared(
+        ptr: *mut PJconsts,
+        ctx: Rc<Context>,
+    ) -> Result<Self, ProjCreateError> {
+        Ok(PjRef {
+            ptr: checked(ptr, &ctx)?,
+            ctx: ContextRef::Shared(ctx),
+        })
+    }
+
+    /// Return the context, if the object keeps it alive
+    pub(crate) fn shared_context(&self) -> Option<&Rc<Context>> {
+        match &self.ctx {
+            ContextRef::Borrowed(_) => None,
+            ContextRef::Shared(ctx) => Some(ctx),
+        }
+    }
+
+    /// Return the context for modification, if the object keeps it alive
+    #[cfg(feature = "network")]
+    pub(crate) fn shared_context_mut(&mut self) -> Option<&mut Rc<Context>> {
+        match &mut self.ctx {
+            ContextRef::Borrowed(_) => None,
+            ContextRef::Shared(ctx) => Some(ctx),
+        }
+    }
+
+    /// Move the object to `ctx`, keeping it alive for as long as the object
+    pub(crate) fn assign_context(&mut self, ctx: &Rc<Context>) {
+        unsafe { proj_assign_context(self.as_ptr(), ctx.as_ptr()) };
+        // the previous context is destroyed afterwards if it isn't shared
+        self.ctx = ContextRef::Shared(Rc::clone(ctx));
+    }
+}
+
+impl Drop for PjRef<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            proj_destroy(self.ptr.as_ptr());
+        }
+    }
+}
+
+/// Extension trait tying raw `proj-sys` object pointers to their [`Context`]
+///
+/// ```rust
+/// use proj::{Context, PjPtrExt};
+/// use std::ffi::CString;
+///
+/// let ctx = Context::new();
+/// let definition = CString::new("EPSG:4326").unwrap();
+/// let wgs84 = unsafe { proj_sys::proj_create(ctx.as_ptr(), definition.as_ptr()).into_pj_ref(&ctx) }
+///     .unwrap();
+/// assert_eq!(unsafe { proj_sys::proj_is_crs(wgs84.as_ptr()) }, 1);
+/// ```
+pub trait PjPtrExt {
+    /// Take ownership of an object, tying its lifetime to that of `ctx`
+    ///
+    /// A null pointer is treated as a failed creation, and the error is read from `ctx`.
+    ///
+    /// # Safety
+    /// The object must have been created in `ctx`, and must not be owned by anything else.
+    unsafe fn into_pj_ref(self, ctx: &Context) -> Result<PjRef<'_>, ProjCreateError>;
+}
+
+impl PjPtrExt for *mut PJconsts {
+    unsafe fn into_pj_ref(self, ctx: &Context) -> Result<PjRef<'_>, ProjCreateError> {
+        Ok(PjRef {
+            ptr: checked(self, ctx)?,
+            ctx: ContextRef::Borrowed(ctx),
+        })
+    }
+}
+
+/// Check that the creation of an object in `ctx` succeeded
+unsafe fn checked(ptr: *mut PJconsts, ctx: &Context) -> Result<NonNull<PJconsts>, ProjCreateError> {
+    let ptr = result_from_create(ctx.as_ptr(), ptr)
+        .map_err(|e| ProjCreateError::ProjError(e.context_message(ctx.as_ptr())))?;
+    Ok(NonNull::new_unchecked(ptr))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proj_sys::{proj_create, proj_is_crs};
+    use std::ffi::CString;
+    use std::ptr;
+
+    #[test]
+    fn test_into_pj_ref() {
+        let ctx = Context::new();
+        let definition = CString::new("EPSG:4326").unwrap();
+        let crs =
+            unsafe { proj_create(ctx.as_ptr(), definition.as_ptr()).into_pj_ref(&ctx) }.unwrap();
+        assert_eq!(unsafe { proj_is_crs(crs.as_ptr()) }, 1);
+        assert_eq!(crs.context().as_ptr(), ctx.as_ptr());
+    }
+
+    #[test]
+    fn test_shared_context() {
+        let ctx = Rc::new(Context::new());
+        let ctx_ptr = ctx.as_ptr();
+        let definition = CString::new("EPSG:4326").unwrap();
+        let crs =
+            unsafe { PjRef::new_shared(proj_create(ctx_ptr, definition.as_ptr()), ctx) }.unwrap();
+        // the object keeps the context alive
+        assert_eq!(unsafe { proj_is_crs(crs.as_ptr()) }, 1);
+        assert_eq!(crs.context().as_ptr(), ctx_ptr);
+        assert_eq!(crs.shared_context().map(|ctx| ctx.as_ptr()), Some(ctx_ptr));
+    }
+
+    #[test]
+    fn test_null() {
+        let ctx = Context::new();
+        let definition = CString::new("🦀").unwrap();
+        let err = unsafe { proj_create(ctx.as_ptr(), definition.as_ptr()).into_pj_ref(&ctx) };
+        assert!(matches!(err, Err(ProjCreateError::ProjError(_))));
+        let err = unsafe { ptr::null_mut::<PJconsts>().into_pj_ref(&ctx) };
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,354 @@
+//! A pool of identical transformations, shared by the threads of a service
+//!
+//! A [`Proj`] can't be shared between threads, so services converting coordinates from many
+//! threads either create a transformation per request, or wrap a single one in a `Mutex`,
+//! serialising every conversion. A [`ProjPool`] instead owns several copies of the same
+//! transformation, each in its own context, and lends them out to one thread at a time:
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use proj::ProjPool;
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! let pool = Arc::new(ProjPool::new_known_crs(4, "EPSG:4326", "EPSG:3857", None).unwrap());
+//! let handles: Vec<_> = (0..8)
+//!     .map(|i| {
+//!         let pool = Arc::clone(&pool);
+//!         thread::spawn(move || {
+//!             // the transformation is returned to the pool when dropped
+//!             let to_merc = pool.get();
+//!             to_merc.convert((i as f64, 48.8566)).unwrap()
+//!         })
+//!     })
+//!     .collect();
+//! for handle in handles {
+//!     let (_, y) = handle.join().unwrap();
+//!     assert_relative_eq!(y, 6250564.349543125, epsilon = 1e-6);
+//! }
+//! assert_eq!(pool.metrics().checkouts, 8);
+//! ```
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::proj::CoordinateType;
+use crate::{
+    Area, Coord, CoordTransform, Direction, IntoCrs, Proj, ProjCoord, ProjCreateError, ProjError,
+    Transformer,
+};
+
+/// A transformation owned by a pool
+///
+/// Each is created in its own context, which nothing outside the pool refers to: the guards only
+/// expose methods which don't hand out the context, or objects sharing it.
+struct Slot(Proj);
+
+// SAFETY: a slot owns its context and every object created in it, and is used by a single
+// thread at a time, so moving it to another thread is sound even though `Proj` is `!Send`.
+unsafe impl Send for Slot {}
+
+/// Counters of the use of a [`ProjPool`], to size it
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolMetrics {
+    /// The number of times a transformation was taken from the pool
+    pub checkouts: u64,
+    /// The number of requests for a transformation made while every one was in use, so the
+    /// thread waited, or [`try_get`](ProjPool::try_get) returned `None`
+    pub contended: u64,
+    /// The total time spent waiting for a transformation
+    pub wait_time: Duration,
+}
+
+/// A pool of copies of the same transformation, which can be used by several threads at once
+///
+/// See the [module documentation](crate::pool) for an example.
+pub struct ProjPool {
+    idle: Mutex<Vec<Slot>>,
+    returned: Condvar,
+    size: usize,
+    checkouts: AtomicU64,
+    contended: AtomicU64,
+    wait_nanos: AtomicU64,
+}
+
+impl ProjPool {
+    /// Create a pool of `size` transformations from a definition accepted by [`Proj::new`]
+    ///
+    /// Returns an error if `size` is `0`, or if the transformation can't be created.
+    pub fn new(size: usize, definition: &str) -> Result<ProjPool, ProjCreateError> {
+        Self::create(size, || Proj::new(definition))
+    }
+
+    /// Create a pool of `size` transformations between two CRSs, as
+    /// [`Proj::new_known_crs`] does
+    ///
+    /// Returns an error if `size` is `0`, or if the transformation can't be created.
+    pub fn new_known_crs<S, T>(
+        size: usize,
+        from: S,
+        to: T,
+        area: Option<Area>,
+    ) -> Result<ProjPool, ProjCreateError>
+    where
+        S: IntoCrs,
+        T: IntoCrs,
+    {
+        let (from, to) = (from.crs_definition()?, to.crs_definition()?);
+        Self::create(size, || Proj::new_known_crs(&*from, &*to, area))
+    }
+
+    /// Create the transformations in their own contexts using `create`
+    fn create<F>(size: usize, create: F) -> Result<ProjPool, ProjCreateError>
+    where
+        F: Fn() -> Result<Proj, ProjCreateError>,
+    {
+        if size == 0 {
+            return Err(ProjCreateError::ProjError(
+                "A pool needs at least one transformation".to_string(),
+            ));
+        }
+        let idle = (0..size)
+            .map(|_| create().map(Slot))
+            .collect::<Result<_, _>>()?;
+        Ok(ProjPool {
+            idle: Mutex::new(idle),
+            returned: Condvar::new(),
+            size,
+            checkouts: AtomicU64::new(0),
+            contended: AtomicU64::new(0),
+            wait_nanos: AtomicU64::new(0),
+        })
+    }
+
+    /// Take a transformation from the pool, waiting until one is available
+    pub fn get(&self) -> PooledProj<'_> {
+        let mut idle = self.lock();
+        if idle.is_empty() {
+            let start = Instant::now();
+            while idle.is_empty() {
+                idle = self.returned.wait(idle).unwrap_or_else(|e| e.into_inner());
+            }
+            self.contended.fetch_add(1, Ordering::Relaxed);
+            let waited = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+            self.wait_nanos.fetch_add(waited, Ordering::Relaxed);
+        }
+        self.checkout(&mut idle)
+    }
+
+    /// Take a transformation from the pool if one is available, without waiting
+    pub fn try_get(&self) -> Option<PooledProj<'_>> {
+        let mut idle = self.lock();
+        if idle.is_empty() {
+            self.contended.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        Some(self.checkout(&mut idle))
+    }
+
+    /// The number of transformations owned by the pool
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The number of transformations which aren't in use
+    pub fn available(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Counters of the use of the pool since it was created
+    pub fn metrics(&self) -> PoolMetrics {
+        PoolMetrics {
+            checkouts: self.checkouts.load(Ordering::Relaxed),
+            contended: self.contended.load(Ordering::Relaxed),
+            wait_time: Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Slot>> {
+        // the slots are only pushed and popped, so they're consistent even if a thread panicked
+        self.idle.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn checkout(&self, idle: &mut Vec<Slot>) -> PooledProj<'_> {
+        self.checkouts.fetch_add(1, Ordering::Relaxed);
+        PooledProj {
+            pool: self,
+            slot: idle.pop(),
+        }
+    }
+}
+
+impl std::fmt::Debug for ProjPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProjPool")
+            .field("size", &self.size)
+            .field("available", &self.available())
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+/// A transformation taken from a [`ProjPool`], which is returned to it when dropped
+///
+/// Only the methods converting coordinates are available, since the others could share the
+/// transformation's context with objects outliving the guard.
+pub struct PooledProj<'a> {
+    pool: &'a ProjPool,
+    // only taken when dropped
+    slot: Option<Slot>,
+}
+
+impl PooledProj<'_> {
+    fn proj(&self) -> &Proj {
+        &self
+            .slot
+            .as_ref()
+            .expect("the transformation was returned")
+            .0
+    }
+
+    /// Convert a point, as [`Proj::convert`] does
+    pub fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.proj().convert(point)
+    }
+
+    /// Convert a point in the inverse direction, as [`Proj::convert_inverse`] does
+    pub fn convert_inverse<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.proj().convert_inverse(point)
+    }
+
+    /// Convert points in place, as [`Proj::convert_array`] does
+    pub fn convert_array<'a, C, F>(&self, points: &'a mut [C]) -> Result<&'a mut [C], ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.proj().convert_array(points)
+    }
+
+    /// Convert points in place in the inverse direction, as [`Proj::convert_array_inverse`]
+    /// does
+    pub fn convert_array_inverse<'a, C, F>(
+        &self,
+        points: &'a mut [C],
+    ) -> Result<&'a mut [C], ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.proj().convert_array_inverse(points)
+    }
+
+    /// Project a point, as [`Proj::project`] does
+    pub fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.proj().project(point, inverse)
+    }
+
+    /// Transform a coordinate with up to four dimensions, as [`Proj::trans_coord`] does
+    pub fn trans_coord(
+        &self,
+        coord: ProjCoord,
+        direction: Direction,
+    ) -> Result<ProjCoord, ProjError> {
+        self.proj().trans_coord(coord, direction)
+    }
+}
+
+impl Drop for PooledProj<'_> {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            self.pool.lock().push(slot);
+            self.pool.returned.notify_one();
+        }
+    }
+}
+
+impl std::fmt::Debug for PooledProj<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PooledProj").field(self.proj()).finish()
+    }
+}
+
+impl CoordTransform for PooledProj<'_> {
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.proj().convert(point)
+    }
+
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.proj().project(point, inverse)
+    }
+}
+
+impl Transformer for PooledProj<'_> {
+    fn transform_point(&self, point: (f64, f64), inverse: bool) -> Result<(f64, f64), ProjError> {
+        self.proj().transform_point(point, inverse)
+    }
+
+    fn transform_points(&self, points: &mut [(f64, f64)], inverse: bool) -> Result<(), ProjError> {
+        self.proj().transform_points(points, inverse)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_pool() {
+        assert!(ProjPool::new(0, "+proj=merc").is_err());
+        let pool = ProjPool::new_known_crs(2, "EPSG:4326", "EPSG:3857", None).unwrap();
+        let expected = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None)
+            .unwrap()
+            .convert((2.0, 48.0))
+            .unwrap();
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..50 {
+                        assert_eq!(pool.get().convert((2.0, 48.0)).unwrap(), expected);
+                    }
+                });
+            }
+        });
+        assert_eq!(pool.available(), 2);
+        assert_eq!(pool.metrics().checkouts, 200);
+        // every transformation is in use
+        let first = pool.try_get().unwrap();
+        let second = pool.get();
+        assert!(pool.try_get().is_none());
+        assert!(pool.metrics().contended >= 1);
+        let mut points = [expected];
+        second.convert_array_inverse(&mut points).unwrap();
+        assert_relative_eq!(points[0].0, 2.0, epsilon = 1e-9);
+        drop(first);
+        assert_eq!(pool.available(), 1);
+        // a guard can be moved to another thread
+        thread::scope(|scope| {
+            scope.spawn(move || second.convert((2.0, 48.0)).unwrap());
+        });
+        assert_eq!(pool.available(), 2);
+    }
+}
@@ -0,0 +1,142 @@
+//! Rounding of the coordinates output by batch conversions
+use crate::Accuracy;
+
+/// How a coordinate is rounded
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Precision {
+    /// Round to a number of decimal places
+    DecimalPlaces(u8),
+    /// Round to a number of significant digits, at least one
+    SignificantDigits(u8),
+}
+
+impl Precision {
+    /// Round `value`, leaving non-finite values unchanged
+    pub fn round(self, value: f64) -> f64 {
+        if !value.is_finite() || value == 0.0 {
+            return value;
+        }
+        let places = match self {
+            Precision::DecimalPlaces(places) => i32::from(places),
+            Precision::SignificantDigits(digits) => {
+                let magnitude = value.abs().log10().floor() as i32;
+                i32::from(digits.max(1)) - 1 - magnitude
+            }
+        };
+        // dividing by an exact power of ten yields the double closest to the rounded decimal
+        if places >= 0 {
+            let scale = 10f64.powi(places);
+            let scaled = value * scale;
+            if scaled.is_finite() {
+                scaled.round() / scale
+            } else {
+                value
+            }
+        } else {
+            let scale = 10f64.powi(-places);
+            (value / scale).round() * scale
+        }
+    }
+}
+
+/// The precision of the coordinates output by the batch conversions of a
+/// [`Proj`](crate::Proj)
+///
+/// Producers of interchange formats such as GeoJSON can use it to avoid publishing digits which
+/// don't carry any information:
+///
+/// ```rust
+/// use proj::{OutputPrecision, Precision, Proj};
+///
+/// let mut to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+/// // centimetres
+/// to_merc.set_output_precision(Some(OutputPrecision::uniform(Precision::DecimalPlaces(2))));
+/// let mut points = [(2.3522, 48.8566)];
+/// to_merc.convert_array(&mut points).unwrap();
+/// assert_eq!(points[0], (261845.71, 6250564.35));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OutputPrecision {
+    /// The precision of the first axis
+    pub x: Precision,
+    /// The precision of the second axis
+    pub y: Precision,
+}
+
+impl OutputPrecision {
+    /// Use the same precision for both axes
+    pub fn uniform(precision: Precision) -> Self {
+        OutputPrecision {
+            x: precision,
+            y: precision,
+        }
+    }
+
+    /// Keep the decimal places which are significant given the accuracy of an operation, such
+    /// as its [declared accuracy](crate::Proj::declared_accuracy)
+    ///
+    /// `metres_per_unit` is the size of a unit of the output coordinates: `1.0` for metres, or
+    /// about `111_320.0` for degrees of latitude. Returns `None` if the accuracy is unknown or
+    /// if the operation is exact, in which case coordinates shouldn't be rounded.
+    ///
+    /// ```rust
+    /// use proj::{Accuracy, OutputPrecision, Precision};
+    ///
+    /// let precision = OutputPrecision::from_accuracy(Accuracy::Metres(0.5), 1.0).unwrap();
+    /// assert_eq!(precision.x, Precision::DecimalPlaces(1));
+    /// let precision = OutputPrecision::from_accuracy(Accuracy::Metres(2.0), 111_320.0).unwrap();
+    /// assert_eq!(precision.y, Precision::DecimalPlaces(5));
+    /// ```
+    pub fn from_accuracy(accuracy: Accuracy, metres_per_unit: f64) -> Option<Self> {
+        let accuracy = accuracy.metres().filter(|metres| *metres > 0.0)?;
+        let places = (metres_per_unit / accuracy).log10().ceil();
+        if !places.is_finite() {
+            return None;
+        }
+        let places = places.clamp(0.0, f64::from(u8::MAX)) as u8;
+        Some(Self::uniform(Precision::DecimalPlaces(places)))
+    }
+
+    /// Round a point
+    pub(crate) fn round(&self, x: f64, y: f64) -> (f64, f64) {
+        (self.x.round(x), self.y.round(y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round() {
+        assert_eq!(Precision::DecimalPlaces(2).round(1.23456), 1.23);
+        assert_eq!(Precision::DecimalPlaces(0).round(-2.5), -3.0);
+        assert_eq!(Precision::DecimalPlaces(3).round(0.1 + 0.2), 0.3);
+        assert_eq!(Precision::SignificantDigits(3).round(123456.0), 123000.0);
+        assert_eq!(Precision::SignificantDigits(3).round(0.00123456), 0.00123);
+        assert_eq!(Precision::SignificantDigits(0).round(7.7), 8.0);
+        assert_eq!(Precision::DecimalPlaces(200).round(1e300), 1e300);
+        assert!(Precision::DecimalPlaces(2).round(f64::NAN).is_nan());
+        assert_eq!(
+            Precision::DecimalPlaces(2).round(f64::INFINITY),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_from_accuracy() {
+        assert_eq!(
+            OutputPrecision::from_accuracy(Accuracy::Metres(0.01), 1.0),
+            Some(OutputPrecision::uniform(Precision::DecimalPlaces(2)))
+        );
+        assert_eq!(
+            OutputPrecision::from_accuracy(Accuracy::Metres(1000.0), 1.0),
+            Some(OutputPrecision::uniform(Precision::DecimalPlaces(0)))
+        );
+        assert_eq!(
+            OutputPrecision::from_accuracy(Accuracy::Metres(0.0), 1.0),
+            None
+        );
+        assert_eq!(OutputPrecision::from_accuracy(Accuracy::Unknown, 1.0), None);
+    }
+}
@@ -0,0 +1,12 @@
+//! The types and traits needed by most uses of the crate, for glob importing
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use proj::prelude::*;
+//!
+//! let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+//! let result = ft_to_m.convert((4760096.421921, 3744293.729449)).unwrap();
+//! // using the Coord trait
+//! assert_relative_eq!(result.x(), 1450880.29, epsilon = 1e-2);
+//! ```
+pub use crate::{Area, Coord, Proj, ProjBuilder, ProjCreateError, ProjError, Transform};
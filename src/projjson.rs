@@ -0,0 +1,428 @@
+//! Typed representation of [PROJJSON](https://proj.org/specifications/projjson.html) CRSs
+//!
+//! The structs mirror the PROJJSON schema for the common CRS types, so that CRSs can be
+//! inspected or modified without re-parsing the JSON returned by
+//! [`to_projjson`](crate::Proj::to_projjson). Members which aren't modelled are kept in the
+//! `other` map of the enclosing object, so that a CRS survives a round trip.
+//!
+//! ```rust
+//! use proj::projjson::{Crs, Unit};
+//! use proj::Proj;
+//!
+//! let utm = Proj::new("EPSG:32631").unwrap();
+//! let Crs::Projected(crs) = utm.projjson_value().unwrap() else {
+//!     panic!("not a projected CRS");
+//! };
+//! assert_eq!(crs.conversion.method.name, "Transverse Mercator");
+//! assert_eq!(crs.coordinate_system.axis[0].unit, Some(Unit::Named("metre".to_string())));
+//!
+//! let utm = Proj::from_projjson_struct(&Crs::Projected(crs)).unwrap();
+//! assert!(utm.as_proj_string().unwrap().contains("+zone=31"));
+//! ```
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{Proj, ProjCreateError, ProjError};
+
+/// A coordinate reference system, tagged by its PROJJSON `type`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
+pub enum Crs {
+    #[serde(rename = "GeographicCRS")]
+    Geographic(GeodeticCrs),
+    #[serde(rename = "GeodeticCRS")]
+    Geodetic(GeodeticCrs),
+    #[serde(rename = "ProjectedCRS")]
+    Projected(ProjectedCrs),
+    #[serde(rename = "VerticalCRS")]
+    Vertical(VerticalCrs),
+    #[serde(rename = "CompoundCRS")]
+    Compound(CompoundCrs),
+    #[serde(rename = "BoundCRS")]
+    Bound(BoundCrs),
+}
+
+impl Crs {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Crs::Geographic(crs) | Crs::Geodetic(crs) => Some(&crs.name),
+            Crs::Projected(crs) => Some(&crs.name),
+            Crs::Vertical(crs) => Some(&crs.name),
+            Crs::Compound(crs) => Some(&crs.name),
+            Crs::Bound(_) => None,
+        }
+    }
+}
+
+/// The scope, area of use and identifier shared by CRSs
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Usage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<BoundingBox>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
+}
+
+/// A geographic or geodetic CRS, which is based on either a datum or a datum ensemble
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GeodeticCrs {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datum: Option<Datum>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datum_ensemble: Option<DatumEnsemble>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coordinate_system: Option<CoordinateSystem>,
+    #[serde(flatten)]
+    pub usage: Usage,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProjectedCrs {
+    pub name: String,
+    pub base_crs: GeodeticCrs,
+    pub conversion: Operation,
+    pub coordinate_system: CoordinateSystem,
+    #[serde(flatten)]
+    pub usage: Usage,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VerticalCrs {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datum: Option<Datum>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datum_ensemble: Option<DatumEnsemble>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coordinate_system: Option<CoordinateSystem>,
+    #[serde(flatten)]
+    pub usage: Usage,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompoundCrs {
+    pub name: String,
+    pub components: Vec<Crs>,
+    #[serde(flatten)]
+    pub usage: Usage,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A CRS along with the transformation of its coordinates to a hub CRS, such as a PROJ string
+/// with `+towgs84`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BoundCrs {
+    pub source_crs: Box<Crs>,
+    pub target_crs: Box<Crs>,
+    pub transformation: Operation,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A datum, such as a `GeodeticReferenceFrame` or a `VerticalReferenceFrame`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Datum {
+    /// The PROJJSON type of the datum
+    #[serde(rename = "type")]
+    pub datum_type: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ellipsoid: Option<Ellipsoid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prime_meridian: Option<PrimeMeridian>,
+    /// The epoch of a dynamic reference frame, as a decimal year
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame_reference_epoch: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A group of realisations of a datum, such as `WGS 84`, which are treated as equivalent
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DatumEnsemble {
+    pub name: String,
+    pub members: Vec<EnsembleMember>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ellipsoid: Option<Ellipsoid>,
+    /// The accuracy of the ensemble in metres, as a decimal string
+    pub accuracy: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EnsembleMember {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// An ellipsoid, defined by its semi-major axis and either its inverse flattening or its
+/// semi-minor axis, or a sphere defined by its radius
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ellipsoid {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub semi_major_axis: Option<Measure>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub semi_minor_axis: Option<Measure>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_flattening: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius: Option<Measure>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PrimeMeridian {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<Measure>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CoordinateSystem {
+    /// The kind of coordinate system, such as `ellipsoidal`, `Cartesian` or `vertical`
+    pub subtype: String,
+    pub axis: Vec<Axis>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Axis {
+    pub name: String,
+    pub abbreviation: String,
+    /// The direction of the axis, such as `north`, `east` or `up`
+    pub direction: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<Unit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_value: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_value: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_meaning: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// The conversion of a projected CRS, or the transformation of a bound CRS
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Operation {
+    pub name: String,
+    pub method: Method,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<Parameter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Method {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A parameter of an operation method
+///
+/// `value` is a string for file parameters, such as the name of a grid.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Parameter {
+    pub name: String,
+    pub value: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<Unit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Id>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+/// A unit of measure
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Unit {
+    /// One of the units PROJJSON refers to by name: `metre`, `degree` or `unity`
+    Named(String),
+    Defined {
+        /// The kind of unit, such as `LinearUnit` or `AngularUnit`
+        #[serde(rename = "type")]
+        unit_type: String,
+        name: String,
+        /// The factor converting the unit to the SI unit of its kind
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        conversion_factor: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<Id>,
+    },
+}
+
+/// A value, which is in the default unit of its context (such as metres for an ellipsoid axis)
+/// unless a unit is given
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Measure {
+    Value(f64),
+    WithUnit { value: f64, unit: Unit },
+}
+
+impl Measure {
+    pub fn value(&self) -> f64 {
+        match self {
+            Measure::Value(value) | Measure::WithUnit { value, .. } => *value,
+        }
+    }
+}
+
+/// The area of use of a CRS, in degrees
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub south_latitude: f64,
+    pub west_longitude: f64,
+    pub north_latitude: f64,
+    pub east_longitude: f64,
+}
+
+/// An identifier, such as `EPSG:4326`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Id {
+    pub authority: String,
+    pub code: Code,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Code {
+    Integer(i64),
+    Text(String),
+}
+
+impl Proj {
+    /// Return the PROJJSON representation of a CRS as typed structs
+    ///
+    /// This fails for coordinate operations, and for CRS types which aren't modelled by [`Crs`].
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    #[cfg_attr(docsrs, doc(cfg(feature = "projjson")))]
+    pub fn projjson_value(&self) -> Result<Crs, ProjError> {
+        let json = self.to_projjson(None, None, None)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Create a CRS from its PROJJSON representation as typed structs
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    #[cfg_attr(docsrs, doc(cfg(feature = "projjson")))]
+    pub fn from_projjson_struct(crs: &Crs) -> Result<Proj, ProjCreateError> {
+        let json =
+            serde_json::to_string(crs).map_err(|e| ProjCreateError::ProjError(e.to_string()))?;
+        Proj::new(&json)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for definition in ["EPSG:4326", "EPSG:32631", "EPSG:5972", "EPSG:4978"] {
+            let proj = Proj::new(definition).unwrap();
+            let crs = proj.projjson_value().unwrap();
+            let round_trip = Proj::from_projjson_struct(&crs).unwrap();
+            assert_eq!(
+                round_trip.to_projjson(None, None, None).unwrap(),
+                proj.to_projjson(None, None, None).unwrap(),
+                "{}",
+                definition
+            );
+        }
+    }
+
+    #[test]
+    fn test_crs_types() {
+        let wgs84 = Proj::new("EPSG:4326").unwrap().projjson_value().unwrap();
+        let Crs::Geographic(wgs84) = wgs84 else {
+            panic!("not a geographic CRS: {:?}", wgs84);
+        };
+        let ensemble = wgs84.datum_ensemble.unwrap();
+        let ellipsoid = ensemble.ellipsoid.unwrap();
+        assert_eq!(ellipsoid.semi_major_axis.unwrap().value(), 6378137.0);
+        assert_eq!(ellipsoid.inverse_flattening, Some(298.257223563));
+        assert_eq!(wgs84.usage.id.unwrap().code, Code::Integer(4326));
+        // members which are modelled aren't duplicated
+        assert_eq!(wgs84.other.keys().collect::<Vec<_>>(), ["$schema"]);
+
+        let compound = Proj::new("EPSG:5972").unwrap().projjson_value().unwrap();
+        let Crs::Compound(compound) = compound else {
+            panic!("not a compound CRS: {:?}", compound);
+        };
+        let Crs::Vertical(vertical) = &compound.components[1] else {
+            panic!("not a vertical CRS: {:?}", compound.components[1]);
+        };
+        let datum = vertical.datum.as_ref().unwrap();
+        assert_eq!(datum.datum_type, "DynamicVerticalReferenceFrame");
+        assert_eq!(datum.frame_reference_epoch, Some(2000.0));
+
+        let bound = Proj::new("+proj=longlat +ellps=GRS80 +towgs84=1,2,3 +type=crs")
+            .unwrap()
+            .projjson_value()
+            .unwrap();
+        let Crs::Bound(bound) = bound else {
+            panic!("not a bound CRS: {:?}", bound);
+        };
+        assert_eq!(bound.target_crs.name(), Some("WGS 84"));
+        assert_eq!(bound.transformation.parameters.len(), 3);
+        assert_eq!(bound.transformation.parameters[0].value, Value::from(1));
+
+        let operation = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        assert!(matches!(
+            operation.projjson_value(),
+            Err(ProjError::ProjJson(_))
+        ));
+    }
+}
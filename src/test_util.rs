@@ -0,0 +1,221 @@
+//! A local HTTP server of grid files, for tests which download grids
+//!
+//! Tests enabling network access otherwise depend on the availability of
+//! [cdn.proj.org](https://cdn.proj.org), and of the network. A [`GridServer`] serves the files
+//! of a directory on localhost, answering the range requests PROJ makes, and
+//! [`ProjBuilder::with_test_endpoint`](crate::ProjBuilder::with_test_endpoint) points a context
+//! at it:
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use proj::test_util::GridServer;
+//! use proj::ProjBuilder;
+//!
+//! let server = GridServer::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data")).unwrap();
+//! let mut builder = ProjBuilder::new();
+//! builder.with_test_endpoint(&server).unwrap();
+//! let shift = builder
+//!     .proj("+proj=hgridshift +grids=test_hgrid_little_endian.gsb")
+//!     .unwrap();
+//! let (lon, lat) = shift.convert((4.5f64.to_radians(), 52.5f64.to_radians())).unwrap();
+//! assert_relative_eq!(lon.to_degrees(), 5.875, epsilon = 1e-6);
+//! assert_relative_eq!(lat.to_degrees(), 55.375, epsilon = 1e-6);
+//! assert!(server.requests() > 0);
+//! ```
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use crate::ProjError;
+
+/// An HTTP server of the files of a directory on localhost, which stops when dropped
+///
+/// Only `GET` and `HEAD` requests for files at the root of the directory are served, in whole or
+/// in a single range. Connections are handled one at a time, and closed after each response.
+pub struct GridServer {
+    addr: SocketAddr,
+    stopped: Arc<AtomicBool>,
+    requests: Arc<AtomicU64>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl GridServer {
+    /// Start serving the files of `dir` on a free port of localhost
+    pub fn new<P: AsRef<Path>>(dir: P) -> Result<GridServer, ProjError> {
+        let dir = dir.as_ref().to_path_buf();
+        // fail early if the directory can't be read
+        std::fs::read_dir(&dir).map_err(ProjError::GridFiles)?;
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let stopped = Arc::new(AtomicBool::new(false));
+        let requests = Arc::new(AtomicU64::new(0));
+        let thread = {
+            let (stopped, requests) = (Arc::clone(&stopped), Arc::clone(&requests));
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let Ok(stream) = stream else {
+                        continue;
+                    };
+                    requests.fetch_add(1, Ordering::SeqCst);
+                    // a failure only affects the client which made the request
+                    let _ = respond(&dir, stream);
+                }
+            })
+        };
+        Ok(GridServer {
+            addr,
+            stopped,
+            requests,
+            thread: Some(thread),
+        })
+    }
+
+    /// The URL of the server, to use as a URL endpoint
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// The number of requests received so far
+    pub fn requests(&self) -> u64 {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for GridServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // wake the server up, so it sees it was stopped
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl std::fmt::Debug for GridServer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GridServer")
+            .field("url", &self.url())
+            .field("requests", &self.requests())
+            .finish()
+    }
+}
+
+/// Read a request from `stream`, and send the response
+fn respond(dir: &Path, mut stream: TcpStream) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut range = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    if method != "GET" && method != "HEAD" {
+        return write_status(&mut stream, "405 Method Not Allowed");
+    }
+    let Some(path) = file_path(dir, path) else {
+        return write_status(&mut stream, "404 Not Found");
+    };
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let (status, start, end) = match range.as_deref().map(|range| parse_range(range, len)) {
+        None => ("200 OK", 0, len),
+        Some(Some((start, end))) => ("206 Partial Content", start, end),
+        Some(None) => return write_status(&mut stream, "416 Range Not Satisfiable"),
+    };
+    let mut head = format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nAccept-Ranges: bytes\r\n\
+         Connection: close\r\n",
+        end - start
+    );
+    if range.is_some() {
+        head += &format!("Content-Range: bytes {start}-{}/{len}\r\n", end - 1);
+    }
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(b"\r\n")?;
+    if method == "GET" {
+        file.seek(SeekFrom::Start(start))?;
+        std::io::copy(&mut file.take(end - start), &mut stream)?;
+    }
+    stream.flush()
+}
+
+fn write_status(stream: &mut TcpStream, status: &str) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+    )
+}
+
+/// The file of `dir` requested using `path`, if it exists and is at the root of `dir`
+fn file_path(dir: &Path, path: &str) -> Option<PathBuf> {
+    let name = path.strip_prefix('/')?;
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        return None;
+    }
+    Some(dir.join(name)).filter(|path| path.is_file())
+}
+
+/// Parse a `bytes=x-y` range of a file of `len` bytes into its start and (exclusive) end
+fn parse_range(range: &str, len: u64) -> Option<(u64, u64)> {
+    let (start, end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    let start: u64 = start.trim().parse().ok()?;
+    let end = match end.trim() {
+        "" => len,
+        end => end.parse::<u64>().ok()?.saturating_add(1).min(len),
+    };
+    Some((start, end)).filter(|(start, end)| start < end)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_grid_server() {
+        assert_eq!(parse_range("bytes=0-3", 100), Some((0, 4)));
+        assert_eq!(parse_range("bytes=96-", 100), Some((96, 100)));
+        assert_eq!(parse_range("bytes=90-200", 100), Some((90, 100)));
+        assert_eq!(parse_range("bytes=100-200", 100), None);
+
+        let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data");
+        let server = GridServer::new(&data).unwrap();
+        let agent = ureq::Agent::new();
+        let grid = std::fs::read(data.join("test_nodata.gtx")).unwrap();
+        let res = agent
+            .get(&format!("{}/test_nodata.gtx", server.url()))
+            .set("Range", "bytes=8-15")
+            .call()
+            .unwrap();
+        assert_eq!(res.status(), 206);
+        assert_eq!(
+            res.header("Content-Range").unwrap(),
+            format!("bytes 8-15/{}", grid.len())
+        );
+        let mut body = vec![];
+        res.into_reader().read_to_end(&mut body).unwrap();
+        assert_eq!(body, &grid[8..16]);
+        for path in ["/missing.tif", "/../Cargo.toml", "/"] {
+            let res = agent.get(&format!("{}{path}", server.url())).call();
+            assert!(matches!(res, Err(ureq::Error::Status(404, _))), "{}", path);
+        }
+        assert_eq!(server.requests(), 4);
+    }
+}
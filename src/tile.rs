@@ -0,0 +1,157 @@
+//! Transformation of [vector tile](https://github.com/mapbox/vector-tile-spec) coordinates.
+//!
+//! Features in Mapbox Vector Tiles (MVT) are encoded as integer coordinates local to their tile,
+//! from `0` to the tile's extent, with `y` increasing downwards. A [`TileTransform`] converts
+//! them to and from any CRS using the Web Mercator tile grid, reusing the same transformation
+//! objects for every tile.
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use proj::tile::{TileId, TileTransform, DEFAULT_EXTENT};
+//!
+//! let transform = TileTransform::new("EPSG:4326").unwrap();
+//! let tile = TileId::new(0, 0, 0);
+//! let layer = [(2048, 2048), (4096, 2048)];
+//! let lonlat = transform.to_crs(tile, DEFAULT_EXTENT, &layer).unwrap();
+//! assert_relative_eq!(lonlat[0].0, 0.0, epsilon = 1e-9);
+//! assert_relative_eq!(lonlat[1].0, 180.0, epsilon = 1e-9);
+//! assert_eq!(transform.to_tile(tile, DEFAULT_EXTENT, &lonlat).unwrap(), layer);
+//! ```
+use crate::{Proj, ProjCreateError, ProjError};
+
+/// The extent of tiles, unless specified otherwise in the layer
+pub const DEFAULT_EXTENT: u32 = 4096;
+
+/// The CRS of the tile grid
+const WEB_MERCATOR: &str = "EPSG:3857";
+
+/// Half of the width of the Web Mercator tile grid, in metres
+const HALF_WIDTH: f64 = 20_037_508.342_789_244;
+
+/// The address of a tile in the Web Mercator tile grid, with `y` increasing southwards
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TileId {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl TileId {
+    pub fn new(z: u8, x: u32, y: u32) -> Self {
+        Self { z, x, y }
+    }
+
+    /// The width of the tile, in metres
+    fn size(&self) -> f64 {
+        2.0 * HALF_WIDTH / 2f64.powi(i32::from(self.z))
+    }
+
+    /// Convert tile-local coordinates to Web Mercator
+    fn mercator(self, extent: u32, (px, py): (i32, i32)) -> (f64, f64) {
+        let size = self.size();
+        let extent = f64::from(extent);
+        (
+            -HALF_WIDTH + (f64::from(self.x) + f64::from(px) / extent) * size,
+            HALF_WIDTH - (f64::from(self.y) + f64::from(py) / extent) * size,
+        )
+    }
+
+    /// Convert Web Mercator coordinates to tile-local coordinates, rounding to the nearest
+    /// integer
+    fn local(self, extent: u32, (x, y): (f64, f64)) -> (i32, i32) {
+        let size = self.size();
+        let extent = f64::from(extent);
+        (
+            (((x + HALF_WIDTH) / size - f64::from(self.x)) * extent).round() as i32,
+            (((HALF_WIDTH - y) / size - f64::from(self.y)) * extent).round() as i32,
+        )
+    }
+}
+
+/// Transformations between tile-local coordinates and a CRS
+///
+/// Coordinates in the CRS use the `Longitude, Latitude` / `Easting, Northing` axis order.
+pub struct TileTransform {
+    to_crs: Proj,
+    from_crs: Proj,
+}
+
+impl TileTransform {
+    /// Create the transformations between the tile grid and `crs`
+    pub fn new(crs: &str) -> Result<Self, ProjCreateError> {
+        Ok(Self {
+            to_crs: Proj::new_known_crs(WEB_MERCATOR, crs, None)?,
+            from_crs: Proj::new_known_crs(crs, WEB_MERCATOR, None)?,
+        })
+    }
+
+    /// Convert the coordinates of a tile layer to the CRS
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn to_crs(
+        &self,
+        tile: TileId,
+        extent: u32,
+        points: &[(i32, i32)],
+    ) -> Result<Vec<(f64, f64)>, ProjError> {
+        let mut converted: Vec<(f64, f64)> = points
+            .iter()
+            .map(|&point| tile.mercator(extent, point))
+            .collect();
+        self.to_crs.convert_array(&mut converted)?;
+        Ok(converted)
+    }
+
+    /// Convert coordinates in the CRS to the local coordinates of a tile layer
+    ///
+    /// Points outside the tile result in coordinates outside of `0..extent`.
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn to_tile(
+        &self,
+        tile: TileId,
+        extent: u32,
+        points: &[(f64, f64)],
+    ) -> Result<Vec<(i32, i32)>, ProjError> {
+        let mut converted = points.to_vec();
+        self.from_crs.convert_array(&mut converted)?;
+        Ok(converted
+            .into_iter()
+            .map(|point| tile.local(extent, point))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tile_mercator() {
+        let tile = TileId::new(1, 1, 0);
+        assert_eq!(tile.mercator(4096, (0, 4096)), (0.0, 0.0));
+        assert_eq!(tile.mercator(4096, (4096, 0)), (HALF_WIDTH, HALF_WIDTH));
+        assert_eq!(tile.local(4096, (0.0, 0.0)), (0, 4096));
+        // points beyond the tile's edges, such as those in its buffer
+        assert_eq!(tile.local(4096, (-HALF_WIDTH / 64.0, 0.0)), (-64, 4096));
+    }
+
+    #[test]
+    fn test_tile_transform() {
+        let transform = TileTransform::new("EPSG:27700").unwrap();
+        // tile covering London
+        let tile = TileId::new(10, 511, 340);
+        let layer = [(0, 0), (1234, 2345), (4096, 4096)];
+        let bng = transform.to_crs(tile, DEFAULT_EXTENT, &layer).unwrap();
+        assert!(bng
+            .iter()
+            .all(|&(e, n)| (500_000.0..560_000.0).contains(&e)
+                && (150_000.0..220_000.0).contains(&n)));
+        assert_eq!(
+            transform.to_tile(tile, DEFAULT_EXTENT, &bng).unwrap(),
+            layer
+        );
+    }
+}
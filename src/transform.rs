@@ -1,6 +1,7 @@
 use std::{error::Error, fmt};
 
-use crate::{Proj, ProjError};
+use crate::proj::{coord_from_f64, coord_to_f64, CoordinateType};
+use crate::{Coord, CoordTransform, Direction, IntoCrs, Proj, ProjCoord, ProjError};
 
 /// Transform a geometry using PROJ.
 pub trait Transform<T> {
@@ -8,6 +9,13 @@ pub trait Transform<T> {
 
     /// Transform a Geometry by mutating it in place.
     ///
+    /// `proj` is usually a [`Proj`], but can be any [`CoordTransform`].
+    ///
+    /// Coordinates are converted in chunks of [`Proj::array_chunk_len`] points, without copying
+    /// the whole geometry. If an error is returned, the geometry is left unchanged, as with
+    /// [`Proj::convert_array`]: geometries made of several parts are transformed into a copy,
+    /// which replaces them once every part has been transformed.
+    ///
     #[cfg_attr(
         feature = "geo-types",
         doc = r##"
@@ -31,7 +39,7 @@ assert_relative_eq!(
 ```
 "##
     )]
-    fn transform(&mut self, proj: &Proj) -> Result<(), ProjError>;
+    fn transform<P: CoordTransform>(&mut self, proj: &P) -> Result<(), ProjError>;
 
     /// Immutable flavor of [`Transform::transform`], which allocates a new geometry.
     ///
@@ -63,7 +71,7 @@ assert_relative_eq!(
 ```
 "##
     )]
-    fn transformed(&self, proj: &Proj) -> Result<Self::Output, ProjError>;
+    fn transformed<P: CoordTransform>(&self, proj: &P) -> Result<Self::Output, ProjError>;
 
     /// Transform a geometry from one CRS to another CRS by modifying it in place.
     ///
@@ -84,11 +92,15 @@ assert_relative_eq!(point, point!(x: -4064052.0f32, y: -7223650.5f32));
 ```
 "##
     )]
-    fn transform_crs_to_crs(
+    fn transform_crs_to_crs<S, U>(
         &mut self,
-        source_crs: &str,
-        target_crs: &str,
-    ) -> Result<(), TransformError> {
+        source_crs: S,
+        target_crs: U,
+    ) -> Result<(), TransformError>
+    where
+        S: IntoCrs,
+        U: IntoCrs,
+    {
         let proj = Proj::new_known_crs(source_crs, target_crs, None)?;
         Ok(self.transform(&proj)?)
     }
@@ -114,14 +126,164 @@ assert_relative_eq!(
 ```
 "##
     )]
-    fn transformed_crs_to_crs(
+    fn transformed_crs_to_crs<S, U>(
         &self,
-        source_crs: &str,
-        target_crs: &str,
-    ) -> Result<Self::Output, TransformError> {
+        source_crs: S,
+        target_crs: U,
+    ) -> Result<Self::Output, TransformError>
+    where
+        S: IntoCrs,
+        U: IntoCrs,
+    {
         let proj = Proj::new_known_crs(source_crs, target_crs, None)?;
         Ok(self.transformed(&proj)?)
     }
+
+    /// Transform a geometry in place using a time-dependent transformation, for coordinates
+    /// observed at `epoch`, as a decimal year
+    ///
+    /// The epoch is passed to PROJ as the time of every coordinate, as
+    /// [`Proj::trans_coord`] does: [`transform`](Transform::transform) passes none, so
+    /// time-dependent transformations use the epoch of their definition.
+    ///
+    #[cfg_attr(
+        feature = "geo-types",
+        doc = r##"
+# Examples
+
+```
+# use approx::assert_relative_eq;
+use proj::{Proj, Transform};
+use geo_types::point;
+
+// ITRF2014 to ETRF2000, which depends on the time
+let itrf_to_etrf = Proj::new_known_crs("EPSG:9000", "EPSG:9067", None).unwrap();
+let mut point = point!(x: 2.0, y: 48.0);
+point.transform_at_epoch(&itrf_to_etrf, 2020.0).unwrap();
+assert_relative_eq!(point, point!(x: 1.9999929349642769, y: 47.99999486502744), epsilon = 1e-12);
+```
+"##
+    )]
+    fn transform_at_epoch(&mut self, proj: &Proj, epoch: f64) -> Result<(), ProjError> {
+        self.transform(&AtEpoch { proj, epoch })
+    }
+
+    /// Immutable flavor of [`Transform::transform_at_epoch`], which allocates a new geometry.
+    fn transformed_at_epoch(&self, proj: &Proj, epoch: f64) -> Result<Self::Output, ProjError> {
+        self.transformed(&AtEpoch { proj, epoch })
+    }
+
+    /// Transform a geometry in place from a dynamic CRS, in which its coordinates were observed
+    /// at `epoch`, to another CRS
+    ///
+    /// The source CRS is given the coordinate epoch, as [`Proj::with_epoch`] does, so that PROJ
+    /// selects the operations which apply to coordinate metadata, and the epoch is passed as
+    /// in [`transform_at_epoch`](Transform::transform_at_epoch).
+    ///
+    #[cfg_attr(
+        feature = "geo-types",
+        doc = r##"
+# Examples
+
+```
+# use approx::assert_relative_eq;
+use proj::Transform;
+use geo_types::point;
+
+// a GNSS position in ITRF2014, observed in 2020
+let mut point = point!(x: 2.0, y: 48.0);
+point.transform_crs_to_crs_at_epoch("EPSG:9000", "EPSG:9067", 2020.0).unwrap();
+assert_relative_eq!(point, point!(x: 1.9999929349642769, y: 47.99999486502744), epsilon = 1e-12);
+```
+"##
+    )]
+    fn transform_crs_to_crs_at_epoch<S, U>(
+        &mut self,
+        source_crs: S,
+        target_crs: U,
+        epoch: f64,
+    ) -> Result<(), TransformError>
+    where
+        S: IntoCrs,
+        U: IntoCrs,
+    {
+        let proj = crs_to_crs_at_epoch(source_crs, target_crs, epoch)?;
+        Ok(self.transform_at_epoch(&proj, epoch)?)
+    }
+
+    /// Immutable flavor of [`Transform::transform_crs_to_crs_at_epoch`], which allocates a new
+    /// geometry.
+    fn transformed_crs_to_crs_at_epoch<S, U>(
+        &self,
+        source_crs: S,
+        target_crs: U,
+        epoch: f64,
+    ) -> Result<Self::Output, TransformError>
+    where
+        S: IntoCrs,
+        U: IntoCrs,
+    {
+        let proj = crs_to_crs_at_epoch(source_crs, target_crs, epoch)?;
+        Ok(self.transformed_at_epoch(&proj, epoch)?)
+    }
+}
+
+/// Create the transformation from a CRS with a coordinate epoch to another CRS
+fn crs_to_crs_at_epoch<S, U>(
+    source_crs: S,
+    target_crs: U,
+    epoch: f64,
+) -> Result<Proj, crate::ProjCreateError>
+where
+    S: IntoCrs,
+    U: IntoCrs,
+{
+    let source = Proj::new(&source_crs.crs_definition()?)?.with_epoch(epoch)?;
+    Proj::new_known_crs(&source, target_crs, None)
+}
+
+/// A transformation converting coordinates with a time
+struct AtEpoch<'a> {
+    proj: &'a Proj,
+    epoch: f64,
+}
+
+impl AtEpoch<'_> {
+    fn trans<C, F>(&self, point: C, direction: Direction) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        let (x, y, z) = coord_to_f64(&point, || ProjError::FloatConversion)?;
+        let coord = ProjCoord::xyzt(x, y, z.unwrap_or(0.0), self.epoch);
+        let result = self.proj.trans_coord(coord, direction)?;
+        coord_from_f64((result.x(), result.y(), z.map(|_| result.z())), || {
+            ProjError::FloatConversion
+        })
+    }
+}
+
+impl CoordTransform for AtEpoch<'_> {
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.trans(point, Direction::Forward)
+    }
+
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        let direction = if inverse {
+            Direction::Inverse
+        } else {
+            Direction::Forward
+        };
+        self.trans(point, direction)
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,376 @@
+use crate::proj::{coord_from_f64, coord_to_f64, CoordinateType};
+use crate::{Coord, Proj, ProjError};
+
+/// The coordinate conversions used by [`Transform`](crate::Transform), abstracting over
+/// [`Proj`]
+///
+/// Libraries can accept any implementation, such as a transformation caching its results, a
+/// composition of several transformations, or a mock in tests, instead of the concrete `Proj`.
+/// Only [`convert`](#tymethod.convert) and [`project`](#tymethod.project) are required:
+///
+/// ```rust
+/// use proj::{Coord, CoordTransform, CoordinateType, Proj, ProjError, Transform};
+///
+/// /// Apply two transformations in turn
+/// struct Compose<'a>(&'a Proj, &'a Proj);
+///
+/// impl CoordTransform for Compose<'_> {
+///     fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+///     where
+///         C: Coord<F>,
+///         F: CoordinateType,
+///     {
+///         self.1.convert(self.0.convert(point)?)
+///     }
+///
+///     fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+///     where
+///         C: Coord<F>,
+///         F: CoordinateType,
+///     {
+///         if inverse {
+///             self.0.project(self.1.project(point, true)?, true)
+///         } else {
+///             self.1.project(self.0.project(point, false)?, false)
+///         }
+///     }
+/// }
+///
+/// let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+/// let m_to_ft = Proj::new_known_crs("EPSG:26946", "EPSG:2230", None).unwrap();
+/// # #[cfg(feature = "geo-types")]
+/// # {
+/// let mut point = geo_types::point!(x: 4760096.421921, y: 3744293.729449);
+/// point.transform(&Compose(&ft_to_m, &m_to_ft)).unwrap();
+/// approx::assert_relative_eq!(point.x(), 4760096.421921, epsilon = 1e-6);
+/// # }
+/// ```
+pub trait CoordTransform {
+    /// Convert a point, as [`Proj::convert`] does
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType;
+
+    /// Convert points in place, as [`Proj::convert_array`] does
+    ///
+    /// The default implementation converts the points one at a time into a copy, which is only
+    /// written back once they have all been converted: if an error is returned, the points are
+    /// left unchanged.
+    fn convert_array<'a, C, F>(&self, points: &'a mut [C]) -> Result<&'a mut [C], ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        let copy = |point: &C| match point.z() {
+            Some(z) => C::from_xyz(point.x(), point.y(), z),
+            None => C::from_xy(point.x(), point.y()),
+        };
+        let converted = points
+            .iter()
+            .map(|point| self.convert(copy(point)))
+            .collect::<Result<Vec<C>, ProjError>>()?;
+        for (point, converted) in points.iter_mut().zip(converted) {
+            *point = converted;
+        }
+        Ok(points)
+    }
+
+    /// Project a point, or unproject it if `inverse` is `true`, as [`Proj::project`] does
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType;
+
+    /// Convert a bounding box, as [`Proj::transform_bounds`] does
+    ///
+    /// The default implementation converts `densify_pts` points along each edge, in addition to
+    /// the corners, and returns their extent. Unlike PROJ, it doesn't handle boxes crossing the
+    /// antimeridian.
+    fn transform_bounds<F>(
+        &self,
+        left: F,
+        bottom: F,
+        right: F,
+        top: F,
+        densify_pts: i32,
+    ) -> Result<[F; 4], ProjError>
+    where
+        F: CoordinateType,
+    {
+        let steps = F::from(densify_pts.max(0) + 1).ok_or(ProjError::FloatConversion)?;
+        let mut extent = [
+            F::infinity(),
+            F::infinity(),
+            F::neg_infinity(),
+            F::neg_infinity(),
+        ];
+        for i in 0..=densify_pts.max(0) + 1 {
+            let t = F::from(i).ok_or(ProjError::FloatConversion)? / steps;
+            let x = left + (right - left) * t;
+            let y = bottom + (top - bottom) * t;
+            for point in [(x, bottom), (x, top), (left, y), (right, y)] {
+                let (x, y) = self.convert(point)?;
+                extent = [
+                    extent[0].min(x),
+                    extent[1].min(y),
+                    extent[2].max(x),
+                    extent[3].max(y),
+                ];
+            }
+        }
+        Ok(extent)
+    }
+}
+
+impl CoordTransform for Proj {
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        Proj::convert(self, point)
+    }
+
+    fn convert_array<'a, C, F>(&self, points: &'a mut [C]) -> Result<&'a mut [C], ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        Proj::convert_array(self, points)
+    }
+
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        Proj::project(self, point, inverse)
+    }
+
+    fn transform_bounds<F>(
+        &self,
+        left: F,
+        bottom: F,
+        right: F,
+        top: F,
+        densify_pts: i32,
+    ) -> Result<[F; 4], ProjError>
+    where
+        F: CoordinateType,
+    {
+        Proj::transform_bounds(self, left, bottom, right, top, densify_pts)
+    }
+}
+
+impl<T: CoordTransform + ?Sized> CoordTransform for &T {
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        (**self).convert(point)
+    }
+
+    fn convert_array<'a, C, F>(&self, points: &'a mut [C]) -> Result<&'a mut [C], ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        (**self).convert_array(points)
+    }
+
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        (**self).project(point, inverse)
+    }
+
+    fn transform_bounds<F>(
+        &self,
+        left: F,
+        bottom: F,
+        right: F,
+        top: F,
+        densify_pts: i32,
+    ) -> Result<[F; 4], ProjError>
+    where
+        F: CoordinateType,
+    {
+        (**self).transform_bounds(left, bottom, right, top, densify_pts)
+    }
+}
+
+/// Converts points using [`Transformer::transform_point`], so that trait objects can be used
+/// with [`Transform`](crate::Transform)
+impl CoordTransform for dyn Transformer + '_ {
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        transform_coord(self, point, false)
+    }
+
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        transform_coord(self, point, inverse)
+    }
+}
+
+/// Convert a point using a [`Transformer`]
+pub(crate) fn transform_coord<T, C, F>(
+    transformer: &T,
+    point: C,
+    inverse: bool,
+) -> Result<C, ProjError>
+where
+    T: Transformer + ?Sized,
+    C: Coord<F>,
+    F: CoordinateType,
+{
+    let (x, y, z) = coord_to_f64(&point, || ProjError::FloatConversion)?;
+    // transformers only convert horizontal coordinates, so heights are left unchanged
+    let (x, y) = transformer.transform_point((x, y), inverse)?;
+    coord_from_f64((x, y, z), || ProjError::FloatConversion)
+}
+
+/// Conversion of points, abstracting over [`Proj`]
+///
+/// Code which only converts points can accept a `&dyn Transformer`, or be generic over
+/// `Transformer`, so that its tests can use a `MockTransformer` instead of PROJ, with the `mock`
+/// feature:
+///
+/// ```rust
+/// use proj::{Proj, ProjError, Transformer};
+///
+/// /// The extent of points, in the target CRS
+/// fn extent(to: &dyn Transformer, points: &[(f64, f64)]) -> Result<[f64; 4], ProjError> {
+///     let mut points = points.to_vec();
+///     to.transform_points(&mut points, false)?;
+///     Ok(points.iter().fold(
+///         [f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY],
+///         |[west, south, east, north], &(x, y)| [west.min(x), south.min(y), east.max(x), north.max(y)],
+///     ))
+/// }
+///
+/// let wgs84_to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+/// let [west, _, east, _] = extent(&wgs84_to_merc, &[(-1.0, 50.0), (1.0, 51.0)]).unwrap();
+/// assert!(west < 0.0 && east > 0.0);
+/// ```
+///
+/// Transformers only convert horizontal coordinates: when a `Transformer` converts a
+/// [`Coord`] with a height, such as through [`CoordTransform`], the height is left unchanged.
+pub trait Transformer {
+    /// Convert a point forwards, or in the inverse direction if `inverse` is `true`
+    fn transform_point(&self, point: (f64, f64), inverse: bool) -> Result<(f64, f64), ProjError>;
+
+    /// Convert points in place, forwards or in the inverse direction if `inverse` is `true`
+    ///
+    /// If an error is returned, the points preceding the failure may have been converted.
+    fn transform_points(&self, points: &mut [(f64, f64)], inverse: bool) -> Result<(), ProjError> {
+        for point in points {
+            *point = self.transform_point(*point, inverse)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts points using [`convert`](Proj::convert) and
+/// [`convert_array`](Proj::convert_array), or their inverse variants
+impl Transformer for Proj {
+    fn transform_point(&self, point: (f64, f64), inverse: bool) -> Result<(f64, f64), ProjError> {
+        if inverse {
+            self.convert_inverse(point)
+        } else {
+            self.convert(point)
+        }
+    }
+
+    fn transform_points(&self, points: &mut [(f64, f64)], inverse: bool) -> Result<(), ProjError> {
+        if inverse {
+            self.convert_array_inverse(points)?;
+        } else {
+            self.convert_array(points)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Transformer + ?Sized> Transformer for &T {
+    fn transform_point(&self, point: (f64, f64), inverse: bool) -> Result<(f64, f64), ProjError> {
+        (**self).transform_point(point, inverse)
+    }
+
+    fn transform_points(&self, points: &mut [(f64, f64)], inverse: bool) -> Result<(), ProjError> {
+        (**self).transform_points(points, inverse)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_proj_transformer() {
+        let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+        let transformer: &dyn Transformer = &ft_to_m;
+        let point = (4760096.421921, 3744293.729449);
+        let converted = transformer.transform_point(point, false).unwrap();
+        assert_eq!(converted, ft_to_m.convert(point).unwrap());
+        let mut points = [converted; 3];
+        (&transformer).transform_points(&mut points, true).unwrap();
+        assert_relative_eq!(points[2].0, point.0, epsilon = 1e-6);
+    }
+
+    /// A transformation only implementing the required methods
+    struct Minimal(Proj);
+
+    impl CoordTransform for Minimal {
+        fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+        where
+            C: Coord<F>,
+            F: CoordinateType,
+        {
+            self.0.convert(point)
+        }
+
+        fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+        where
+            C: Coord<F>,
+            F: CoordinateType,
+        {
+            self.0.project(point, inverse)
+        }
+    }
+
+    #[test]
+    fn test_coord_transform_defaults() {
+        let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+        let bounds = [
+            4760096.421921,
+            3744293.729449,
+            4760196.421921,
+            3744393.729449,
+        ];
+        let expected = ft_to_m
+            .transform_bounds(bounds[0], bounds[1], bounds[2], bounds[3], 21)
+            .unwrap();
+        let minimal = Minimal(ft_to_m);
+        let result = minimal
+            .transform_bounds(bounds[0], bounds[1], bounds[2], bounds[3], 21)
+            .unwrap();
+        for (result, expected) in result.iter().zip(expected) {
+            assert_relative_eq!(*result, expected, epsilon = 1e-6);
+        }
+        let mut points = [(bounds[0], bounds[1]), (bounds[2], bounds[3])];
+        CoordTransform::convert_array(&minimal, &mut points).unwrap();
+        assert_relative_eq!(points[0].0, expected[0], epsilon = 1e-6);
+        assert_relative_eq!(points[1].1, expected[3], epsilon = 1e-6);
+    }
+}
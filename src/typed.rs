@@ -0,0 +1,343 @@
+//! Strongly typed CRSs, coordinate operations and coordinate metadata
+//!
+//! A [`Proj`] can hold any kind of PROJ object, so calling [`convert`](Proj::convert) on a CRS,
+//! or [`with_epoch`](Proj::with_epoch) on a pipeline, only fails at runtime. [`Crs`],
+//! [`Transformation`] and [`CoordinateMetadata`] wrap a `Proj` known to hold the corresponding
+//! kind of object, and only expose the methods which make sense for it:
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use proj::{Crs, Transformation};
+//!
+//! let wgs84 = Crs::new("EPSG:4326").unwrap();
+//! let web_mercator = Crs::new("EPSG:3857").unwrap();
+//! let to_merc: Transformation = wgs84.to(&web_mercator, None).unwrap();
+//! let (x, _) = to_merc.convert((2.3522, 48.8566)).unwrap();
+//! assert_relative_eq!(x, 261845.70624393807, epsilon = 1e-6);
+//!
+//! // an operation isn't a CRS
+//! assert!(Crs::new("+proj=merc").is_err());
+//! ```
+//!
+//! Every type converts to and from a `Proj`, and gives access to it using `as_proj`, so the
+//! typed and untyped APIs can be mixed during a migration.
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use proj_sys::proj_get_source_crs;
+
+use crate::proj::CoordinateType;
+use crate::{
+    Area, Coord, CoordTransform, Direction, IntoCrs, Proj, ProjCoord, ProjCreateError, ProjError,
+    Transformer,
+};
+
+/// A coordinate reference system
+///
+/// Obtained using [`Crs::new`], or by converting a [`Proj`] holding a CRS using `TryFrom`.
+#[derive(Debug)]
+pub struct Crs(Proj);
+
+/// A coordinate operation, which transforms coordinates
+///
+/// Obtained using [`Transformation::new`], [`Transformation::between`], [`Crs::to`], or by
+/// converting a [`Proj`] holding a coordinate operation using `TryFrom`.
+#[derive(Debug)]
+pub struct Transformation(Proj);
+
+/// A CRS with a coordinate epoch, for coordinates in a dynamic CRS
+///
+/// Obtained using [`CoordinateMetadata::new`], [`Crs::with_epoch`], or by converting a
+/// [`Proj`] holding coordinate metadata using `TryFrom`.
+#[derive(Debug)]
+pub struct CoordinateMetadata(Proj);
+
+impl Crs {
+    /// Try to create a CRS from any definition accepted by [`Proj::new`], such as
+    /// `"EPSG:4326"`, failing if it defines another kind of object
+    pub fn new(definition: &str) -> Result<Crs, ProjError> {
+        Crs::try_from(Proj::new(definition)?)
+    }
+
+    /// Create the transformation from this CRS to `target`, as
+    /// [`Proj::new_known_crs`] does
+    pub fn to(&self, target: &Crs, area: Option<Area>) -> Result<Transformation, ProjCreateError> {
+        Transformation::between(self, target, area)
+    }
+
+    /// Attach a coordinate epoch, as a decimal year, to the CRS
+    ///
+    /// ```rust
+    /// let itrf2014 = proj::Crs::new("EPSG:9000").unwrap();
+    /// assert_eq!(itrf2014.with_epoch(2022.66).unwrap().epoch(), Some(2022.66));
+    /// ```
+    pub fn with_epoch(&self, epoch: f64) -> Result<CoordinateMetadata, ProjCreateError> {
+        self.0.with_epoch(epoch).map(CoordinateMetadata)
+    }
+
+    /// The underlying [`Proj`], for the methods which aren't available on `Crs`
+    pub fn as_proj(&self) -> &Proj {
+        &self.0
+    }
+
+    /// Return the underlying [`Proj`]
+    pub fn into_proj(self) -> Proj {
+        self.0
+    }
+}
+
+impl Transformation {
+    /// Try to create a coordinate operation from any definition accepted by [`Proj::new`],
+    /// such as a PROJ string, failing if it defines another kind of object
+    pub fn new(definition: &str) -> Result<Transformation, ProjError> {
+        Transformation::try_from(Proj::new(definition)?)
+    }
+
+    /// Create the transformation between two CRSs, as [`Proj::new_known_crs`] does
+    pub fn between<S, T>(
+        from: S,
+        to: T,
+        area: Option<Area>,
+    ) -> Result<Transformation, ProjCreateError>
+    where
+        S: IntoCrs,
+        T: IntoCrs,
+    {
+        Proj::new_known_crs(from, to, area).map(Transformation)
+    }
+
+    /// Create a coordinate operation from its code in an authority, as
+    /// [`Proj::from_operation_code`] does
+    pub fn from_code(authority: &str, code: &str) -> Result<Transformation, ProjCreateError> {
+        Proj::from_operation_code(authority, code).map(Transformation)
+    }
+
+    /// The same operation run backwards, as returned by [`Proj::inverse`]
+    pub fn inverse(&self) -> Result<Transformation, ProjCreateError> {
+        self.0.inverse().map(Transformation)
+    }
+
+    /// The transformation from the target CRS of this transformation to its source CRS, as
+    /// returned by [`Proj::reversed`]
+    pub fn reversed(&self) -> Result<Transformation, ProjCreateError> {
+        self.0.reversed().map(Transformation)
+    }
+
+    /// Convert a point, as [`Proj::convert`] does
+    pub fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.0.convert(point)
+    }
+
+    /// Convert points in place, as [`Proj::convert_array`] does
+    pub fn convert_array<'a, C, F>(&self, points: &'a mut [C]) -> Result<&'a mut [C], ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.0.convert_array(points)
+    }
+
+    /// Transform a coordinate with up to four dimensions, as [`Proj::trans_coord`] does
+    pub fn trans_coord(
+        &self,
+        coord: ProjCoord,
+        direction: Direction,
+    ) -> Result<ProjCoord, ProjError> {
+        self.0.trans_coord(coord, direction)
+    }
+
+    /// The underlying [`Proj`], for the methods which aren't available on `Transformation`
+    pub fn as_proj(&self) -> &Proj {
+        &self.0
+    }
+
+    /// Return the underlying [`Proj`]
+    pub fn into_proj(self) -> Proj {
+        self.0
+    }
+}
+
+impl CoordinateMetadata {
+    /// Try to create coordinate metadata from a definition such as `"EPSG:9000@2022.66"`, as
+    /// [`Proj::coordinate_metadata`] does
+    pub fn new(definition: &str) -> Result<CoordinateMetadata, ProjCreateError> {
+        Proj::coordinate_metadata(definition).map(CoordinateMetadata)
+    }
+
+    /// The coordinate epoch, as a decimal year
+    ///
+    /// Returns `None` if PROJ doesn't report one, as [`Proj::epoch`] does.
+    pub fn epoch(&self) -> Option<f64> {
+        self.0.epoch()
+    }
+
+    /// The CRS the coordinates are in
+    ///
+    /// ```rust
+    /// let itrf2014 = proj::CoordinateMetadata::new("EPSG:9000@2022.66").unwrap();
+    /// let crs = itrf2014.crs().unwrap();
+    /// let wkt = crs.as_proj().as_wkt(proj::WktVersion::Wkt2_2019).unwrap();
+    /// assert!(wkt.starts_with("GEOGCRS[\"ITRF2014\""));
+    /// ```
+    ///
+    /// # Safety
+    /// This method contains unsafe code.
+    pub fn crs(&self) -> Result<Crs, ProjCreateError> {
+        self.0
+            .derive(|ctx| unsafe { proj_get_source_crs(ctx, self.0.as_ptr()) })
+            .map(Crs)
+    }
+
+    /// The underlying [`Proj`], for the methods which aren't available on `CoordinateMetadata`
+    pub fn as_proj(&self) -> &Proj {
+        &self.0
+    }
+
+    /// Return the underlying [`Proj`]
+    pub fn into_proj(self) -> Proj {
+        self.0
+    }
+}
+
+impl TryFrom<Proj> for Crs {
+    type Error = ProjError;
+
+    fn try_from(proj: Proj) -> Result<Self, Self::Error> {
+        if !proj.is_crs() {
+            return Err(ProjError::NotACrs);
+        }
+        Ok(Crs(proj))
+    }
+}
+
+impl TryFrom<Proj> for Transformation {
+    type Error = ProjError;
+
+    fn try_from(proj: Proj) -> Result<Self, Self::Error> {
+        if !proj.is_coordinate_operation() {
+            return Err(ProjError::NotAnOperation);
+        }
+        Ok(Transformation(proj))
+    }
+}
+
+impl TryFrom<Proj> for CoordinateMetadata {
+    type Error = ProjError;
+
+    fn try_from(proj: Proj) -> Result<Self, Self::Error> {
+        if !proj.is_coordinate_metadata() {
+            return Err(ProjError::NotCoordinateMetadata);
+        }
+        Ok(CoordinateMetadata(proj))
+    }
+}
+
+impl From<Crs> for Proj {
+    fn from(crs: Crs) -> Self {
+        crs.0
+    }
+}
+
+impl From<Transformation> for Proj {
+    fn from(transformation: Transformation) -> Self {
+        transformation.0
+    }
+}
+
+impl From<CoordinateMetadata> for Proj {
+    fn from(metadata: CoordinateMetadata) -> Self {
+        metadata.0
+    }
+}
+
+impl IntoCrs for Crs {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        self.0.crs_definition()
+    }
+}
+
+/// Transformations from or to coordinate metadata use its epoch
+impl IntoCrs for CoordinateMetadata {
+    fn crs_definition(&self) -> Result<Cow<'_, str>, ProjCreateError> {
+        self.0.crs_definition()
+    }
+}
+
+impl CoordTransform for Transformation {
+    fn convert<C, F>(&self, point: C) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.0.convert(point)
+    }
+
+    fn project<C, F>(&self, point: C, inverse: bool) -> Result<C, ProjError>
+    where
+        C: Coord<F>,
+        F: CoordinateType,
+    {
+        self.0.project(point, inverse)
+    }
+}
+
+impl Transformer for Transformation {
+    fn transform_point(&self, point: (f64, f64), inverse: bool) -> Result<(f64, f64), ProjError> {
+        self.0.transform_point(point, inverse)
+    }
+
+    fn transform_points(&self, points: &mut [(f64, f64)], inverse: bool) -> Result<(), ProjError> {
+        self.0.transform_points(points, inverse)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typed() {
+        let wgs84 = Crs::new("EPSG:4326").unwrap();
+        let utm31n = Crs::try_from(Proj::new("EPSG:32631").unwrap()).unwrap();
+        let to_utm = wgs84.to(&utm31n, None).unwrap();
+        let expected = Proj::new_known_crs("EPSG:4326", "EPSG:32631", None)
+            .unwrap()
+            .convert((3.0, 0.0))
+            .unwrap();
+        assert_eq!(to_utm.convert((3.0, 0.0)).unwrap(), expected);
+        let back = to_utm.reversed().unwrap().convert(expected).unwrap();
+        assert_relative_eq!(back.0, 3.0, epsilon = 1e-9);
+        // through the traits
+        assert_eq!(
+            Transformer::transform_point(&to_utm, (3.0, 0.0), false).unwrap(),
+            expected
+        );
+        // the wrong kinds of objects are rejected
+        assert!(matches!(Crs::new("+proj=merc"), Err(ProjError::NotACrs)));
+        let to_feet = Transformation::new("+proj=unitconvert +xy_in=m +xy_out=ft").unwrap();
+        assert!(to_feet
+            .inverse()
+            .unwrap()
+            .as_proj()
+            .is_coordinate_operation());
+        assert!(matches!(
+            Transformation::new("EPSG:4326"),
+            Err(ProjError::NotAnOperation)
+        ));
+        assert!(matches!(
+            CoordinateMetadata::try_from(wgs84.into_proj()),
+            Err(ProjError::NotCoordinateMetadata)
+        ));
+        // between a CRS and coordinate metadata
+        let itrf2014 = Crs::new("EPSG:9000").unwrap().with_epoch(2010.0).unwrap();
+        assert_eq!(itrf2014.epoch(), Some(2010.0));
+        assert!(itrf2014.crs().unwrap().as_proj().is_crs());
+        let to_itrf2008 = Transformation::between(&itrf2014, "EPSG:5332", None).unwrap();
+        assert!(to_itrf2008.as_proj().is_coordinate_operation());
+        assert!(Proj::from(to_itrf2008).is_coordinate_operation());
+    }
+}
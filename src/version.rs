@@ -0,0 +1,194 @@
+//! The versions of libproj this crate supports
+use proj_sys::{proj_info, PROJ_VERSION_MAJOR, PROJ_VERSION_MINOR, PROJ_VERSION_PATCH};
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::{ProjCreateError, ProjError};
+
+/// The oldest libproj release this crate can be built against
+const MINIMUM: ProjVersion = ProjVersion {
+    major: 9,
+    minor: 2,
+    patch: 0,
+};
+
+/// A libproj release number
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProjVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl fmt::Display for ProjVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The libproj versions supported by this crate, and the version it is linked against
+///
+/// Obtained using [`supported_proj_versions`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SupportedVersions {
+    /// The oldest supported release
+    pub minimum: ProjVersion,
+    /// The newest release this crate has been tested with. Later patch releases of the same
+    /// minor version are also supported.
+    pub maximum_tested: ProjVersion,
+    /// The release of the libproj library in use
+    pub linked: ProjVersion,
+    /// Whether libproj was built from the source bundled with `proj-sys`, rather than found on
+    /// the system
+    pub bundled: bool,
+}
+
+impl SupportedVersions {
+    /// Whether the linked libproj is within the supported range
+    pub fn is_supported(&self) -> bool {
+        self.linked >= self.minimum
+            && (self.linked.major, self.linked.minor)
+                <= (self.maximum_tested.major, self.maximum_tested.minor)
+    }
+
+    /// Whether the linked libproj can be used: it must be a supported release, or a later
+    /// minor release of the same major version
+    ///
+    /// Transformations can't be created using an incompatible libproj. Later minor releases
+    /// aren't tested, but keep the API of their major version: a warning is logged when they
+    /// are used.
+    pub fn is_compatible(&self) -> bool {
+        self.linked >= self.minimum && self.linked.major == self.maximum_tested.major
+    }
+
+    /// Return an error describing the supported range if the linked libproj is outside of it
+    ///
+    /// ```rust
+    /// // fail fast, rather than debugging subtle differences in results
+    /// proj::supported_proj_versions().check().unwrap();
+    /// ```
+    pub fn check(&self) -> Result<(), ProjError> {
+        if self.is_supported() {
+            Ok(())
+        } else {
+            Err(ProjError::UnsupportedVersion {
+                linked: self.linked,
+                minimum: self.minimum,
+                maximum: self.maximum_tested,
+            })
+        }
+    }
+}
+
+/// Return the range of libproj versions supported by this crate, along with the version it is
+/// actually linked against
+///
+/// ```rust
+/// let versions = proj::supported_proj_versions();
+/// assert!(versions.minimum <= versions.maximum_tested);
+/// println!(
+///     "libproj {} ({})",
+///     versions.linked,
+///     if versions.bundled { "bundled" } else { "system" }
+/// );
+/// ```
+///
+/// # Safety
+/// This method contains unsafe code.
+pub fn supported_proj_versions() -> SupportedVersions {
+    let info = unsafe { proj_info() };
+    SupportedVersions {
+        minimum: MINIMUM,
+        // the release whose headers the bindings were generated from
+        maximum_tested: ProjVersion {
+            major: PROJ_VERSION_MAJOR,
+            minor: PROJ_VERSION_MINOR,
+            patch: PROJ_VERSION_PATCH,
+        },
+        linked: ProjVersion {
+            major: info.major as u32,
+            minor: info.minor as u32,
+            patch: info.patch as u32,
+        },
+        bundled: proj_sys::BUNDLED,
+    }
+}
+
+/// Check that the linked libproj is compatible, which is done once, when the first
+/// transformation is created, after loading it if the `runtime-loading` feature is enabled
+pub(crate) fn check_linked_version() -> Result<(), ProjCreateError> {
+    #[cfg(feature = "runtime-loading")]
+    proj_sys::load_libproj()?;
+    static VERSIONS: OnceLock<SupportedVersions> = OnceLock::new();
+    let versions = VERSIONS.get_or_init(|| {
+        let versions = supported_proj_versions();
+        if versions.is_compatible() && !versions.is_supported() {
+            log::warn!(
+                "libproj {} hasn't been tested with this crate: results may differ from those \
+                 of libproj {}.{}.x",
+                versions.linked,
+                versions.maximum_tested.major,
+                versions.maximum_tested.minor
+            );
+        }
+        versions
+    });
+    if versions.is_compatible() {
+        Ok(())
+    } else {
+        Err(ProjCreateError::UnsupportedVersion {
+            linked: versions.linked,
+            minimum: versions.minimum,
+            maximum: versions.maximum_tested,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn versions(linked: (u32, u32, u32)) -> SupportedVersions {
+        SupportedVersions {
+            minimum: MINIMUM,
+            maximum_tested: ProjVersion {
+                major: 9,
+                minor: 4,
+                patch: 1,
+            },
+            linked: ProjVersion {
+                major: linked.0,
+                minor: linked.1,
+                patch: linked.2,
+            },
+            bundled: false,
+        }
+    }
+
+    #[test]
+    fn test_supported_proj_versions() {
+        let versions = supported_proj_versions();
+        assert!(versions.is_supported());
+        assert!(versions.check().is_ok());
+        assert_eq!(versions.minimum.to_string(), "9.2.0");
+    }
+
+    #[test]
+    fn test_is_supported() {
+        assert!(versions((9, 4, 0)).is_supported());
+        // later patch releases are supported
+        assert!(versions((9, 4, 3)).is_supported());
+        assert!(versions((9, 3, 1)).is_supported());
+        assert!(!versions((9, 1, 1)).is_supported());
+        assert!(!versions((9, 5, 0)).is_supported());
+        assert!(versions((9, 5, 0)).is_compatible());
+        assert!(!versions((9, 1, 1)).is_compatible());
+        assert!(!versions((10, 0, 0)).is_compatible());
+        assert!(!versions((8, 2, 1)).is_compatible());
+        assert!(check_linked_version().is_ok());
+        assert_eq!(
+            versions((8, 2, 1)).check().unwrap_err().to_string(),
+            "libproj 8.2.1 is not supported: use a release from 9.2.0 to 9.4.x"
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! Transformation of WKB and EWKB geometries, without decoding them
+//!
+//! Databases such as PostGIS exchange geometries as Well-Known Binary, or as its Extended
+//! variant (EWKB), which adds an SRID. The functions of this module transform the coordinates
+//! of these geometries where they are in the bytes, optionally rewriting the SRID, so that ETL
+//! jobs don't decode each geometry into `geo-types` objects only to encode it again:
+//!
+//! ```rust
+//! # use approx::assert_relative_eq;
+//! use std::convert::TryInto;
+//!
+//! use proj::{wkb, Proj};
+//!
+//! // POINT(2 48) in little-endian WKB
+//! let mut point = vec![1, 1, 0, 0, 0];
+//! point.extend_from_slice(&2.0f64.to_le_bytes());
+//! point.extend_from_slice(&48.0f64.to_le_bytes());
+//!
+//! let to_merc = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+//! let mut ewkb = vec![];
+//! wkb::transform(&point, &to_merc, Some(3857), &mut ewkb).unwrap();
+//! // the SRID follows the geometry type, which has the EWKB SRID flag
+//! assert_eq!(&ewkb[..9], [1, 1, 0, 0, 0x20, 0x11, 0x0f, 0, 0]);
+//! let x = f64::from_le_bytes(ewkb[9..17].try_into().unwrap());
+//! assert_relative_eq!(x, 222638.98158654713, epsilon = 1e-6);
+//! ```
+//!
+//! Both the OGC (ISO) and the PostGIS (EWKB) ways of flagging heights and measures are
+//! understood. Heights are transformed, measures are left as they are, and so are empty points,
+//! whose coordinates are NaN. Curved geometries, polyhedral surfaces and TINs are supported,
+//! their control points being transformed like the other points.
+use std::convert::TryInto;
+
+use crate::{CoordTransform, ProjError};
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Transform the geometry at the start of `wkb` in place, returning its length in bytes
+///
+/// If `srid` is given, it replaces the SRID of an EWKB geometry: since the geometry can't grow,
+/// an SRID is only added by [`transform`]. The bytes following the geometry are left as they
+/// are, so that a stream of concatenated geometries can be transformed by calling this
+/// function at each offset it returns.
+pub fn transform_in_place<P: CoordTransform>(
+    wkb: &mut [u8],
+    proj: &P,
+    srid: Option<i32>,
+) -> Result<usize, ProjError> {
+    let layout = Layout::scan(wkb)?;
+    layout.apply(wkb, proj, srid)?;
+    Ok(layout.len)
+}
+
+/// Append the transformation of the geometry at the start of `wkb` to `output`, returning the
+/// length in bytes of the geometry read from `wkb`
+///
+/// If `srid` is given, the output is an EWKB geometry with this SRID, whether or not the input
+/// had one. Otherwise, any SRID of the input is kept.
+pub fn transform<P: CoordTransform>(
+    wkb: &[u8],
+    proj: &P,
+    srid: Option<i32>,
+    output: &mut Vec<u8>,
+) -> Result<usize, ProjError> {
+    let layout = Layout::scan(wkb)?;
+    let start = output.len();
+    match (srid, layout.has_srid) {
+        (Some(srid), false) => {
+            // the byte order, and the type with the SRID flag, followed by the SRID
+            let big_endian = wkb[0] == 0;
+            let kind = read_u32(&wkb[1..5], big_endian) | EWKB_SRID;
+            output.push(wkb[0]);
+            if big_endian {
+                output.extend_from_slice(&kind.to_be_bytes());
+                output.extend_from_slice(&srid.to_be_bytes());
+            } else {
+                output.extend_from_slice(&kind.to_le_bytes());
+                output.extend_from_slice(&srid.to_le_bytes());
+            }
+            output.extend_from_slice(&wkb[5..layout.len]);
+            let added = &mut output[start..];
+            Layout::scan(added)?.apply(added, proj, None)?;
+        }
+        _ => {
+            output.extend_from_slice(&wkb[..layout.len]);
+            layout.apply(&mut output[start..], proj, srid)?;
+        }
+    }
+    Ok(layout.len)
+}
+
+/// The positions of the SRIDs and coordinates of a geometry
+struct Layout {
+    /// The length of the geometry
+    len: usize,
+    /// Whether the outer geometry has an SRID
+    has_srid: bool,
+    /// The offsets of the SRIDs, and whether they are big-endian
+    srids: Vec<(usize, bool)>,
+    points: Vec<Points>,
+}
+
+/// A sequence of points
+struct Points {
+    offset: usize,
+    count: usize,
+    /// The number of values of each point
+    dims: usize,
+    has_z: bool,
+    big_endian: bool,
+}
+
+impl Layout {
+    fn scan(wkb: &[u8]) -> Result<Layout, ProjError> {
+        let mut layout = Layout {
+            len: 0,
+            has_srid: false,
+            srids: vec![],
+            points: vec![],
+        };
+        layout.geometry(wkb)?;
+        layout.has_srid = layout.srids.first().is_some_and(|&(offset, _)| offset == 5);
+        Ok(layout)
+    }
+
+    /// The offset of the next `len` bytes, which are skipped
+    fn take(&mut self, wkb: &[u8], len: usize) -> Result<usize, ProjError> {
+        let offset = self.len;
+        self.len = offset
+            .checked_add(len)
+            .filter(|&end| end <= wkb.len())
+            .ok_or_else(|| invalid("the geometry is truncated"))?;
+        Ok(offset)
+    }
+
+    fn u32(&mut self, wkb: &[u8], big_endian: bool) -> Result<u32, ProjError> {
+        let offset = self.take(wkb, 4)?;
+        Ok(read_u32(&wkb[offset..offset + 4], big_endian))
+    }
+
+    fn geometry(&mut self, wkb: &[u8]) -> Result<(), ProjError> {
+        let big_endian = match wkb[self.take(wkb, 1)?] {
+            0 => true,
+            1 => false,
+            order => return Err(invalid(&format!("invalid byte order {}", order))),
+        };
+        let kind = self.u32(wkb, big_endian)?;
+        if kind & EWKB_SRID != 0 {
+            let offset = self.take(wkb, 4)?;
+            self.srids.push((offset, big_endian));
+        }
+        // ISO WKB adds 1000 to the type for heights, 2000 for measures, and 3000 for both
+        let (iso, base) = ((kind & 0x0fff_ffff) / 1000, (kind & 0x0fff_ffff) % 1000);
+        let has_z = kind & EWKB_Z != 0 || iso == 1 || iso == 3;
+        let has_m = kind & EWKB_M != 0 || iso == 2 || iso == 3;
+        let dims = 2 + has_z as usize + has_m as usize;
+        let points = |layout: &mut Layout, count: usize| -> Result<(), ProjError> {
+            let len = count
+                .checked_mul(dims * 8)
+                .ok_or_else(|| invalid("the geometry is truncated"))?;
+            let offset = layout.take(wkb, len)?;
+            layout.points.push(Points {
+                offset,
+                count,
+                dims,
+                has_z,
+                big_endian,
+            });
+            Ok(())
+        };
+        match base {
+            // Point
+            1 => points(self, 1),
+            // LineString, CircularString
+            2 | 8 => {
+                let count = self.u32(wkb, big_endian)? as usize;
+                points(self, count)
+            }
+            // Polygon, Triangle
+            3 | 17 => {
+                for _ in 0..self.u32(wkb, big_endian)? {
+                    let count = self.u32(wkb, big_endian)? as usize;
+                    points(self, count)?;
+                }
+                Ok(())
+            }
+            // the multi geometries, collections, compound curves and surfaces
+            4..=7 | 9..=12 | 15 | 16 => {
+                for _ in 0..self.u32(wkb, big_endian)? {
+                    self.geometry(wkb)?;
+                }
+                Ok(())
+            }
+            _ => Err(invalid(&format!("unsupported geometry type {}", kind))),
+        }
+    }
+
+    /// Transform the coordinates, and replace the SRIDs with `srid` if it's given
+    fn apply<P: CoordTransform>(
+        &self,
+        wkb: &mut [u8],
+        proj: &P,
+        srid: Option<i32>,
+    ) -> Result<(), ProjError> {
+        if let Some(srid) = srid {
+            for &(offset, big_endian) in &self.srids {
+                let bytes = if big_endian {
+                    srid.to_be_bytes()
+                } else {
+                    srid.to_le_bytes()
+                };
+                wkb[offset..offset + 4].copy_from_slice(&bytes);
+            }
+        }
+        let mut buffer = vec![];
+        for points in &self.points {
+            let value = |wkb: &[u8], i: usize, d: usize| {
+                let offset = points.offset + (i * points.dims + d) * 8;
+                let bytes = wkb[offset..offset + 8].try_into().unwrap();
+                if points.big_endian {
+                    f64::from_be_bytes(bytes)
+                } else {
+                    f64::from_le_bytes(bytes)
+                }
+            };
+            buffer.clear();
+            buffer.extend((0..points.count).map(|i| {
+                let z = if points.has_z { value(wkb, i, 2) } else { 0.0 };
+                (value(wkb, i, 0), value(wkb, i, 1), z)
+            }));
+            // an empty point
+            if points.count == 1 && buffer[0].0.is_nan() && buffer[0].1.is_nan() {
+                continue;
+            }
+            proj.convert_array(&mut buffer)?;
+            for (i, &(x, y, z)) in buffer.iter().enumerate() {
+                let dims = if points.has_z { 3 } else { 2 };
+                for (d, value) in [x, y, z].iter().enumerate().take(dims) {
+                    let offset = points.offset + (i * points.dims + d) * 8;
+                    let bytes = if points.big_endian {
+                        value.to_be_bytes()
+                    } else {
+                        value.to_le_bytes()
+                    };
+                    wkb[offset..offset + 8].copy_from_slice(&bytes);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_u32(bytes: &[u8], big_endian: bool) -> u32 {
+    let bytes = bytes.try_into().unwrap();
+    if big_endian {
+        u32::from_be_bytes(bytes)
+    } else {
+        u32::from_le_bytes(bytes)
+    }
+}
+
+fn invalid(reason: &str) -> ProjError {
+    ProjError::Wkb(reason.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::proj::normalized_known_crs;
+
+    /// Encode a geometry of `kind` from its counts and coordinates
+    fn encode(big_endian: bool, kind: u32, counts: &[u32], coords: &[f64]) -> Vec<u8> {
+        let mut wkb = vec![if big_endian { 0 } else { 1 }];
+        for value in std::iter::once(&kind).chain(counts) {
+            if big_endian {
+                wkb.extend_from_slice(&value.to_be_bytes());
+            } else {
+                wkb.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        for value in coords {
+            if big_endian {
+                wkb.extend_from_slice(&value.to_be_bytes());
+            } else {
+                wkb.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        wkb
+    }
+
+    #[test]
+    fn test_transform_wkb() {
+        let to_merc = normalized_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let (x, y) = to_merc.convert((2.0, 48.0)).unwrap();
+
+        // a big-endian ISO polygon with heights and measures
+        let polygon = encode(
+            true,
+            3003,
+            &[1, 4],
+            &[
+                0.0, 0.0, 1.0, 7.0, 2.0, 0.0, 2.0, 7.0, 2.0, 48.0, 3.0, 7.0, 0.0, 0.0, 1.0, 7.0,
+            ],
+        );
+        let mut transformed = polygon.clone();
+        assert_eq!(
+            transform_in_place(&mut transformed, &to_merc, Some(3857)).unwrap(),
+            polygon.len()
+        );
+        let (_, merc_y) = to_merc.convert((2.0, 0.0)).unwrap();
+        let expected = encode(
+            true,
+            3003,
+            &[1, 4],
+            &[
+                0.0, 0.0, 1.0, 7.0, x, merc_y, 2.0, 7.0, x, y, 3.0, 7.0, 0.0, 0.0, 1.0, 7.0,
+            ],
+        );
+        assert_eq!(transformed, expected);
+
+        // a stream of EWKB geometries: a collection holding an empty point and a line string,
+        // followed by a point
+        let mut stream = encode(false, 7 | EWKB_SRID, &[4326, 2], &[]);
+        stream.extend(encode(false, 1, &[], &[f64::NAN, f64::NAN]));
+        stream.extend(encode(false, 2 | EWKB_Z, &[1], &[2.0, 48.0, 5.0]));
+        let collection_len = stream.len();
+        stream.extend(encode(false, 1, &[], &[2.0, 48.0]));
+        let mut output = vec![];
+        let mut offset = 0;
+        while offset < stream.len() {
+            offset += transform(&stream[offset..], &to_merc, Some(3857), &mut output).unwrap();
+        }
+        let mut expected = encode(false, 7 | EWKB_SRID, &[3857, 2], &[]);
+        expected.extend(encode(false, 1, &[], &[f64::NAN, f64::NAN]));
+        expected.extend(encode(false, 2 | EWKB_Z, &[1], &[x, y, 5.0]));
+        // an SRID was added to the point
+        expected.extend(encode(false, 1 | EWKB_SRID, &[3857], &[x, y]));
+        assert_eq!(output.len(), expected.len());
+        assert_eq!(output[..collection_len], expected[..collection_len]);
+        assert_eq!(output[collection_len..], expected[collection_len..]);
+
+        // invalid geometries
+        let truncated = &polygon[..polygon.len() - 1];
+        assert!(matches!(
+            transform(truncated, &to_merc, None, &mut vec![]),
+            Err(ProjError::Wkb(_))
+        ));
+        let mut unsupported = encode(false, 13, &[], &[]);
+        assert!(transform_in_place(&mut unsupported, &to_merc, None).is_err());
+        let mut invalid_latitude = encode(false, 1, &[], &[0.0, 91.0]);
+        assert!(transform_in_place(&mut invalid_latitude, &to_merc, None).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+//! Checks that the conversion hot paths don't allocate when they succeed
+//!
+//! These tests count the allocations made by a global allocator, so they are kept in their
+//! own test binary.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+
+use approx::assert_relative_eq;
+use proj::Proj;
+
+/// Count the allocations made by each thread
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Return the number of allocations made by the current thread while running `f`
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+/// The default chunk length of the array methods
+const ARRAY_CHUNK_LEN: usize = 64;
+
+#[test]
+fn test_hot_paths_dont_allocate() {
+    let ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+    assert_eq!(ft_to_m.array_chunk_len(), ARRAY_CHUNK_LEN);
+    let point = (4760096.421921, 3744293.729449);
+    // PROJ chooses the operation on first use
+    ft_to_m.convert(point).unwrap();
+    let (result, count) = allocations(|| ft_to_m.convert(point));
+    assert!(result.is_ok());
+    assert_eq!(count, 0);
+    let (result, count) = allocations(|| ft_to_m.convert_inverse(point));
+    assert!(result.is_ok());
+    assert_eq!(count, 0);
+    // more points than fit in a chunk
+    let mut points = vec![point; ARRAY_CHUNK_LEN * 2 + 1];
+    let (result, count) = allocations(|| ft_to_m.convert_array(&mut points).is_ok());
+    assert!(result);
+    assert_eq!(count, 0);
+    assert!(points.iter().all(|p| p == &points[0]));
+    assert_relative_eq!(points[ARRAY_CHUNK_LEN * 2].0, 1450880.2910605022);
+
+    let merc = Proj::new("+proj=merc +ellps=WGS84").unwrap();
+    let (result, count) = allocations(|| merc.project((0.1, 0.2), false));
+    assert!(result.is_ok());
+    assert_eq!(count, 0);
+    // the error message is only built on failure
+    let (result, count) = allocations(|| merc.project((0.0, 2.0), false));
+    assert!(result.is_err());
+    assert!(count > 0);
+}
+
+#[test]
+fn test_array_chunk_len_allocations() {
+    let mut ft_to_m = Proj::new_known_crs("EPSG:2230", "EPSG:26946", None).unwrap();
+    let mut points = vec![(4760096.421921, 3744293.729449); 500];
+    ft_to_m.convert_array(&mut points[..1]).unwrap();
+    for len in [1, 3, ARRAY_CHUNK_LEN, 200, 1000] {
+        ft_to_m.set_array_chunk_len(NonZeroUsize::new(len).unwrap());
+        let (result, count) = allocations(|| ft_to_m.convert_array(&mut points).is_ok());
+        assert!(result);
+        // a single buffer is allocated for long chunks
+        assert_eq!(count, usize::from(len > ARRAY_CHUNK_LEN));
+    }
+}
@@ -0,0 +1,107 @@
+//! Checks how the environment variables read by PROJ are handled
+//!
+//! PROJ reads them once per process, and they would affect every test running concurrently, so
+//! each test runs its checks in a child process started with the environment it needs.
+use std::env;
+use std::ffi::OsStr;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[cfg(feature = "runtime-loading")]
+use proj::{Proj, ProjCreateError};
+use proj::{ProjBuilder, ProjError};
+
+const BOGUS_DATA_DIR: &str = "/nonexistent/proj";
+/// Set in the child processes, to the data directories found by the parent
+const DATA_DIRS_VAR: &str = "PROJ_RS_TEST_DATA_DIRS";
+
+/// Run the test `name` in a child process, with the environment variables `vars` and the data
+/// directories found in this process, and check that it passes
+fn run_in_child(name: &str, vars: &[(&str, &str)]) {
+    let data_dirs: Vec<PathBuf> = ProjBuilder::new()
+        .search_paths()
+        .unwrap()
+        .into_iter()
+        .filter(|dir| dir.join("proj.db").is_file())
+        .collect();
+    assert!(!data_dirs.is_empty());
+    let status = Command::new(env::current_exe().unwrap())
+        .args([name, "--exact", "--nocapture"])
+        .envs(vars.iter().map(|(name, value)| (name, value)))
+        .env(DATA_DIRS_VAR, env::join_paths(&data_dirs).unwrap())
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+/// The data directories found by the parent process, if this is a child process
+fn child_data_dirs() -> Option<Vec<PathBuf>> {
+    env::var_os(DATA_DIRS_VAR).map(|dirs| env::split_paths(OsStr::new(&dirs)).collect())
+}
+
+#[test]
+fn test_ignore_environment() {
+    let Some(data_dirs) = child_data_dirs() else {
+        return run_in_child(
+            "test_ignore_environment",
+            &[
+                ("PROJ_DATA", BOGUS_DATA_DIR),
+                ("PROJ_LIB", BOGUS_DATA_DIR),
+                ("PROJ_NETWORK", "ON"),
+            ],
+        );
+    };
+    // the environment applies by default: the database can't be found
+    let default = ProjBuilder::new();
+    assert!(default.network_enabled());
+    assert_eq!(
+        default.search_paths().unwrap().last(),
+        Some(&PathBuf::from(BOGUS_DATA_DIR))
+    );
+    assert!(default
+        .proj_known_crs("EPSG:2230", "EPSG:26946", None)
+        .is_err());
+
+    let mut builder = ProjBuilder::new();
+    assert!(matches!(
+        builder.ignore_environment([BOGUS_DATA_DIR]),
+        Err(ProjError::DatabaseNotFound)
+    ));
+    builder.ignore_environment(&data_dirs).unwrap();
+    assert_eq!(builder.search_paths().unwrap(), data_dirs);
+    assert_eq!(
+        builder.database_path().unwrap(),
+        data_dirs[0].join("proj.db")
+    );
+    assert!(!builder.network_enabled());
+    assert!(builder
+        .proj_known_crs("EPSG:2230", "EPSG:26946", None)
+        .is_ok());
+}
+
+#[cfg(feature = "runtime-loading")]
+#[test]
+fn test_runtime_loading_unavailable() {
+    if child_data_dirs().is_none() {
+        return run_in_child(
+            "test_runtime_loading_unavailable",
+            &[("PROJ_SYS_LIBRARY", "/nonexistent/libproj.so")],
+        );
+    }
+    assert!(proj::load_libproj().is_err());
+    // functions which can't fail do nothing, and the others return an error
+    let builder = ProjBuilder::new();
+    assert!(!builder.network_enabled());
+    assert!(matches!(
+        builder.proj("EPSG:4326"),
+        Err(ProjCreateError::Unavailable(_))
+    ));
+    assert!(matches!(
+        Proj::new_known_crs("EPSG:4326", "EPSG:3857", None),
+        Err(ProjCreateError::Unavailable(_))
+    ));
+    assert!(matches!(
+        proj::grid_info("us_noaa_conus.tif"),
+        Err(ProjError::Unavailable(_))
+    ));
+}